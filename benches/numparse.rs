@@ -45,16 +45,14 @@ impl<'a> NumberParser<'a> {
 }
 
 struct NumberParserSimd<'a, S: Simd> {
-    string: &'a [i8],
+    string: &'a [u8],
     _simd: std::marker::PhantomData<S>,
 }
 
 impl<'a, S: Simd> NumberParserSimd<'a, S> {
     fn new(string: &'a str) -> Self {
         Self {
-            // Transmuting here is safe because we're converting from u8 to i8
-            // This is necessary to make loading easier below
-            string: unsafe { core::mem::transmute::<&[u8], &[i8]>(string.as_bytes()) },
+            string: string.as_bytes(),
 
             _simd: std::marker::PhantomData,
         }
@@ -62,11 +60,13 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
 
     fn get_next(&mut self) -> Option<u64> {
         simd_invoke!(S, {
-            let zero_char = S::Vi8::set1(b'0' as i8);
-            let nine_char = S::Vi8::set1(b'9' as i8);
+            let zero_char = S::Vu8::set1(b'0');
+            let ten = S::Vu8::set1(10);
 
+            // Characters below '0' wrap around to large values, so a single unsigned
+            // comparison finds the digits.
             let next_simd_match_vec =
-                |chars: S::Vi8| chars.cmp_gte(zero_char) & chars.cmp_lte(nine_char);
+                |chars: S::Vu8| S::Vi8::from_mask((chars - zero_char).cmp_lt(ten));
 
             if self.string.is_empty() {
                 return None;
@@ -78,18 +78,18 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
                     return None;
                 }
 
-                let chars = S::Vi8::load_from_slice(self.string);
+                let chars = S::Vu8::load_from_slice(self.string);
                 let mask = next_simd_match_vec(chars);
 
                 if let Some(index) = mask.index_of_first_truthy() {
                     self.string = &self.string[index..];
                     break;
                 } else {
-                    if self.string.len() < S::Vi8::WIDTH {
+                    if self.string.len() < S::Vu8::WIDTH {
                         self.string = &self.string[0..0];
                         return None;
                     }
-                    self.string = &self.string[S::Vi8::WIDTH..];
+                    self.string = &self.string[S::Vu8::WIDTH..];
                 }
             }
 
@@ -98,20 +98,20 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
 
             // Locate the end of the number
             loop {
-                let chars = S::Vi8::load_from_slice(number_end_str);
+                let chars = S::Vu8::load_from_slice(number_end_str);
                 let mask = next_simd_match_vec(chars);
 
                 if let Some(index) = mask.index_of_first_falsy() {
                     number_end_str = &number_end_str[index..];
                     num_length += index;
                     break;
-                } else if self.string.len() <= S::Vi8::WIDTH {
+                } else if self.string.len() <= S::Vu8::WIDTH {
                     number_end_str = &number_end_str[0..0];
                     num_length += self.string.len();
                     break;
                 } else {
-                    number_end_str = &number_end_str[S::Vi8::WIDTH..];
-                    num_length += S::Vi8::WIDTH;
+                    number_end_str = &number_end_str[S::Vu8::WIDTH..];
+                    num_length += S::Vu8::WIDTH;
                 }
             }
 
@@ -120,19 +120,19 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
             let mut num_str = &self.string[..num_length];
 
             loop {
-                let num_chars = S::Vi8::load_from_slice(num_str);
+                let num_chars = S::Vu8::load_from_slice(num_str);
                 let digits = num_chars - zero_char;
 
-                let length = num_str.len().min(S::Vi8::WIDTH);
+                let length = num_str.len().min(S::Vu8::WIDTH);
                 for digit in digits.iter().take(length) {
                     total *= 10;
                     total += digit as u64;
                 }
 
-                if num_str.len() <= S::Vi8::WIDTH {
+                if num_str.len() <= S::Vu8::WIDTH {
                     break;
                 } else {
-                    num_str = &num_str[S::Vi8::WIDTH..];
+                    num_str = &num_str[S::Vu8::WIDTH..];
                 }
             }
 
@@ -85,7 +85,7 @@ impl<'a, S: Simd> NumberParserSimd<'a, S> {
         let nine_char = S::Vi8::set1(b'9' as i8);
 
         let next_simd_match_vec =
-            |chars: S::Vi8| chars.cmp_gte(zero_char) & chars.cmp_lte(nine_char);
+            |chars: S::Vi8| S::Vi8::from_mask(chars.cmp_gte(zero_char) & chars.cmp_lte(nine_char));
 
         if self.string.is_empty() {
             return None;
@@ -1,6 +1,14 @@
-use crate::SimdConsts;
+use crate::{SimdConsts, SimdMask};
 
 pub trait SimdBaseIo: SimdConsts {
+    /// The mask type returned by comparisons, consumed by [`crate::SimdBaseOps::select`] and by
+    /// the masked loads and stores.
+    type Mask: SimdMask<Engine = Self::Engine>;
+
+    /// The vector holding the indices of a gather. This is the signed integer vector
+    /// with lanes as wide as the ones of `Self`.
+    type GatherIndices: SimdBaseIo<Scalar: Into<i64> + From<i8>>;
+
     /// Transmutes the vector into a array representation defined by `Self::ArrayRepresentation`.
     /// Please don't use this function directly unless necessary.
     #[inline(always)]
@@ -36,6 +44,43 @@ pub trait SimdBaseIo: SimdConsts {
     /// Store a vector to a 32 bit aligned raw pointer.
     unsafe fn copy_to_ptr_aligned(self, ptr: *mut Self::Scalar);
 
+    /// Loads `slice[indices[i]]` into each lane `i`.
+    ///
+    /// Panics if any index is negative or out of bounds for `slice`. Uses the native gather
+    /// instructions for 32 and 64 bit lanes on Avx2 and Avx512, and loads each lane separately
+    /// otherwise.
+    #[inline(always)]
+    fn gather(slice: &[Self::Scalar], indices: Self::GatherIndices) -> Self {
+        assert_indices_in_bounds::<Self>("gather", slice.len(), indices, u64::MAX);
+        unsafe { Self::gather_unchecked(slice, indices) }
+    }
+
+    /// Same as `gather`, without checking the indices.
+    ///
+    /// # Safety
+    /// Every index must be within the bounds of `slice`.
+    unsafe fn gather_unchecked(slice: &[Self::Scalar], indices: Self::GatherIndices) -> Self;
+
+    /// Loads the lanes where `mask` is set from an unaligned raw pointer, and sets the others to zero.
+    ///
+    /// # Safety
+    /// Every lane set in `mask` must be valid to read. The other lanes are never read.
+    unsafe fn load_from_ptr_masked(ptr: *const Self::Scalar, mask: Self::Mask) -> Self;
+
+    /// Stores the lanes where `mask` is set to an unaligned raw pointer.
+    ///
+    /// # Safety
+    /// Every lane set in `mask` must be valid to write. The other lanes are never written.
+    unsafe fn copy_to_ptr_masked(self, ptr: *mut Self::Scalar, mask: Self::Mask);
+
+    /// Loads the lanes where `mask` is set from a slice, and sets the others to zero. Lanes past the
+    /// end of the slice are treated as not set.
+    fn load_from_slice_masked(slice: &[Self::Scalar], mask: Self::Mask) -> Self;
+
+    /// Stores the lanes where `mask` is set to a slice. Lanes past the end of the slice are treated
+    /// as not set.
+    fn store_to_slice_masked(self, slice: &mut [Self::Scalar], mask: Self::Mask);
+
     unsafe fn underlying_value(self) -> Self::UnderlyingType;
     unsafe fn underlying_value_mut(&mut self) -> &mut Self::UnderlyingType;
     unsafe fn from_underlying_value(value: Self::UnderlyingType) -> Self;
@@ -72,21 +117,30 @@ pub trait SimdBaseIo: SimdConsts {
     }
 
     /// Tries to load from a slice. If the slice is too short, it uses zeroes for the remaining values.
+    #[inline(always)]
     fn load_from_slice(slice: &[Self::Scalar]) -> Self {
-        unsafe {
-            if slice.len() < Self::WIDTH {
-                let mut val = Self::zeroes();
-                for (i, s) in slice.iter().copied().enumerate() {
-                    let ptr = val.get_unchecked_mut(i);
-                    *ptr = s;
-                }
-                val
-            } else {
-                Self::load_from_ptr_unaligned(slice.as_ptr())
-            }
-        }
+        Self::load_partial(slice)
     }
 
+    /// Loads the first `slice.len()` lanes from a slice, and sets the remaining lanes to zero.
+    /// Never reads past the end of the slice. Short slices use a masked load where the engine
+    /// has one for the lane width.
+    fn load_partial(slice: &[Self::Scalar]) -> Self;
+
+    /// Loads `N` vectors from a slice of interleaved values, like `[x, y, z, x, y, z, ...]`, so that
+    /// lane `i` of vector `c` holds `slice[i * N + c]`. `N` must be 2, 3 or 4.
+    ///
+    /// Panics if the slice holds fewer than `N * Self::WIDTH` values. Maps to `vld2q`, `vld3q` and
+    /// `vld4q` on Neon, and to loads followed by shuffles elsewhere.
+    fn load_deinterleaved<const N: usize>(slice: &[Self::Scalar]) -> [Self; N];
+
+    /// Stores `N` vectors interleaved, so that `slice[i * N + c]` holds lane `i` of `vectors[c]`.
+    /// This is the inverse of `load_deinterleaved`, and `N` must also be 2, 3 or 4.
+    ///
+    /// Panics if the slice holds fewer than `N * Self::WIDTH` values. Maps to `vst2q`, `vst3q` and
+    /// `vst4q` on Neon, and to shuffles followed by stores elsewhere.
+    fn store_interleaved<const N: usize>(vectors: [Self; N], slice: &mut [Self::Scalar]);
+
     /// Tries to copy to a slice. If the slice is too short, it returns the length of the slice.
     fn copy_to_slice_exact(self, slice: &mut [Self::Scalar]) -> Result<(), usize> {
         unsafe {
@@ -112,3 +166,54 @@ pub trait SimdBaseIo: SimdConsts {
         }
     }
 }
+
+/// Gathers one lane at a time, for lane widths without a native gather.
+#[inline(always)]
+pub(crate) unsafe fn gather_emulated<V: SimdBaseIo>(
+    slice: &[V::Scalar],
+    indices: V::GatherIndices,
+) -> V {
+    let lanes = indices.transmute_into_array_ref();
+    let mut result = V::zeroes();
+    for i in 0..V::WIDTH {
+        let index: i64 = lanes[i].into();
+        *result.get_unchecked_mut(i) = *slice.get_unchecked(index as usize);
+    }
+    result
+}
+
+/// Panics if any index in a lane set in `active` is negative or not below `len`.
+#[inline(always)]
+pub(crate) fn assert_indices_in_bounds<V: SimdBaseIo>(
+    op: &str,
+    len: usize,
+    indices: V::GatherIndices,
+    active: u64,
+) {
+    let lanes = unsafe { indices.transmute_into_array_ref() };
+    for i in 0..V::WIDTH {
+        let index: i64 = lanes[i].into();
+        assert!(
+            (active >> i) & 1 == 0 || (index >= 0 && (index as u64) < len as u64),
+            "{op} index {index} out of bounds for slice of length {len}"
+        );
+    }
+}
+
+/// Stores one lane at a time, for engines and lane widths without a native scatter. Only the lanes
+/// set in `active` are stored.
+#[inline(always)]
+pub(crate) unsafe fn scatter_emulated<V: SimdBaseIo>(
+    value: V,
+    slice: &mut [V::Scalar],
+    indices: V::GatherIndices,
+    active: u64,
+) {
+    let lanes = indices.transmute_into_array_ref();
+    for i in 0..V::WIDTH {
+        if (active >> i) & 1 == 1 {
+            let index: i64 = lanes[i].into();
+            *slice.get_unchecked_mut(index as usize) = value.get_unchecked(i);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use core::fmt::Debug;
+use core::ops::*;
+
+use super::SimdBaseOps;
+use crate::Simd;
+
+/// A lane mask, as returned by comparisons and consumed by [`SimdBaseOps::select`].
+///
+/// Each engine picks its own representation: AVX-512 keeps one bit per lane in a k-register,
+/// while the other engines keep a vector with every bit of a lane either set or cleared. Masks
+/// can only be built from comparisons and the operators below, so a lane is always fully on or off.
+pub trait SimdMask:
+    'static
+    + Copy
+    + core::marker::Sync
+    + core::marker::Send
+    + Debug
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+{
+    /// The number of lanes in the mask.
+    const WIDTH: usize;
+
+    type Engine: Simd;
+
+    /// Returns an integer where bit `i` is set if lane `i` of the mask is set.
+    /// Bits at or above `Self::WIDTH` are always zero.
+    fn to_bitmask(self) -> u64;
+
+    /// Checks if any lane is set.
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.to_bitmask() != 0
+    }
+
+    /// Checks if every lane is set.
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.count() == Self::WIDTH
+    }
+
+    /// Checks if no lane is set.
+    #[inline(always)]
+    fn none(self) -> bool {
+        !self.any()
+    }
+
+    /// Counts the lanes that are set.
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.to_bitmask().count_ones() as usize
+    }
+
+    /// Element-wise selection between two vectors, picking `if_true` where the mask is set
+    /// and `if_false` elsewhere.
+    #[inline(always)]
+    fn select<V: SimdBaseOps<Mask = Self>>(self, if_true: V, if_false: V) -> V {
+        V::select(self, if_true, if_false)
+    }
+
+    /// Element-wise "blend" between two vectors. `a` is selected where the mask is not set,
+    /// and `b` is selected where it is.
+    #[inline(always)]
+    fn blendv<V: SimdBaseOps<Mask = Self>>(self, a: V, b: V) -> V {
+        V::select(self, b, a)
+    }
+}
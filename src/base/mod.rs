@@ -6,6 +6,12 @@ mod io;
 mod iters;
 pub use iters::*;
 
+mod mask;
+pub use mask::*;
+
+mod shuffle;
+pub use shuffle::*;
+
 mod transmute;
 pub use transmute::*;
 
@@ -13,6 +19,7 @@ mod specializations;
 pub use specializations::*;
 
 pub use io::SimdBaseIo;
+pub(crate) use io::{assert_indices_in_bounds, gather_emulated, scatter_emulated};
 
 use crate::Simd;
 
@@ -92,38 +99,193 @@ pub trait SimdBaseOps:
     /// Binary and not between two vectors `self & (!rhs)`
     fn and_not(self, rhs: Self) -> Self;
 
-    /// Element-wise "blend" between two vectors. A is selected if the mask value
-    /// is zero, and B is selected if the mask value is all 1's. undefined behavior if
-    /// it's anything in  between. See note below.
+    /// Element-wise selection between two vectors, picking `if_true` where `mask` is set
+    /// and `if_false` elsewhere.
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+
+    /// Converts a mask into a vector, with all 1's in the lanes where the mask is set
+    /// and all 0's elsewhere.
+    fn from_mask(mask: Self::Mask) -> Self;
+
+    /// Stores each lane `i` into `slice[indices[i]]`. When several lanes share an index,
+    /// the highest of those lanes is the one that ends up in the slice.
     ///
-    /// Note: SSE2 will select B only when all bits are 1, while SSE41 and AVX2 only
-    /// check the high bit. To maintain portability ensure all bits are 1 when using
-    /// blend. Results of comparison operations adhere to this.
-    fn blendv(self, a: Self, b: Self) -> Self;
+    /// Panics if any index is negative or out of bounds for `slice`. Uses the native scatter
+    /// instructions for 32 and 64 bit lanes on Avx512, and stores each lane separately otherwise.
+    #[inline(always)]
+    fn scatter(self, slice: &mut [Self::Scalar], indices: Self::GatherIndices) {
+        assert_indices_in_bounds::<Self>("scatter", slice.len(), indices, u64::MAX);
+        unsafe { self.scatter_unchecked(slice, indices) }
+    }
+
+    /// Same as `scatter`, without checking the indices.
+    ///
+    /// # Safety
+    /// Every index must be within the bounds of `slice`.
+    unsafe fn scatter_unchecked(self, slice: &mut [Self::Scalar], indices: Self::GatherIndices);
+
+    /// Same as `scatter`, but only stores the lanes where `mask` is set. The indices of the
+    /// other lanes are ignored and don't need to be in bounds.
+    #[inline(always)]
+    fn scatter_masked(
+        self,
+        slice: &mut [Self::Scalar],
+        indices: Self::GatherIndices,
+        mask: Self::Mask,
+    ) {
+        assert_indices_in_bounds::<Self>("scatter", slice.len(), indices, mask.to_bitmask());
+        unsafe { self.scatter_masked_unchecked(slice, indices, mask) }
+    }
+
+    /// Same as `scatter_masked`, without checking the indices.
+    ///
+    /// # Safety
+    /// Every index of a lane set in `mask` must be within the bounds of `slice`.
+    unsafe fn scatter_masked_unchecked(
+        self,
+        slice: &mut [Self::Scalar],
+        indices: Self::GatherIndices,
+        mask: Self::Mask,
+    );
+
+    /// Element-wise equality between two vectors. The mask is set in the lanes where the elements are equal.
+    fn cmp_eq(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise inequality between two vectors. The mask is set in the lanes where the elements are not equal.
+    fn cmp_neq(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise less than between two vectors. The mask is set in the lanes where the first element
+    /// is less than the second element.
+    fn cmp_lt(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise less than or equal to between two vectors. The mask is set in the lanes where the first element
+    /// is less than or equal to the second element.
+    fn cmp_lte(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise greater than between two vectors. The mask is set in the lanes where the first element
+    /// is greater than the second element.
+    fn cmp_gt(self, rhs: Self) -> Self::Mask;
+
+    /// Element-wise greater than or equal to between two vectors. The mask is set in the lanes where the first element
+    /// is greater than or equal to the second element.
+    fn cmp_gte(self, rhs: Self) -> Self::Mask;
+
+    /// Gets the "mask" of a vector, where bit `i` of the u64 is set if the value at lane `i` is truthy.
+    /// A value is truthy if its highest bit is set, which is the sign bit for signed and float types.
+    /// Results of comparison operations converted with `from_mask` are truthy in every set lane.
+    fn get_mask(self) -> u64;
+
+    /// Checks if any element in the vector is truthy.
+    #[inline(always)]
+    fn is_any_truthy(self) -> bool {
+        self.get_mask() != 0
+    }
+
+    /// Checks if every element in the vector is truthy.
+    #[inline(always)]
+    fn is_all_truthy(self) -> bool {
+        self.get_mask() == u64::MAX >> (64 - Self::WIDTH)
+    }
+
+    /// Grabs the index of the first truthy value. If no value is truthy, returns None.
+    /// Index will always be smaller than Self::WIDTH.
+    #[inline(always)]
+    fn index_of_first_truthy(self) -> Option<usize> {
+        let mask = self.get_mask();
+        if mask == 0 {
+            None
+        } else {
+            Some(mask.trailing_zeros() as usize)
+        }
+    }
+
+    /// Grabs the index of the first falsy value. If no value is falsy, returns None.
+    /// Index will always be smaller than Self::WIDTH.
+    #[inline(always)]
+    fn index_of_first_falsy(self) -> Option<usize> {
+        let mask = !self.get_mask() & (u64::MAX >> (64 - Self::WIDTH));
+        if mask == 0 {
+            None
+        } else {
+            Some(mask.trailing_zeros() as usize)
+        }
+    }
+
+    /// Grabs the index of the last truthy value. If no value is truthy, returns None.
+    /// Index will always be smaller than Self::WIDTH.
+    #[inline(always)]
+    fn index_of_last_truthy(self) -> Option<usize> {
+        let mask = self.get_mask();
+        if mask == 0 {
+            None
+        } else {
+            Some(63 - mask.leading_zeros() as usize)
+        }
+    }
+
+    /// Grabs the index of the last falsy value. If no value is falsy, returns None.
+    /// Index will always be smaller than Self::WIDTH.
+    #[inline(always)]
+    fn index_of_last_falsy(self) -> Option<usize> {
+        let mask = !self.get_mask() & (u64::MAX >> (64 - Self::WIDTH));
+        if mask == 0 {
+            None
+        } else {
+            Some(63 - mask.leading_zeros() as usize)
+        }
+    }
+
+    /// Interleaves the lower halves of `self` and `other`, giving
+    /// `[self[0], other[0], self[1], other[1], ...]`.
+    fn zip_lo(self, other: Self) -> Self;
+
+    /// Interleaves the upper halves of `self` and `other`, giving
+    /// `[self[WIDTH / 2], other[WIDTH / 2], self[WIDTH / 2 + 1], other[WIDTH / 2 + 1], ...]`.
+    /// For single lane vectors this is `other`.
+    fn zip_hi(self, other: Self) -> Self;
+
+    /// Splits the lanes of `self` followed by those of `other` into the even and the odd ones.
+    /// This undoes `zip_lo` and `zip_hi`, so `a.zip_lo(b).unzip(a.zip_hi(b))` is `(a, b)`.
+    fn unzip(self, other: Self) -> (Self, Self);
 
-    /// Element-wise equality between two vectors. If two elements are equal, it returns all 1's
-    /// in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_eq(self, rhs: Self) -> Self;
+    /// Builds a vector where lane `i` holds lane `indices[i]` of `self`. Indices wrap around the
+    /// width of the vector, so only their lowest bits are used.
+    fn permute(self, indices: Self::GatherIndices) -> Self;
 
-    /// Element-wise inequality between two vectors. If two elements are not equal, it returns all 1's
-    /// in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_neq(self, rhs: Self) -> Self;
+    /// Builds a vector where lane `i` holds lane `P::INDICES[i]` of `self`, wrapping around the
+    /// width of the vector. The indices are known at compile time, so each engine lowers this to
+    /// its fixed shuffle, like `pshufd`, `vpermps`, `vqtbl1q` or `i8x16.shuffle`.
+    fn shuffle<P: ShufflePattern>(self) -> Self;
 
-    /// Element-wise less than between two vectors. If the first element is less than the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_lt(self, rhs: Self) -> Self;
+    /// Reverses the order of the lanes.
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.shuffle::<Reverse>()
+    }
 
-    /// Element-wise less than or equal to between two vectors. If the first element is less than or equal to the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_lte(self, rhs: Self) -> Self;
+    /// Rotates the lanes so that lane `N` ends up first, like `slice::rotate_left`.
+    #[inline(always)]
+    fn rotate_lanes_left<const N: usize>(self) -> Self {
+        self.shuffle::<RotateLanesLeft<N>>()
+    }
 
-    /// Element-wise greater than between two vectors. If the first element is greater than the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_gt(self, rhs: Self) -> Self;
+    /// Copies lane `N` into every lane. Panics if `N` is not smaller than `Self::WIDTH`.
+    #[inline(always)]
+    fn broadcast_lane<const N: usize>(self) -> Self {
+        assert!(
+            N < Self::WIDTH,
+            "lane {N} out of bounds for a vector of width {}",
+            Self::WIDTH
+        );
+        self.shuffle::<BroadcastLane<N>>()
+    }
 
-    /// Element-wise greater than or equal to between two vectors. If the first element is greater than or equal to the second element,
-    /// it returns all 1's in the corresponding element of the result, otherwise it returns all 0's.
-    fn cmp_gte(self, rhs: Self) -> Self;
+    /// Swaps each even lane with the odd lane that follows it. Vectors with a single lane are
+    /// left unchanged.
+    #[inline(always)]
+    fn swap_pairs(self) -> Self {
+        self.shuffle::<SwapPairs>()
+    }
 
     /// Element-wise maximum between two vectors.
     fn max(self, rhs: Self) -> Self;
@@ -133,6 +295,85 @@ pub trait SimdBaseOps:
 
     /// Add every number in the vector together
     fn horizontal_add(self) -> Self::HorizontalAddScalar;
+
+    /// Minimum of every number in the vector. The result is unspecified if any lane is NaN.
+    #[inline(always)]
+    fn horizontal_min(self) -> Self::Scalar {
+        reduce_lanes(self, Self::min)
+    }
+
+    /// Maximum of every number in the vector. The result is unspecified if any lane is NaN.
+    #[inline(always)]
+    fn horizontal_max(self) -> Self::Scalar {
+        reduce_lanes(self, Self::max)
+    }
+
+    /// Multiply every number in the vector together, wrapping on overflow for integers. Neighbouring
+    /// lanes are multiplied first, so `[a, b, c, d]` gives `(a * b) * (c * d)`.
+    #[inline(always)]
+    fn horizontal_mul(self) -> Self::Scalar {
+        reduce_lanes(self, <Self as SimdBaseOps>::mul)
+    }
+
+    /// Binary and of every number in the vector
+    #[inline(always)]
+    fn horizontal_and(self) -> Self::Scalar {
+        reduce_lanes(self, Self::bit_and)
+    }
+
+    /// Binary or of every number in the vector
+    #[inline(always)]
+    fn horizontal_or(self) -> Self::Scalar {
+        reduce_lanes(self, Self::bit_or)
+    }
+
+    /// Binary xor of every number in the vector
+    #[inline(always)]
+    fn horizontal_xor(self) -> Self::Scalar {
+        reduce_lanes(self, Self::bit_xor)
+    }
+
+    /// Index of the first lane holding the minimum of the vector. The result is unspecified if
+    /// any lane is NaN.
+    fn argmin(self) -> usize;
+
+    /// Index of the first lane holding the maximum of the vector. The result is unspecified if
+    /// any lane is NaN.
+    fn argmax(self) -> usize;
+
+    /// Inclusive prefix sum of the lanes, so lane `i` holds the sum of lanes `0..=i`. Integers
+    /// wrap on overflow.
+    fn prefix_sum(self) -> Self;
+
+    /// Exclusive prefix sum of the lanes, so lane `i` holds the sum of lanes `0..i` and the first
+    /// lane is zero. Integers wrap on overflow.
+    fn exclusive_prefix_sum(self) -> Self;
+
+    /// Replaces every value in `slice` with the sum of the values up to and including it. Works a
+    /// vector at a time, carrying the running total from one vector to the next.
+    #[inline(always)]
+    fn prefix_sum_in_place(slice: &mut [Self::Scalar]) {
+        let mut total = Self::zeroes();
+        for chunk in slice.chunks_mut(Self::WIDTH) {
+            let sums = Self::load_partial(chunk).prefix_sum() + total;
+            sums.copy_to_slice(chunk);
+            total = Self::set1(sums[Self::WIDTH - 1]);
+        }
+    }
+}
+
+/// Folds the lanes of `value` with `f` in log2(WIDTH) steps, combining each even lane with the odd
+/// lane after it, so `f` only ever sees neighbouring ranges of lanes in order.
+#[inline(always)]
+pub(crate) fn reduce_lanes<V: SimdBaseOps>(value: V, f: impl Fn(V, V) -> V) -> V::Scalar {
+    let mut value = value;
+    let mut width = V::WIDTH;
+    while width > 1 {
+        let (even, odd) = value.unzip(value);
+        value = f(even, odd);
+        width /= 2;
+    }
+    value[0]
 }
 
 pub trait SimdBase: SimdBaseOps + SimdBaseIo + SimdIter {}
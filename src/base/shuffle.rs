@@ -0,0 +1,92 @@
+/// The lanes picked by a shuffle of a vector with `WIDTH` lanes: lane `i` of the result holds lane
+/// `INDICES[i]` of the input, wrapping around the width. The indices are constants, so every
+/// engine lowers the shuffle to its fixed shuffle instructions.
+pub trait LaneIndices<const WIDTH: usize> {
+    const INDICES: [usize; WIDTH];
+}
+
+/// A shuffle known at compile time for every vector width, as taken by
+/// [`crate::SimdBaseOps::shuffle`]. It is implemented for every type with [`LaneIndices`] for all
+/// the widths, which is easiest to write once for any `WIDTH`:
+///
+/// ```
+/// use simdeez::LaneIndices;
+///
+/// /// Swaps the lower and the upper half of the vector.
+/// struct SwapHalves;
+///
+/// impl<const WIDTH: usize> LaneIndices<WIDTH> for SwapHalves {
+///     const INDICES: [usize; WIDTH] = {
+///         let mut indices = [0; WIDTH];
+///         let mut i = 0;
+///         while i < WIDTH {
+///             indices[i] = i + WIDTH / 2;
+///             i += 1;
+///         }
+///         indices
+///     };
+/// }
+/// ```
+pub trait ShufflePattern:
+    LaneIndices<1>
+    + LaneIndices<2>
+    + LaneIndices<4>
+    + LaneIndices<8>
+    + LaneIndices<16>
+    + LaneIndices<32>
+    + LaneIndices<64>
+{
+}
+
+impl<P> ShufflePattern for P where
+    P: LaneIndices<1>
+        + LaneIndices<2>
+        + LaneIndices<4>
+        + LaneIndices<8>
+        + LaneIndices<16>
+        + LaneIndices<32>
+        + LaneIndices<64>
+{
+}
+
+/// Implements `LaneIndices` for any `WIDTH`, with lane `$i` taking lane `$index`.
+macro_rules! impl_lane_indices {
+    ($pattern:ty $(, const $n:ident)*; |$i:ident| $index:expr) => {
+        impl<$(const $n: usize,)* const WIDTH: usize> LaneIndices<WIDTH> for $pattern {
+            const INDICES: [usize; WIDTH] = {
+                let mut indices = [0; WIDTH];
+                let mut $i = 0;
+                while $i < WIDTH {
+                    indices[$i] = $index;
+                    $i += 1;
+                }
+                indices
+            };
+        }
+    };
+}
+/// Reverses the order of the lanes.
+pub struct Reverse;
+impl_lane_indices!(Reverse; |i| WIDTH - 1 - i);
+
+/// Rotates the lanes so that lane `N` ends up first.
+pub struct RotateLanesLeft<const N: usize>;
+impl_lane_indices!(RotateLanesLeft<N>, const N; |i| i + N);
+
+/// Copies lane `N` into every lane.
+pub struct BroadcastLane<const N: usize>;
+impl_lane_indices!(BroadcastLane<N>, const N; |i| N);
+
+/// Swaps each even lane with the odd lane that follows it.
+pub struct SwapPairs;
+impl_lane_indices!(SwapPairs; |i| i ^ 1);
+
+/// Lane `i` takes lane `3 * i + C`, which is where channel `C` of values interleaved in threes
+/// sits.
+pub(crate) struct EveryThird<const C: usize>;
+impl_lane_indices!(EveryThird<C>, const C; |i| 3 * i + C);
+
+/// Lane `j` takes lane `(K * WIDTH + j) / 3`, the lane of its channel that value `K * WIDTH + j` of
+/// three interleaved channels comes from.
+pub(crate) struct ThirdOfValue<const K: usize>;
+impl_lane_indices!(ThirdOfValue<K>, const K; |j| (K * WIDTH + j) / 3);
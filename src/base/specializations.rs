@@ -1,4 +1,5 @@
 use super::transmute::*;
+use super::SimdMask;
 use crate::{Simd, SimdBaseOps};
 use core::ops::*;
 
@@ -36,6 +37,68 @@ pub trait SimdInt:
         SimdInt::shr(self, BY)
     }
 
+    /// Shift each value left by the count in the same lane of `counts`. Only the low bits of each
+    /// count are used, like `wrapping_shl`.
+    fn shl_var(self, counts: Self) -> Self;
+
+    /// Shift each value right by the count in the same lane of `counts`, like `wrapping_shr`. This
+    /// shifts in copies of the sign bit for signed types, and zeroes for unsigned types.
+    fn shr_var(self, counts: Self) -> Self;
+
+    /// Shift each value right by the count in the same lane of `counts`, always shifting in zeroes
+    /// like `shr` does. Only the low bits of each count are used.
+    fn shr_logical(self, counts: Self) -> Self;
+
+    /// Count the ones in the binary representation of each value, like `count_ones`.
+    fn count_ones(self) -> Self;
+
+    /// Count the leading zeroes in the binary representation of each value, like `leading_zeros`.
+    fn leading_zeros(self) -> Self;
+
+    /// Count the trailing zeroes in the binary representation of each value, like
+    /// `trailing_zeros`.
+    #[inline(always)]
+    fn trailing_zeros(self) -> Self {
+        // Subtracting one sets exactly the bits below the lowest set bit.
+        (self - Self::from_i64(1)).and_not(self).count_ones()
+    }
+
+    /// Rotate the bits of each value left by the count in the same lane of `counts`, like
+    /// `rotate_left`.
+    #[inline(always)]
+    fn rotate_left(self, counts: Self) -> Self {
+        let bits = Self::from_i64(core::mem::size_of::<Self::Scalar>() as i64 * 8);
+        self.shl_var(counts) | self.shr_logical(bits - counts)
+    }
+
+    /// Rotate the bits of each value right by the count in the same lane of `counts`, like
+    /// `rotate_right`.
+    #[inline(always)]
+    fn rotate_right(self, counts: Self) -> Self {
+        let bits = Self::from_i64(core::mem::size_of::<Self::Scalar>() as i64 * 8);
+        self.shr_logical(counts) | self.shl_var(bits - counts)
+    }
+
+    /// Rotate the bits of each value left by a constant n bits, like `rotate_left`.
+    #[inline(always)]
+    fn rotate_left_const<const BY: i32>(self) -> Self {
+        let bits = core::mem::size_of::<Self::Scalar>() as i32 * 8;
+        match BY & (bits - 1) {
+            0 => self,
+            by => SimdInt::shl(self, by) | SimdInt::shr(self, bits - by),
+        }
+    }
+
+    /// Rotate the bits of each value right by a constant n bits, like `rotate_right`.
+    #[inline(always)]
+    fn rotate_right_const<const BY: i32>(self) -> Self {
+        let bits = core::mem::size_of::<Self::Scalar>() as i32 * 8;
+        match BY & (bits - 1) {
+            0 => self,
+            by => SimdInt::shr(self, by) | SimdInt::shl(self, bits - by),
+        }
+    }
+
     /// Add every number in the vector together in unsigned arithmetic. When expanding the size of each number,
     /// it treats the numbers as unsigned, meaning the sign bit doesn't get moved around.
     fn horizontal_unsigned_add(self) -> Self::HorizontalAddScalar;
@@ -45,6 +108,16 @@ pub trait SimdInt:
 
 /// Operations shared by 8 bit int types
 pub trait SimdInt8: SimdInt<Scalar = i8, HorizontalAddScalar = i64> + SimdTransmuteI8 {
+    /// Bit cast to u8.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u8(self) -> <Self::Engine as Simd>::Vu8;
+
+    /// Element-wise add, clamping to `i8::MIN..=i8::MAX` instead of wrapping.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Element-wise subtract, clamping to `i8::MIN..=i8::MAX` instead of wrapping.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
     /// Splits the vector into two halves, then extends them both to be i16. This is useful for horizontal adding.
     fn extend_to_i16(self) -> (<Self::Engine as Simd>::Vi16, <Self::Engine as Simd>::Vi16);
 
@@ -70,78 +143,55 @@ pub trait SimdInt8: SimdInt<Scalar = i8, HorizontalAddScalar = i64> + SimdTransm
         a + b
     }
 
-    /// Gets the "mask" of a vector, where each bit in the u32 represents whether the value at that location
-    /// is truthy. A value is truthy either if the highest bit is one, or if any bit is one, depending
-    /// on the instruction set being used. Please always make sure at least the highest bit is set to 1.
-    fn get_mask(self) -> u32;
+    /// Compares each value against every value in `set`, setting the mask where the value equals any of them.
+    /// `set` can hold at most 16 values. Uses a single `pcmpestrm` on SSE4.2 and above.
+    fn cmp_eq_any(self, set: &[i8]) -> Self::Mask;
 
-    /// Checks if any element in the vector is truthy. A value is truthy either if the highest bit is one, or if any bit is one,
-    /// depending on the instruction set being used. Please always make sure at least the highest bit is set to 1.
-    #[inline(always)]
-    fn is_any_truthy(self) -> bool {
-        self.get_mask() != 0
-    }
+    /// Checks whether each value falls within any of the inclusive `(low, high)` ranges, setting the mask
+    /// where it does. At most 8 ranges can be given. Uses a single `pcmpestrm` on SSE4.2 and above.
+    fn cmp_in_ranges(self, ranges: &[(i8, i8)]) -> Self::Mask;
 
-    /// Grabs the index of the last value that matches the given value. If no value matches, returns None.
-    /// Index will always be smaller than Self::WIDTH.
-    #[inline(always)]
-    fn index_of_last_truthy(self) -> Option<usize> {
-        let leading = self.get_mask().leading_zeros();
-        if leading >= Self::WIDTH as u32 {
-            None
-        } else {
-            Some(leading as usize)
-        }
-    }
+    /// Concatenates `low` and `self` (with `low` in the lower lanes), and returns the `Self::WIDTH` values
+    /// starting at lane `N`. `N` must be between 0 and `Self::WIDTH`. This is useful for looking at values
+    /// that straddle two consecutive loads, and maps to `palignr` on SSSE3 and above.
+    fn align_right<const N: i32>(self, low: Self) -> Self;
 
-    /// Grabs the index of the last value that matches the given value. If no value matches, returns None.
-    /// Index will always be smaller than Self::WIDTH.
-    #[inline(always)]
-    fn index_of_last_falsy(self) -> Option<usize> {
-        let leading = self.get_mask().leading_ones();
-        if leading >= Self::WIDTH as u32 {
-            None
-        } else {
-            Some(leading as usize)
-        }
-    }
+    /// Looks up each index in a 16 entry table, producing `table[index]` for indices between 0 and 15
+    /// and 0 for any other index, including negative ones. The same table is used for every lane.
+    /// Maps to `pshufb` on SSSE3 and above, `vqtbl1q` on Neon and `i8x16_swizzle` on Wasm.
+    fn lookup16(table: &[i8; 16], indices: Self) -> Self;
 
-    /// Grabs the index of the first value that matches the given value. If no value matches, returns None.
-    /// Index will always be smaller than Self::WIDTH.
-    #[inline(always)]
-    fn index_of_first_truthy(self) -> Option<usize> {
-        let trailing = self.get_mask().trailing_zeros();
-        if trailing >= Self::WIDTH as u32 {
-            None
-        } else {
-            Some(trailing as usize)
-        }
-    }
+    /// Builds a vector where lane `i` holds lane `indices[i]` of `self`, or 0 when the index is negative
+    /// or not smaller than `Self::WIDTH`. Indices reach across the whole vector, even on engines where
+    /// `pshufb` only looks within each 128 bit lane.
+    fn shuffle_bytes(self, indices: Self) -> Self;
 
     /// Grabs the index of the first value that matches the given value. If no value matches, returns None.
     /// Index will always be smaller than Self::WIDTH.
     #[inline(always)]
-    fn index_of_first_falsy(self) -> Option<usize> {
-        let trailing = self.get_mask().trailing_ones();
+    fn index_of_first_eq(self, value: i8) -> Option<usize> {
+        let value = Self::set1(value);
+        let trailing = self.cmp_eq(value).to_bitmask().trailing_zeros();
         if trailing >= Self::WIDTH as u32 {
             None
         } else {
             Some(trailing as usize)
         }
     }
-
-    /// Grabs the index of the first value that matches the given value. If no value matches, returns None.
-    /// Index will always be smaller than Self::WIDTH.
-    #[inline(always)]
-    fn index_of_first_eq(self, value: i8) -> Option<usize> {
-        let value = Self::set1(value);
-        let mask = self.cmp_eq(value);
-        mask.index_of_first_truthy()
-    }
 }
 
 /// Operations shared by 16 bit int types
 pub trait SimdInt16: SimdInt<Scalar = i16, HorizontalAddScalar = i64> + SimdTransmuteI16 {
+    /// Bit cast to u16.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u16(self) -> <Self::Engine as Simd>::Vu16;
+
+    /// Element-wise add, clamping to `i16::MIN..=i16::MAX` instead of wrapping.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Element-wise subtract, clamping to `i16::MIN..=i16::MAX` instead of wrapping.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
     /// Splits the vector into two halves, then extends them both to be i32. This is useful for horizontal adding.
     fn extend_to_i32(self) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32);
 
@@ -166,6 +216,37 @@ pub trait SimdInt16: SimdInt<Scalar = i16, HorizontalAddScalar = i64> + SimdTran
         let (a, b) = self.unsigned_extend_to_i32();
         a + b
     }
+
+    /// Element-wise multiply, keeping the high 16 bits of each 32 bit product.
+    fn mul_hi(self, rhs: Self) -> Self;
+
+    /// Element-wise Q15 multiply, rounding to nearest: `(a * b + 0x4000) >> 15`. Like
+    /// `_mm_mulhrs_epi16`, `i16::MIN * i16::MIN` wraps around to `i16::MIN`.
+    fn mulhrs(self, rhs: Self) -> Self;
+
+    /// Multiplies the lanes into i32 products, then adds each even product to the odd one after it,
+    /// like `_mm_madd_epi16`. The sums wrap on overflow, which only happens when all four values
+    /// are `i16::MIN`. On single lane vectors this is just the product.
+    fn madd(self, rhs: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Multiplies the lanes into i32 products that can't overflow. The halves are laid out like the
+    /// ones of `extend_to_i32`.
+    #[inline(always)]
+    fn mul_widen(self, rhs: Self) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32) {
+        let (a_lo, a_hi) = self.extend_to_i32();
+        let (b_lo, b_hi) = rhs.extend_to_i32();
+        (a_lo * b_lo, a_hi * b_hi)
+    }
+
+    /// Narrows the lanes of `self` followed by the lanes of `hi` to i8, clamping to `i8::MIN..=i8::MAX`.
+    /// This undoes `extend_to_i16`. Single lane vectors only keep `self`.
+    fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi8;
+
+    /// Like `narrow_saturating`, but clamps to `0..=u8::MAX`.
+    fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu8;
+
+    /// Like `narrow_saturating`, but keeps the low 8 bits of each lane, like `as i8` does.
+    fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi8;
 }
 
 /// Operations shared by 32 bit int types
@@ -177,6 +258,18 @@ pub trait SimdInt32: SimdInt<Scalar = i32, HorizontalAddScalar = i64> + SimdTran
     /// Element-wise cast to f32
     fn cast_f32(self) -> <Self::Engine as Simd>::Vf32;
 
+    /// Splits the vector into two halves, then converts them both to f64. The conversion is exact.
+    fn to_f64_pair(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64);
+
+    /// Bit cast to u32.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u32(self) -> <Self::Engine as Simd>::Vu32;
+
+    /// Transposes `Self::WIDTH` rows in place, so that lane `j` of row `i` ends up in lane `i` of
+    /// row `j`. The shuffles stay in registers. Panics if `rows` does not hold exactly
+    /// `Self::WIDTH` vectors.
+    fn transpose(rows: &mut [Self]);
+
     /// Splits the vector into two halves, then extends them both to be i64. This is useful for horizontal adding.
     fn extend_to_i64(self) -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64);
 
@@ -201,6 +294,28 @@ pub trait SimdInt32: SimdInt<Scalar = i32, HorizontalAddScalar = i64> + SimdTran
         let (a, b) = self.unsigned_extend_to_i64();
         a + b
     }
+
+    /// Element-wise multiply, keeping the high 32 bits of each 64 bit product.
+    fn mul_hi(self, rhs: Self) -> Self;
+
+    /// Multiplies the lanes into i64 products that can't overflow. The halves are laid out like the
+    /// ones of `extend_to_i64`.
+    #[inline(always)]
+    fn mul_widen(self, rhs: Self) -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64) {
+        let (a_lo, a_hi) = self.extend_to_i64();
+        let (b_lo, b_hi) = rhs.extend_to_i64();
+        (a_lo * b_lo, a_hi * b_hi)
+    }
+
+    /// Narrows the lanes of `self` followed by the lanes of `hi` to i16, clamping to `i16::MIN..=i16::MAX`.
+    /// This undoes `extend_to_i32`. Single lane vectors only keep `self`.
+    fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi16;
+
+    /// Like `narrow_saturating`, but clamps to `0..=u16::MAX`.
+    fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu16;
+
+    /// Like `narrow_saturating`, but keeps the low 16 bits of each lane, like `as i16` does.
+    fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi16;
 }
 
 /// Operations shared by 64 bt int types
@@ -212,7 +327,65 @@ pub trait SimdInt64: SimdInt<Scalar = i64, HorizontalAddScalar = i64> + SimdTran
     /// Element-wise cast to f64
     fn cast_f64(self) -> <Self::Engine as Simd>::Vf64;
 
+    /// Bit cast to u64.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_u64(self) -> <Self::Engine as Simd>::Vu64;
+
     fn partial_horizontal_add(self) -> i64;
+
+    /// Narrows the lanes of `self` followed by the lanes of `hi` to i32, clamping to `i32::MIN..=i32::MAX`.
+    /// This undoes `extend_to_i64`. Single lane vectors only keep `self`.
+    fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Like `narrow_saturating`, but clamps to `0..=u32::MAX`.
+    fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu32;
+
+    /// Like `narrow_saturating`, but keeps the low 32 bits of each lane, like `as i32` does.
+    fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi32;
+}
+
+/// Operations shared by 8 bit unsigned int types. Comparisons, `min` and `max` treat the values as
+/// unsigned, and right shifts are logical.
+pub trait SimdUint8: SimdInt<Scalar = u8, HorizontalAddScalar = u64> + SimdTransmuteU8 {
+    /// Bit cast to i8.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i8(self) -> <Self::Engine as Simd>::Vi8;
+
+    /// Element-wise add, clamping to `u8::MIN..=u8::MAX` instead of wrapping.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Element-wise subtract, clamping to `u8::MIN..=u8::MAX` instead of wrapping.
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+/// Operations shared by 16 bit unsigned int types. Comparisons, `min` and `max` treat the values as
+/// unsigned, and right shifts are logical.
+pub trait SimdUint16: SimdInt<Scalar = u16, HorizontalAddScalar = u64> + SimdTransmuteU16 {
+    /// Bit cast to i16.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i16(self) -> <Self::Engine as Simd>::Vi16;
+
+    /// Element-wise add, clamping to `u16::MIN..=u16::MAX` instead of wrapping.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Element-wise subtract, clamping to `u16::MIN..=u16::MAX` instead of wrapping.
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+/// Operations shared by 32 bit unsigned int types. Comparisons, `min` and `max` treat the values as
+/// unsigned, and right shifts are logical.
+pub trait SimdUint32: SimdInt<Scalar = u32, HorizontalAddScalar = u64> + SimdTransmuteU32 {
+    /// Bit cast to i32.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i32(self) -> <Self::Engine as Simd>::Vi32;
+}
+
+/// Operations shared by 64 bit unsigned int types. Comparisons, `min` and `max` treat the values as
+/// unsigned, and right shifts are logical.
+pub trait SimdUint64: SimdInt<Scalar = u64, HorizontalAddScalar = u64> + SimdTransmuteU64 {
+    /// Bit cast to i64.
+    /// This function is only used for compilation and does not generate any instructions, thus it has zero latency.
+    fn bitcast_i64(self) -> <Self::Engine as Simd>::Vi64;
 }
 
 /// Operations shared by f32 and f64 floating point types
@@ -232,8 +405,23 @@ pub trait SimdFloat:
     /// Element-wise floors between two vectors
     fn floor(self) -> Self;
 
-    /// Element-wise rounds between two vectors
-    fn round(self) -> Self;
+    /// Element-wise rounds between two vectors, with ties rounded to even. This is the same as
+    /// `round_ties_even`, and gives identical results on every engine.
+    #[inline(always)]
+    fn round(self) -> Self {
+        self.round_ties_even()
+    }
+
+    /// Element-wise rounds to the nearest integer, with ties rounded to the even integer,
+    /// like `f32::round_ties_even`.
+    fn round_ties_even(self) -> Self;
+
+    /// Element-wise rounds to the nearest integer, with ties rounded away from zero,
+    /// like `f32::round`.
+    fn round_ties_away(self) -> Self;
+
+    /// Element-wise rounds towards zero, like `f32::trunc`.
+    fn trunc(self) -> Self;
 
     /// Alternative element-wise ceilings between two vectors.
     /// When using Sse2, this uses a faster version of ceiling
@@ -257,6 +445,9 @@ pub trait SimdFloat:
     fn fast_round(self) -> Self;
 
     /// Element-wise multiply add. This performs `Self * A + B`
+    ///
+    /// This is only fused on engines with FMA support (e.g. Avx2Fma, Avx512, Neon). Other engines
+    /// perform a separate multiply and add, which rounds twice.
     fn mul_add(self, a: Self, b: Self) -> Self;
 
     /// Element-wise multiply subtract. This performs `Self * A - B`
@@ -274,6 +465,29 @@ pub trait SimdFloat:
     /// Element-wise approximate inverse square root
     fn rsqrt(self) -> Self;
 
+    /// Element-wise check for NaN, like `f32::is_nan`.
+    fn is_nan(self) -> Self::Mask;
+
+    /// Element-wise check for positive or negative infinity, like `f32::is_infinite`.
+    fn is_infinite(self) -> Self::Mask;
+
+    /// Element-wise check for numbers that are neither infinite nor NaN, like `f32::is_finite`.
+    fn is_finite(self) -> Self::Mask;
+
+    /// Element-wise check for a set sign bit, like `f32::is_sign_negative`. This includes `-0.0`
+    /// and NaNs with the sign bit set.
+    fn is_sign_negative(self) -> Self::Mask;
+
+    /// Element-wise magnitude of `self` with the sign of `sign`, like `f32::copysign`.
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Element-wise sign, like `f32::signum`. This is 1.0 or -1.0 following the sign bit, even for
+    /// zeroes, and NaN for NaN.
+    fn signum(self) -> Self;
+
+    /// Element-wise fractional part, like `f32::fract`. This is `self - self.trunc()`.
+    fn fract(self) -> Self;
+
     fn from_f64(value: f64) -> Self;
 }
 
@@ -289,9 +503,49 @@ pub trait SimdFloat32:
     /// numbers outside the range of i32. E.g. a very large positive float may become i32::MIN.
     fn cast_i32(self) -> <Self::Engine as Simd>::Vi32;
 
+    /// Element-wise conversion to i32, rounding towards zero. This matches `x as i32` on every engine:
+    /// numbers outside the range of i32 saturate, and NaN becomes 0.
+    fn to_i32_trunc(self) -> <Self::Engine as Simd>::Vi32;
+
+    /// Element-wise conversion to i32, rounding to nearest with ties away from zero, like
+    /// `x.round() as i32`.
+    #[inline(always)]
+    fn to_i32_round(self) -> <Self::Engine as Simd>::Vi32 {
+        self.round_ties_away().to_i32_trunc()
+    }
+
+    /// Element-wise conversion to i32, rounding down, like `x.floor() as i32`.
+    #[inline(always)]
+    fn to_i32_floor(self) -> <Self::Engine as Simd>::Vi32 {
+        self.floor().to_i32_trunc()
+    }
+
+    /// Element-wise conversion to i32, rounding to nearest with ties to even, like
+    /// `x.round_ties_even() as i32`. Unlike `cast_i32`, every input gives a defined result.
+    #[inline(always)]
+    fn to_i32_round_ties_even(self) -> <Self::Engine as Simd>::Vi32 {
+        self.round_ties_even().to_i32_trunc()
+    }
+
+    /// Element-wise conversion to i32 with the rounding of `cast_i32`, to nearest with ties to
+    /// even, except that numbers outside the range of i32 saturate and NaN becomes 0. This is the
+    /// same as `to_i32_round_ties_even`.
+    #[inline(always)]
+    fn to_i32_saturating(self) -> <Self::Engine as Simd>::Vi32 {
+        self.to_i32_round_ties_even()
+    }
+
     /// Element-wise fast reciprocal (1.0 / x)
     fn fast_inverse(self) -> Self;
 
+    /// Splits the vector into two halves, then converts them both to f64. The conversion is exact.
+    fn to_f64_pair(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64);
+
+    /// Transposes `Self::WIDTH` rows in place, so that lane `j` of row `i` ends up in lane `i` of
+    /// row `j`. The shuffles stay in registers. Panics if `rows` does not hold exactly
+    /// `Self::WIDTH` vectors.
+    fn transpose(rows: &mut [Self]);
+
     cfg_if::cfg_if! {
         if #[cfg(feature = "sleef")] {
             fn sin(a: Self::Vf32) -> Self::Vf32;
@@ -342,4 +596,45 @@ pub trait SimdFloat64:
 
     /// Element-wise cast to i64 (rounded, not floored).
     fn cast_i64(self) -> <Self::Engine as Simd>::Vi64;
+
+    /// Element-wise conversion to i64, rounding towards zero. This matches `x as i64` on every engine:
+    /// numbers outside the range of i64 saturate, and NaN becomes 0.
+    fn to_i64_trunc(self) -> <Self::Engine as Simd>::Vi64;
+
+    /// Element-wise conversion to i64, rounding to nearest with ties away from zero, like
+    /// `x.round() as i64`.
+    #[inline(always)]
+    fn to_i64_round(self) -> <Self::Engine as Simd>::Vi64 {
+        self.round_ties_away().to_i64_trunc()
+    }
+
+    /// Element-wise conversion to i64, rounding down, like `x.floor() as i64`.
+    #[inline(always)]
+    fn to_i64_floor(self) -> <Self::Engine as Simd>::Vi64 {
+        self.floor().to_i64_trunc()
+    }
+
+    /// Element-wise conversion to i64, rounding to nearest with ties to even, like
+    /// `x.round_ties_even() as i64`. Unlike `cast_i64`, every input gives a defined result.
+    #[inline(always)]
+    fn to_i64_round_ties_even(self) -> <Self::Engine as Simd>::Vi64 {
+        self.round_ties_even().to_i64_trunc()
+    }
+
+    /// Element-wise conversion to i64 with the rounding of `cast_i64`, to nearest with ties to
+    /// even, except that numbers outside the range of i64 saturate and NaN becomes 0. This is the
+    /// same as `to_i64_round_ties_even`.
+    #[inline(always)]
+    fn to_i64_saturating(self) -> <Self::Engine as Simd>::Vi64 {
+        self.to_i64_round_ties_even()
+    }
+
+    /// Converts the lanes of `self` followed by the lanes of `hi` to f32, rounding to nearest like
+    /// `as f32`. This undoes `to_f64_pair`. Single lane vectors only keep `self`.
+    fn to_f32_narrow(self, hi: Self) -> <Self::Engine as Simd>::Vf32;
+
+    /// Converts the lanes of `self` followed by the lanes of `hi` to i32, rounding to nearest with
+    /// ties to even. Numbers outside the range of i32 saturate and NaN becomes 0, like
+    /// `x.round_ties_even() as i32`. Single lane vectors only keep `self`.
+    fn to_i32_narrow(self, hi: Self) -> <Self::Engine as Simd>::Vi32;
 }
@@ -8,7 +8,7 @@ use core::arch::x86::*;
 use core::arch::x86_64::*;
 
 macro_rules! make_simd_transmute {
-    ($name:ident, $scalar:ident, $sse:ident, $avx:ident, $neon:ident, $wasm:ident) => {
+    ($name:ident, $scalar:ident, $sse:ident, $avx:ident, $avx512:ident, $neon:ident, $wasm:ident) => {
         pub trait $name: Sized {
             /// Tries to transmute the value into its underlying scalar type. Panics if the value is not a scalar.
             fn try_transmute_scalar(&self) -> $scalar {
@@ -32,6 +32,18 @@ macro_rules! make_simd_transmute {
                 panic!("Invalid transmute: tried to transmute non-sse2 into sse2");
             }
 
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Ssse3 type. Panics if the value is not a Ssse3.
+            fn try_transmute_ssse3(&self) -> $sse {
+                panic!("Invalid transmute: tried to transmute non-ssse3 into ssse3");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Ssse3 type. Panics if the value is not a Ssse3.
+            fn try_transmute_from_ssse3(_ssse3: $sse) -> Self {
+                panic!("Invalid transmute: tried to transmute non-ssse3 into ssse3");
+            }
+
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
             /// Tries to transmute the value into its underlying Sse4.1 type. Panics if the value is not a Sse4.1.
             fn try_transmute_sse41(&self) -> $sse {
@@ -44,6 +56,30 @@ macro_rules! make_simd_transmute {
                 panic!("Invalid transmute: tried to transmute non-sse41 into sse41");
             }
 
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Sse4.2 type. Panics if the value is not a Sse4.2.
+            fn try_transmute_sse42(&self) -> $sse {
+                panic!("Invalid transmute: tried to transmute non-sse42 into sse42");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Sse4.2 type. Panics if the value is not a Sse4.2.
+            fn try_transmute_from_sse42(_sse42: $sse) -> Self {
+                panic!("Invalid transmute: tried to transmute non-sse42 into sse42");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Avx type. Panics if the value is not a Avx.
+            fn try_transmute_avx(&self) -> $avx {
+                panic!("Invalid transmute: tried to transmute non-avx into avx");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Avx type. Panics if the value is not a Avx.
+            fn try_transmute_from_avx(_avx: $avx) -> Self {
+                panic!("Invalid transmute: tried to transmute non-avx into avx");
+            }
+
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
             /// Tries to transmute the value into its underlying Avx2 type. Panics if the value is not a Avx2.
             fn try_transmute_avx2(&self) -> $avx {
@@ -56,6 +92,30 @@ macro_rules! make_simd_transmute {
                 panic!("Invalid transmute: tried to transmute non-avx2 into avx2");
             }
 
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Avx2Fma type. Panics if the value is not a Avx2Fma.
+            fn try_transmute_avx2fma(&self) -> $avx {
+                panic!("Invalid transmute: tried to transmute non-avx2fma into avx2fma");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Avx2Fma type. Panics if the value is not a Avx2Fma.
+            fn try_transmute_from_avx2fma(_avx2fma: $avx) -> Self {
+                panic!("Invalid transmute: tried to transmute non-avx2fma into avx2fma");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to transmute the value into its underlying Avx512 type. Panics if the value is not a Avx512.
+            fn try_transmute_avx512(&self) -> $avx512 {
+                panic!("Invalid transmute: tried to transmute non-avx512 into avx512");
+            }
+
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            /// Tries to create the value from its underlying Avx512 type. Panics if the value is not a Avx512.
+            fn try_transmute_from_avx512(_avx512: $avx512) -> Self {
+                panic!("Invalid transmute: tried to transmute non-avx512 into avx512");
+            }
+
             #[cfg(target_arch = "aarch64")]
             /// Tries to transmute the value into its underlying Neon type. Panics if the value is not a Neon.
             fn try_transmute_neon(&self) -> $neon {
@@ -83,9 +143,93 @@ macro_rules! make_simd_transmute {
     };
 }
 
-make_simd_transmute!(SimdTransmuteF32, f32, __m128, __m256, float32x4_t, v128);
-make_simd_transmute!(SimdTransmuteF64, f64, __m128d, __m256d, float64x2_t, v128);
-make_simd_transmute!(SimdTransmuteI8, i8, __m128i, __m256i, int8x16_t, v128);
-make_simd_transmute!(SimdTransmuteI16, i16, __m128i, __m256i, int16x8_t, v128);
-make_simd_transmute!(SimdTransmuteI32, i32, __m128i, __m256i, int32x4_t, v128);
-make_simd_transmute!(SimdTransmuteI64, i64, __m128i, __m256i, int64x2_t, v128);
+make_simd_transmute!(
+    SimdTransmuteF32,
+    f32,
+    __m128,
+    __m256,
+    __m512,
+    float32x4_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteF64,
+    f64,
+    __m128d,
+    __m256d,
+    __m512d,
+    float64x2_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteI8,
+    i8,
+    __m128i,
+    __m256i,
+    __m512i,
+    int8x16_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteI16,
+    i16,
+    __m128i,
+    __m256i,
+    __m512i,
+    int16x8_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteI32,
+    i32,
+    __m128i,
+    __m256i,
+    __m512i,
+    int32x4_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteI64,
+    i64,
+    __m128i,
+    __m256i,
+    __m512i,
+    int64x2_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU8,
+    u8,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint8x16_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU16,
+    u16,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint16x8_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU32,
+    u32,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint32x4_t,
+    v128
+);
+make_simd_transmute!(
+    SimdTransmuteU64,
+    u64,
+    __m128i,
+    __m256i,
+    __m512i,
+    uint64x2_t,
+    v128
+);
@@ -0,0 +1,59 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+use crate::ops::*;
+use crate::*;
+
+mod simd;
+pub use self::simd::*;
+
+define_simd_mask!(Avx, M8x32Avx, i8, 32, __m256i);
+define_simd_mask!(Avx, M16x16Avx, i16, 16, __m256i);
+define_simd_mask!(Avx, M32x8Avx, i32, 8, __m256i);
+define_simd_mask!(Avx, M64x4Avx, i64, 4, __m256i);
+
+define_simd_type!(Avx, i8, 32, __m256i, Avx);
+impl_simd_int_overloads!(I8x32Avx);
+impl_i8_simd_type!(Avx, I8x32Avx, I16x16Avx, U8x32Avx, M8x32Avx);
+
+define_simd_type!(Avx, i16, 16, __m256i, Avx);
+impl_simd_int_overloads!(I16x16Avx);
+impl_i16_simd_type!(Avx, I16x16Avx, I8x32Avx, I32x8Avx, U8x32Avx, U16x16Avx, M16x16Avx);
+
+define_simd_type!(Avx, i32, 8, __m256i, Avx);
+impl_simd_int_overloads!(I32x8Avx);
+impl_i32_simd_type!(
+    Avx, I32x8Avx, I16x16Avx, F32x8Avx, F64x4Avx, I64x4Avx, U16x16Avx, U32x8Avx, M32x8Avx
+);
+
+define_simd_type!(Avx, i64, 4, __m256i, Avx);
+impl_simd_int_overloads!(I64x4Avx);
+impl_i64_simd_type!(Avx, I64x4Avx, I32x8Avx, F64x4Avx, U32x8Avx, U64x4Avx, M64x4Avx);
+
+define_simd_type!(Avx, u8, 32, __m256i, Avx);
+impl_simd_int_overloads!(U8x32Avx);
+impl_u8_simd_type!(Avx, U8x32Avx, I8x32Avx, M8x32Avx);
+
+define_simd_type!(Avx, u16, 16, __m256i, Avx);
+impl_simd_int_overloads!(U16x16Avx);
+impl_u16_simd_type!(Avx, U16x16Avx, I16x16Avx, M16x16Avx);
+
+define_simd_type!(Avx, u32, 8, __m256i, Avx);
+impl_simd_int_overloads!(U32x8Avx);
+impl_u32_simd_type!(Avx, U32x8Avx, I32x8Avx, M32x8Avx);
+
+define_simd_type!(Avx, u64, 4, __m256i, Avx);
+impl_simd_int_overloads!(U64x4Avx);
+impl_u64_simd_type!(Avx, U64x4Avx, I64x4Avx, M64x4Avx);
+
+define_simd_type!(Avx, f32, 8, __m256, Avx);
+impl_simd_float_overloads!(F32x8Avx);
+impl_f32_simd_type!(Avx, F32x8Avx, I32x8Avx, F64x4Avx, M32x8Avx);
+
+define_simd_type!(Avx, f64, 4, __m256d, Avx);
+impl_simd_float_overloads!(F64x4Avx);
+impl_f64_simd_type!(Avx, F64x4Avx, F32x8Avx, I32x8Avx, I64x4Avx, M64x4Avx);
@@ -0,0 +1,53 @@
+#![allow(deprecated)]
+
+use super::*;
+use crate::Simd;
+
+pub struct Avx;
+impl Simd for Avx {
+    type Vi8 = I8x32Avx;
+    type Vi16 = I16x16Avx;
+    type Vi32 = I32x8Avx;
+    type Vf32 = F32x8Avx;
+    type Vf64 = F64x4Avx;
+    type Vi64 = I64x4Avx;
+    type Vu8 = U8x32Avx;
+    type Vu16 = U16x16Avx;
+    type Vu32 = U32x8Avx;
+    type Vu64 = U64x4Avx;
+    type Mi8 = M8x32Avx;
+    type Mi16 = M16x16Avx;
+    type Mi32 = M32x8Avx;
+    type Mi64 = M64x4Avx;
+    type Mf32 = M32x8Avx;
+    type Mf64 = M64x4Avx;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "avx")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x4Avx(_mm256_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x8Avx(_mm256_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        I32x8Avx(_mm256_castps_si256(_mm256_permute_ps(
+            _mm256_castsi256_ps(a.0),
+            IMM8,
+        )))
+    }
+}
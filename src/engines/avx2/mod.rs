@@ -11,26 +11,47 @@ use crate::*;
 mod simd;
 pub use self::simd::*;
 
+define_simd_mask!(Avx2, M8x32, i8, 32, __m256i);
+define_simd_mask!(Avx2, M16x16, i16, 16, __m256i);
+define_simd_mask!(Avx2, M32x8, i32, 8, __m256i);
+define_simd_mask!(Avx2, M64x4, i64, 4, __m256i);
+
 define_simd_type!(Avx2, i8, 32, __m256i);
 impl_simd_int_overloads!(I8x32);
-impl_i8_simd_type!(Avx2, I8x32, I16x16);
+impl_i8_simd_type!(Avx2, I8x32, I16x16, U8x32, M8x32);
 
 define_simd_type!(Avx2, i16, 16, __m256i);
 impl_simd_int_overloads!(I16x16);
-impl_i16_simd_type!(Avx2, I16x16, I32x8);
+impl_i16_simd_type!(Avx2, I16x16, I8x32, I32x8, U8x32, U16x16, M16x16);
 
 define_simd_type!(Avx2, i32, 8, __m256i);
 impl_simd_int_overloads!(I32x8);
-impl_i32_simd_type!(Avx2, I32x8, F32x8, I64x4);
+impl_i32_simd_type!(Avx2, I32x8, I16x16, F32x8, F64x4, I64x4, U16x16, U32x8, M32x8);
 
 define_simd_type!(Avx2, i64, 4, __m256i);
 impl_simd_int_overloads!(I64x4);
-impl_i64_simd_type!(Avx2, I64x4, F64x4);
+impl_i64_simd_type!(Avx2, I64x4, I32x8, F64x4, U32x8, U64x4, M64x4);
+
+define_simd_type!(Avx2, u8, 32, __m256i);
+impl_simd_int_overloads!(U8x32);
+impl_u8_simd_type!(Avx2, U8x32, I8x32, M8x32);
+
+define_simd_type!(Avx2, u16, 16, __m256i);
+impl_simd_int_overloads!(U16x16);
+impl_u16_simd_type!(Avx2, U16x16, I16x16, M16x16);
+
+define_simd_type!(Avx2, u32, 8, __m256i);
+impl_simd_int_overloads!(U32x8);
+impl_u32_simd_type!(Avx2, U32x8, I32x8, M32x8);
+
+define_simd_type!(Avx2, u64, 4, __m256i);
+impl_simd_int_overloads!(U64x4);
+impl_u64_simd_type!(Avx2, U64x4, I64x4, M64x4);
 
 define_simd_type!(Avx2, f32, 8, __m256);
 impl_simd_float_overloads!(F32x8);
-impl_f32_simd_type!(Avx2, F32x8, I32x8);
+impl_f32_simd_type!(Avx2, F32x8, I32x8, F64x4, M32x8);
 
 define_simd_type!(Avx2, f64, 4, __m256d);
 impl_simd_float_overloads!(F64x4);
-impl_f64_simd_type!(Avx2, F64x4, I64x4);
+impl_f64_simd_type!(Avx2, F64x4, F32x8, I32x8, I64x4, M64x4);
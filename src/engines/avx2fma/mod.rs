@@ -0,0 +1,102 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+use crate::ops::*;
+use crate::*;
+
+mod simd;
+pub use self::simd::*;
+
+define_simd_mask!(Avx2Fma, M8x32Avx2Fma, i8, 32, __m256i);
+define_simd_mask!(Avx2Fma, M16x16Avx2Fma, i16, 16, __m256i);
+define_simd_mask!(Avx2Fma, M32x8Avx2Fma, i32, 8, __m256i);
+define_simd_mask!(Avx2Fma, M64x4Avx2Fma, i64, 4, __m256i);
+
+define_simd_type!(Avx2Fma, i8, 32, __m256i, Avx2Fma);
+impl_simd_int_overloads!(I8x32Avx2Fma);
+impl_i8_simd_type!(
+    Avx2Fma,
+    I8x32Avx2Fma,
+    I16x16Avx2Fma,
+    U8x32Avx2Fma,
+    M8x32Avx2Fma
+);
+
+define_simd_type!(Avx2Fma, i16, 16, __m256i, Avx2Fma);
+impl_simd_int_overloads!(I16x16Avx2Fma);
+impl_i16_simd_type!(
+    Avx2Fma,
+    I16x16Avx2Fma,
+    I8x32Avx2Fma,
+    I32x8Avx2Fma,
+    U8x32Avx2Fma,
+    U16x16Avx2Fma,
+    M16x16Avx2Fma
+);
+
+define_simd_type!(Avx2Fma, i32, 8, __m256i, Avx2Fma);
+impl_simd_int_overloads!(I32x8Avx2Fma);
+impl_i32_simd_type!(
+    Avx2Fma,
+    I32x8Avx2Fma,
+    I16x16Avx2Fma,
+    F32x8Avx2Fma,
+    F64x4Avx2Fma,
+    I64x4Avx2Fma,
+    U16x16Avx2Fma,
+    U32x8Avx2Fma,
+    M32x8Avx2Fma
+);
+
+define_simd_type!(Avx2Fma, i64, 4, __m256i, Avx2Fma);
+impl_simd_int_overloads!(I64x4Avx2Fma);
+impl_i64_simd_type!(
+    Avx2Fma,
+    I64x4Avx2Fma,
+    I32x8Avx2Fma,
+    F64x4Avx2Fma,
+    U32x8Avx2Fma,
+    U64x4Avx2Fma,
+    M64x4Avx2Fma
+);
+
+define_simd_type!(Avx2Fma, u8, 32, __m256i, Avx2Fma);
+impl_simd_int_overloads!(U8x32Avx2Fma);
+impl_u8_simd_type!(Avx2Fma, U8x32Avx2Fma, I8x32Avx2Fma, M8x32Avx2Fma);
+
+define_simd_type!(Avx2Fma, u16, 16, __m256i, Avx2Fma);
+impl_simd_int_overloads!(U16x16Avx2Fma);
+impl_u16_simd_type!(Avx2Fma, U16x16Avx2Fma, I16x16Avx2Fma, M16x16Avx2Fma);
+
+define_simd_type!(Avx2Fma, u32, 8, __m256i, Avx2Fma);
+impl_simd_int_overloads!(U32x8Avx2Fma);
+impl_u32_simd_type!(Avx2Fma, U32x8Avx2Fma, I32x8Avx2Fma, M32x8Avx2Fma);
+
+define_simd_type!(Avx2Fma, u64, 4, __m256i, Avx2Fma);
+impl_simd_int_overloads!(U64x4Avx2Fma);
+impl_u64_simd_type!(Avx2Fma, U64x4Avx2Fma, I64x4Avx2Fma, M64x4Avx2Fma);
+
+define_simd_type!(Avx2Fma, f32, 8, __m256, Avx2Fma);
+impl_simd_float_overloads!(F32x8Avx2Fma);
+impl_f32_simd_type!(
+    Avx2Fma,
+    F32x8Avx2Fma,
+    I32x8Avx2Fma,
+    F64x4Avx2Fma,
+    M32x8Avx2Fma
+);
+
+define_simd_type!(Avx2Fma, f64, 4, __m256d, Avx2Fma);
+impl_simd_float_overloads!(F64x4Avx2Fma);
+impl_f64_simd_type!(
+    Avx2Fma,
+    F64x4Avx2Fma,
+    F32x8Avx2Fma,
+    I32x8Avx2Fma,
+    I64x4Avx2Fma,
+    M64x4Avx2Fma
+);
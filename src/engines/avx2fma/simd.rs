@@ -0,0 +1,50 @@
+#![allow(deprecated)]
+
+use super::*;
+use crate::Simd;
+
+pub struct Avx2Fma;
+impl Simd for Avx2Fma {
+    type Vi8 = I8x32Avx2Fma;
+    type Vi16 = I16x16Avx2Fma;
+    type Vi32 = I32x8Avx2Fma;
+    type Vf32 = F32x8Avx2Fma;
+    type Vf64 = F64x4Avx2Fma;
+    type Vi64 = I64x4Avx2Fma;
+    type Vu8 = U8x32Avx2Fma;
+    type Vu16 = U16x16Avx2Fma;
+    type Vu32 = U32x8Avx2Fma;
+    type Vu64 = U64x4Avx2Fma;
+    type Mi8 = M8x32Avx2Fma;
+    type Mi16 = M16x16Avx2Fma;
+    type Mi32 = M32x8Avx2Fma;
+    type Mi64 = M64x4Avx2Fma;
+    type Mf32 = M32x8Avx2Fma;
+    type Mf64 = M64x4Avx2Fma;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x4Avx2Fma(_mm256_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x8Avx2Fma(_mm256_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        I32x8Avx2Fma(_mm256_shuffle_epi32(a.0, IMM8))
+    }
+}
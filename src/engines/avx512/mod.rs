@@ -0,0 +1,57 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+use crate::ops::*;
+use crate::*;
+
+mod simd;
+pub use self::simd::*;
+
+define_simd_mask!(Avx512, M8x64, i8, 64, __mmask64);
+define_simd_mask!(Avx512, M16x32, i16, 32, __mmask32);
+define_simd_mask!(Avx512, M32x16, i32, 16, __mmask16);
+define_simd_mask!(Avx512, M64x8, i64, 8, __mmask8);
+
+define_simd_type!(Avx512, i8, 64, __m512i);
+impl_simd_int_overloads!(I8x64);
+impl_i8_simd_type!(Avx512, I8x64, I16x32, U8x64, M8x64);
+
+define_simd_type!(Avx512, i16, 32, __m512i);
+impl_simd_int_overloads!(I16x32);
+impl_i16_simd_type!(Avx512, I16x32, I8x64, I32x16, U8x64, U16x32, M16x32);
+
+define_simd_type!(Avx512, i32, 16, __m512i);
+impl_simd_int_overloads!(I32x16);
+impl_i32_simd_type!(Avx512, I32x16, I16x32, F32x16, F64x8, I64x8, U16x32, U32x16, M32x16);
+
+define_simd_type!(Avx512, i64, 8, __m512i);
+impl_simd_int_overloads!(I64x8);
+impl_i64_simd_type!(Avx512, I64x8, I32x16, F64x8, U32x16, U64x8, M64x8);
+
+define_simd_type!(Avx512, u8, 64, __m512i);
+impl_simd_int_overloads!(U8x64);
+impl_u8_simd_type!(Avx512, U8x64, I8x64, M8x64);
+
+define_simd_type!(Avx512, u16, 32, __m512i);
+impl_simd_int_overloads!(U16x32);
+impl_u16_simd_type!(Avx512, U16x32, I16x32, M16x32);
+
+define_simd_type!(Avx512, u32, 16, __m512i);
+impl_simd_int_overloads!(U32x16);
+impl_u32_simd_type!(Avx512, U32x16, I32x16, M32x16);
+
+define_simd_type!(Avx512, u64, 8, __m512i);
+impl_simd_int_overloads!(U64x8);
+impl_u64_simd_type!(Avx512, U64x8, I64x8, M64x8);
+
+define_simd_type!(Avx512, f32, 16, __m512);
+impl_simd_float_overloads!(F32x16);
+impl_f32_simd_type!(Avx512, F32x16, I32x16, F64x8, M32x16);
+
+define_simd_type!(Avx512, f64, 8, __m512d);
+impl_simd_float_overloads!(F64x8);
+impl_f64_simd_type!(Avx512, F64x8, F32x16, I32x16, I64x8, M64x8);
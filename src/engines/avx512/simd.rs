@@ -0,0 +1,50 @@
+#![allow(deprecated)]
+
+use super::*;
+use crate::Simd;
+
+pub struct Avx512;
+impl Simd for Avx512 {
+    type Vi8 = I8x64;
+    type Vi16 = I16x32;
+    type Vi32 = I32x16;
+    type Vf32 = F32x16;
+    type Vf64 = F64x8;
+    type Vi64 = I64x8;
+    type Vu8 = U8x64;
+    type Vu16 = U16x32;
+    type Vu32 = U32x16;
+    type Vu64 = U64x8;
+    type Mi8 = M8x64;
+    type Mi16 = M16x32;
+    type Mi32 = M32x16;
+    type Mi64 = M64x8;
+    type Mf32 = M32x16;
+    type Mf64 = M64x8;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "avx512f,avx512bw,avx512dq")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x8(_mm512_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x16(_mm512_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        I32x16(_mm512_shuffle_epi32::<IMM8>(a.0))
+    }
+}
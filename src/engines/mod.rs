@@ -1,11 +1,21 @@
 pub mod scalar;
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod avx;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub mod avx2;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod avx2fma;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod avx512;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub mod sse2;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub mod sse41;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod sse42;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub mod ssse3;
 
 #[cfg(target_arch = "aarch64")]
 pub mod neon;
@@ -9,26 +9,49 @@ use crate::*;
 mod simd;
 pub use self::simd::*;
 
+define_simd_mask!(Neon, M8x16Neon, i8, 16, int8x16_t);
+define_simd_mask!(Neon, M16x8Neon, i16, 8, int16x8_t);
+define_simd_mask!(Neon, M32x4Neon, i32, 4, int32x4_t);
+define_simd_mask!(Neon, M64x2Neon, i64, 2, int64x2_t);
+
 define_simd_type!(Neon, i8, 16, int8x16_t, Neon);
 impl_simd_int_overloads!(I8x16Neon);
-impl_i8_simd_type!(Neon, I8x16Neon, I16x8Neon);
+impl_i8_simd_type!(Neon, I8x16Neon, I16x8Neon, U8x16Neon, M8x16Neon);
 
 define_simd_type!(Neon, i16, 8, int16x8_t, Neon);
 impl_simd_int_overloads!(I16x8Neon);
-impl_i16_simd_type!(Neon, I16x8Neon, I32x4Neon);
+impl_i16_simd_type!(Neon, I16x8Neon, I8x16Neon, I32x4Neon, U8x16Neon, U16x8Neon, M16x8Neon);
 
 define_simd_type!(Neon, i32, 4, int32x4_t, Neon);
 impl_simd_int_overloads!(I32x4Neon);
-impl_i32_simd_type!(Neon, I32x4Neon, F32x4Neon, I64x2Neon);
+impl_i32_simd_type!(
+    Neon, I32x4Neon, I16x8Neon, F32x4Neon, F64x2Neon, I64x2Neon, U16x8Neon, U32x4Neon, M32x4Neon
+);
 
 define_simd_type!(Neon, i64, 2, int64x2_t, Neon);
 impl_simd_int_overloads!(I64x2Neon);
-impl_i64_simd_type!(Neon, I64x2Neon, F64x2Neon);
+impl_i64_simd_type!(Neon, I64x2Neon, I32x4Neon, F64x2Neon, U32x4Neon, U64x2Neon, M64x2Neon);
+
+define_simd_type!(Neon, u8, 16, uint8x16_t, Neon);
+impl_simd_int_overloads!(U8x16Neon);
+impl_u8_simd_type!(Neon, U8x16Neon, I8x16Neon, M8x16Neon);
+
+define_simd_type!(Neon, u16, 8, uint16x8_t, Neon);
+impl_simd_int_overloads!(U16x8Neon);
+impl_u16_simd_type!(Neon, U16x8Neon, I16x8Neon, M16x8Neon);
+
+define_simd_type!(Neon, u32, 4, uint32x4_t, Neon);
+impl_simd_int_overloads!(U32x4Neon);
+impl_u32_simd_type!(Neon, U32x4Neon, I32x4Neon, M32x4Neon);
+
+define_simd_type!(Neon, u64, 2, uint64x2_t, Neon);
+impl_simd_int_overloads!(U64x2Neon);
+impl_u64_simd_type!(Neon, U64x2Neon, I64x2Neon, M64x2Neon);
 
 define_simd_type!(Neon, f32, 4, float32x4_t, Neon);
 impl_simd_float_overloads!(F32x4Neon);
-impl_f32_simd_type!(Neon, F32x4Neon, I32x4Neon);
+impl_f32_simd_type!(Neon, F32x4Neon, I32x4Neon, F64x2Neon, M32x4Neon);
 
 define_simd_type!(Neon, f64, 2, float64x2_t, Neon);
 impl_simd_float_overloads!(F64x2Neon);
-impl_f64_simd_type!(Neon, F64x2Neon, I64x2Neon);
+impl_f64_simd_type!(Neon, F64x2Neon, F32x4Neon, I32x4Neon, I64x2Neon, M64x2Neon);
@@ -6,29 +6,50 @@ use crate::*;
 mod simd;
 pub use self::simd::*;
 
+define_simd_mask!(Scalar, M8x1, i8, 1, i8);
+define_simd_mask!(Scalar, M16x1, i16, 1, i16);
+define_simd_mask!(Scalar, M32x1, i32, 1, i32);
+define_simd_mask!(Scalar, M64x1, i64, 1, i64);
+
 define_simd_type!(Scalar, i8, 1, i8);
 impl_simd_int_overloads!(I8x1);
-impl_i8_simd_type!(Scalar, I8x1, I16x1);
+impl_i8_simd_type!(Scalar, I8x1, I16x1, U8x1, M8x1);
 
 define_simd_type!(Scalar, i16, 1, i16);
 impl_simd_int_overloads!(I16x1);
-impl_i16_simd_type!(Scalar, I16x1, I32x1);
+impl_i16_simd_type!(Scalar, I16x1, I8x1, I32x1, U8x1, U16x1, M16x1);
 
 define_simd_type!(Scalar, i32, 1, i32);
 impl_simd_int_overloads!(I32x1);
-impl_i32_simd_type!(Scalar, I32x1, F32x1, I64x1);
+impl_i32_simd_type!(Scalar, I32x1, I16x1, F32x1, F64x1, I64x1, U16x1, U32x1, M32x1);
 
 define_simd_type!(Scalar, i64, 1, i64);
 impl_simd_int_overloads!(I64x1);
-impl_i64_simd_type!(Scalar, I64x1, F64x1);
+impl_i64_simd_type!(Scalar, I64x1, I32x1, F64x1, U32x1, U64x1, M64x1);
+
+define_simd_type!(Scalar, u8, 1, u8);
+impl_simd_int_overloads!(U8x1);
+impl_u8_simd_type!(Scalar, U8x1, I8x1, M8x1);
+
+define_simd_type!(Scalar, u16, 1, u16);
+impl_simd_int_overloads!(U16x1);
+impl_u16_simd_type!(Scalar, U16x1, I16x1, M16x1);
+
+define_simd_type!(Scalar, u32, 1, u32);
+impl_simd_int_overloads!(U32x1);
+impl_u32_simd_type!(Scalar, U32x1, I32x1, M32x1);
+
+define_simd_type!(Scalar, u64, 1, u64);
+impl_simd_int_overloads!(U64x1);
+impl_u64_simd_type!(Scalar, U64x1, I64x1, M64x1);
 
 define_simd_type!(Scalar, f32, 1, f32);
 impl_simd_float_overloads!(F32x1);
-impl_f32_simd_type!(Scalar, F32x1, I32x1);
+impl_f32_simd_type!(Scalar, F32x1, I32x1, F64x1, M32x1);
 
 define_simd_type!(Scalar, f64, 1, f64);
 impl_simd_float_overloads!(F64x1);
-impl_f64_simd_type!(Scalar, F64x1, I64x1);
+impl_f64_simd_type!(Scalar, F64x1, F32x1, I32x1, I64x1, M64x1);
 
 impl From<i8> for I8x1 {
     fn from(val: i8) -> Self {
@@ -54,6 +75,30 @@ impl From<i64> for I64x1 {
     }
 }
 
+impl From<u8> for U8x1 {
+    fn from(val: u8) -> Self {
+        U8x1(val)
+    }
+}
+
+impl From<u16> for U16x1 {
+    fn from(val: u16) -> Self {
+        U16x1(val)
+    }
+}
+
+impl From<u32> for U32x1 {
+    fn from(val: u32) -> Self {
+        U32x1(val)
+    }
+}
+
+impl From<u64> for U64x1 {
+    fn from(val: u64) -> Self {
+        U64x1(val)
+    }
+}
+
 impl From<f32> for F32x1 {
     fn from(val: f32) -> Self {
         F32x1(val)
@@ -0,0 +1,60 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+use crate::ops::*;
+use crate::*;
+
+mod simd;
+pub use self::simd::*;
+
+define_simd_mask!(Sse42, M8x16Sse42, i8, 16, __m128i);
+define_simd_mask!(Sse42, M16x8Sse42, i16, 8, __m128i);
+define_simd_mask!(Sse42, M32x4Sse42, i32, 4, __m128i);
+define_simd_mask!(Sse42, M64x2Sse42, i64, 2, __m128i);
+
+define_simd_type!(Sse42, i8, 16, __m128i, Sse42);
+impl_simd_int_overloads!(I8x16Sse42);
+impl_i8_simd_type!(Sse42, I8x16Sse42, I16x8Sse42, U8x16Sse42, M8x16Sse42);
+
+define_simd_type!(Sse42, i16, 8, __m128i, Sse42);
+impl_simd_int_overloads!(I16x8Sse42);
+impl_i16_simd_type!(Sse42, I16x8Sse42, I8x16Sse42, I32x4Sse42, U8x16Sse42, U16x8Sse42, M16x8Sse42);
+
+define_simd_type!(Sse42, i32, 4, __m128i, Sse42);
+impl_simd_int_overloads!(I32x4Sse42);
+impl_i32_simd_type!(
+    Sse42, I32x4Sse42, I16x8Sse42, F32x4Sse42, F64x2Sse42, I64x2Sse42, U16x8Sse42, U32x4Sse42,
+    M32x4Sse42
+);
+
+define_simd_type!(Sse42, i64, 2, __m128i, Sse42);
+impl_simd_int_overloads!(I64x2Sse42);
+impl_i64_simd_type!(Sse42, I64x2Sse42, I32x4Sse42, F64x2Sse42, U32x4Sse42, U64x2Sse42, M64x2Sse42);
+
+define_simd_type!(Sse42, u8, 16, __m128i, Sse42);
+impl_simd_int_overloads!(U8x16Sse42);
+impl_u8_simd_type!(Sse42, U8x16Sse42, I8x16Sse42, M8x16Sse42);
+
+define_simd_type!(Sse42, u16, 8, __m128i, Sse42);
+impl_simd_int_overloads!(U16x8Sse42);
+impl_u16_simd_type!(Sse42, U16x8Sse42, I16x8Sse42, M16x8Sse42);
+
+define_simd_type!(Sse42, u32, 4, __m128i, Sse42);
+impl_simd_int_overloads!(U32x4Sse42);
+impl_u32_simd_type!(Sse42, U32x4Sse42, I32x4Sse42, M32x4Sse42);
+
+define_simd_type!(Sse42, u64, 2, __m128i, Sse42);
+impl_simd_int_overloads!(U64x2Sse42);
+impl_u64_simd_type!(Sse42, U64x2Sse42, I64x2Sse42, M64x2Sse42);
+
+define_simd_type!(Sse42, f32, 4, __m128, Sse42);
+impl_simd_float_overloads!(F32x4Sse42);
+impl_f32_simd_type!(Sse42, F32x4Sse42, I32x4Sse42, F64x2Sse42, M32x4Sse42);
+
+define_simd_type!(Sse42, f64, 2, __m128d, Sse42);
+impl_simd_float_overloads!(F64x2Sse42);
+impl_f64_simd_type!(Sse42, F64x2Sse42, F32x4Sse42, I32x4Sse42, I64x2Sse42, M64x2Sse42);
@@ -0,0 +1,49 @@
+use crate::Simd;
+
+use super::*;
+
+pub struct Sse42;
+impl Simd for Sse42 {
+    type Vi8 = I8x16Sse42;
+    type Vi16 = I16x8Sse42;
+    type Vi32 = I32x4Sse42;
+    type Vf32 = F32x4Sse42;
+    type Vf64 = F64x2Sse42;
+    type Vi64 = I64x2Sse42;
+    type Vu8 = U8x16Sse42;
+    type Vu16 = U16x8Sse42;
+    type Vu32 = U32x4Sse42;
+    type Vu64 = U64x2Sse42;
+    type Mi8 = M8x16Sse42;
+    type Mi16 = M16x8Sse42;
+    type Mi32 = M32x4Sse42;
+    type Mi64 = M64x2Sse42;
+    type Mf32 = M32x4Sse42;
+    type Mf64 = M64x2Sse42;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "sse4.2")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x2Sse42(_mm_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x4Sse42(_mm_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        I32x4Sse42(_mm_shuffle_epi32(a.0, IMM8))
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core::ops::*;
+
+use crate::ops::*;
+use crate::*;
+
+mod simd;
+pub use self::simd::*;
+
+define_simd_mask!(Ssse3, M8x16Ssse3, i8, 16, __m128i);
+define_simd_mask!(Ssse3, M16x8Ssse3, i16, 8, __m128i);
+define_simd_mask!(Ssse3, M32x4Ssse3, i32, 4, __m128i);
+define_simd_mask!(Ssse3, M64x2Ssse3, i64, 2, __m128i);
+
+define_simd_type!(Ssse3, i8, 16, __m128i, Ssse3);
+impl_simd_int_overloads!(I8x16Ssse3);
+impl_i8_simd_type!(Ssse3, I8x16Ssse3, I16x8Ssse3, U8x16Ssse3, M8x16Ssse3);
+
+define_simd_type!(Ssse3, i16, 8, __m128i, Ssse3);
+impl_simd_int_overloads!(I16x8Ssse3);
+impl_i16_simd_type!(Ssse3, I16x8Ssse3, I8x16Ssse3, I32x4Ssse3, U8x16Ssse3, U16x8Ssse3, M16x8Ssse3);
+
+define_simd_type!(Ssse3, i32, 4, __m128i, Ssse3);
+impl_simd_int_overloads!(I32x4Ssse3);
+impl_i32_simd_type!(
+    Ssse3, I32x4Ssse3, I16x8Ssse3, F32x4Ssse3, F64x2Ssse3, I64x2Ssse3, U16x8Ssse3, U32x4Ssse3,
+    M32x4Ssse3
+);
+
+define_simd_type!(Ssse3, i64, 2, __m128i, Ssse3);
+impl_simd_int_overloads!(I64x2Ssse3);
+impl_i64_simd_type!(Ssse3, I64x2Ssse3, I32x4Ssse3, F64x2Ssse3, U32x4Ssse3, U64x2Ssse3, M64x2Ssse3);
+
+define_simd_type!(Ssse3, u8, 16, __m128i, Ssse3);
+impl_simd_int_overloads!(U8x16Ssse3);
+impl_u8_simd_type!(Ssse3, U8x16Ssse3, I8x16Ssse3, M8x16Ssse3);
+
+define_simd_type!(Ssse3, u16, 8, __m128i, Ssse3);
+impl_simd_int_overloads!(U16x8Ssse3);
+impl_u16_simd_type!(Ssse3, U16x8Ssse3, I16x8Ssse3, M16x8Ssse3);
+
+define_simd_type!(Ssse3, u32, 4, __m128i, Ssse3);
+impl_simd_int_overloads!(U32x4Ssse3);
+impl_u32_simd_type!(Ssse3, U32x4Ssse3, I32x4Ssse3, M32x4Ssse3);
+
+define_simd_type!(Ssse3, u64, 2, __m128i, Ssse3);
+impl_simd_int_overloads!(U64x2Ssse3);
+impl_u64_simd_type!(Ssse3, U64x2Ssse3, I64x2Ssse3, M64x2Ssse3);
+
+define_simd_type!(Ssse3, f32, 4, __m128, Ssse3);
+impl_simd_float_overloads!(F32x4Ssse3);
+impl_f32_simd_type!(Ssse3, F32x4Ssse3, I32x4Ssse3, F64x2Ssse3, M32x4Ssse3);
+
+define_simd_type!(Ssse3, f64, 2, __m128d, Ssse3);
+impl_simd_float_overloads!(F64x2Ssse3);
+impl_f64_simd_type!(Ssse3, F64x2Ssse3, F32x4Ssse3, I32x4Ssse3, I64x2Ssse3, M64x2Ssse3);
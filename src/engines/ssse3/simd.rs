@@ -0,0 +1,49 @@
+use crate::Simd;
+
+use super::*;
+
+pub struct Ssse3;
+impl Simd for Ssse3 {
+    type Vi8 = I8x16Ssse3;
+    type Vi16 = I16x8Ssse3;
+    type Vi32 = I32x4Ssse3;
+    type Vf32 = F32x4Ssse3;
+    type Vf64 = F64x2Ssse3;
+    type Vi64 = I64x2Ssse3;
+    type Vu8 = U8x16Ssse3;
+    type Vu16 = U16x8Ssse3;
+    type Vu32 = U32x4Ssse3;
+    type Vu64 = U64x2Ssse3;
+    type Mi8 = M8x16Ssse3;
+    type Mi16 = M16x8Ssse3;
+    type Mi32 = M32x4Ssse3;
+    type Mi64 = M64x2Ssse3;
+    type Mf32 = M32x4Ssse3;
+    type Mf64 = M64x2Ssse3;
+
+    #[inline]
+    fn invoke<R>(f: impl FnOnce() -> R) -> R {
+        #[inline]
+        #[target_feature(enable = "ssse3")]
+        unsafe fn inner<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        unsafe { inner(f) }
+    }
+
+    #[inline(always)]
+    unsafe fn castps_pd(a: Self::Vf32) -> Self::Vf64 {
+        F64x2Ssse3(_mm_castps_pd(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn castpd_ps(a: Self::Vf64) -> Self::Vf32 {
+        F32x4Ssse3(_mm_castpd_ps(a.0))
+    }
+
+    #[inline(always)]
+    unsafe fn shuffle_epi32<const IMM8: i32>(a: Self::Vi32) -> Self::Vi32 {
+        I32x4Ssse3(_mm_shuffle_epi32(a.0, IMM8))
+    }
+}
@@ -9,26 +9,49 @@ use crate::*;
 mod simd;
 pub use self::simd::*;
 
+define_simd_mask!(Wasm, M8x16Wasm, i8, 16, v128);
+define_simd_mask!(Wasm, M16x8Wasm, i16, 8, v128);
+define_simd_mask!(Wasm, M32x4Wasm, i32, 4, v128);
+define_simd_mask!(Wasm, M64x2Wasm, i64, 2, v128);
+
 define_simd_type!(Wasm, i8, 16, v128, Wasm);
 impl_simd_int_overloads!(I8x16Wasm);
-impl_i8_simd_type!(Wasm, I8x16Wasm, I16x8Wasm);
+impl_i8_simd_type!(Wasm, I8x16Wasm, I16x8Wasm, U8x16Wasm, M8x16Wasm);
 
 define_simd_type!(Wasm, i16, 8, v128, Wasm);
 impl_simd_int_overloads!(I16x8Wasm);
-impl_i16_simd_type!(Wasm, I16x8Wasm, I32x4Wasm);
+impl_i16_simd_type!(Wasm, I16x8Wasm, I8x16Wasm, I32x4Wasm, U8x16Wasm, U16x8Wasm, M16x8Wasm);
 
 define_simd_type!(Wasm, i32, 4, v128, Wasm);
 impl_simd_int_overloads!(I32x4Wasm);
-impl_i32_simd_type!(Wasm, I32x4Wasm, F32x4Wasm, I64x2Wasm);
+impl_i32_simd_type!(
+    Wasm, I32x4Wasm, I16x8Wasm, F32x4Wasm, F64x2Wasm, I64x2Wasm, U16x8Wasm, U32x4Wasm, M32x4Wasm
+);
 
 define_simd_type!(Wasm, i64, 2, v128, Wasm);
 impl_simd_int_overloads!(I64x2Wasm);
-impl_i64_simd_type!(Wasm, I64x2Wasm, F64x2Wasm);
+impl_i64_simd_type!(Wasm, I64x2Wasm, I32x4Wasm, F64x2Wasm, U32x4Wasm, U64x2Wasm, M64x2Wasm);
+
+define_simd_type!(Wasm, u8, 16, v128, Wasm);
+impl_simd_int_overloads!(U8x16Wasm);
+impl_u8_simd_type!(Wasm, U8x16Wasm, I8x16Wasm, M8x16Wasm);
+
+define_simd_type!(Wasm, u16, 8, v128, Wasm);
+impl_simd_int_overloads!(U16x8Wasm);
+impl_u16_simd_type!(Wasm, U16x8Wasm, I16x8Wasm, M16x8Wasm);
+
+define_simd_type!(Wasm, u32, 4, v128, Wasm);
+impl_simd_int_overloads!(U32x4Wasm);
+impl_u32_simd_type!(Wasm, U32x4Wasm, I32x4Wasm, M32x4Wasm);
+
+define_simd_type!(Wasm, u64, 2, v128, Wasm);
+impl_simd_int_overloads!(U64x2Wasm);
+impl_u64_simd_type!(Wasm, U64x2Wasm, I64x2Wasm, M64x2Wasm);
 
 define_simd_type!(Wasm, f32, 4, v128, Wasm);
 impl_simd_float_overloads!(F32x4Wasm);
-impl_f32_simd_type!(Wasm, F32x4Wasm, I32x4Wasm);
+impl_f32_simd_type!(Wasm, F32x4Wasm, I32x4Wasm, F64x2Wasm, M32x4Wasm);
 
 define_simd_type!(Wasm, f64, 2, v128, Wasm);
 impl_simd_float_overloads!(F64x2Wasm);
-impl_f64_simd_type!(Wasm, F64x2Wasm, I64x2Wasm);
+impl_f64_simd_type!(Wasm, F64x2Wasm, F32x4Wasm, I32x4Wasm, I64x2Wasm, M64x2Wasm);
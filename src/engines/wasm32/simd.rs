@@ -9,6 +9,16 @@ impl Simd for Wasm {
     type Vf32 = F32x4Wasm;
     type Vf64 = F64x2Wasm;
     type Vi64 = I64x2Wasm;
+    type Vu8 = U8x16Wasm;
+    type Vu16 = U16x8Wasm;
+    type Vu32 = U32x4Wasm;
+    type Vu64 = U64x2Wasm;
+    type Mi8 = M8x16Wasm;
+    type Mi16 = M16x8Wasm;
+    type Mi32 = M32x4Wasm;
+    type Mi64 = M64x2Wasm;
+    type Mf32 = M32x4Wasm;
+    type Mf64 = M64x2Wasm;
 
     #[inline]
     fn invoke<R>(f: impl FnOnce() -> R) -> R {
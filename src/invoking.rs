@@ -123,6 +123,14 @@ macro_rules! simd_unsafe_generate_all {
                 __run_simd_invoke_sse2::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
             }
 
+            $(#[$meta])*
+            #[inline(always)]
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $vis unsafe fn [<$fn_name _ssse3>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
+                let args_tuple = ($($arg,)*);
+                __run_simd_invoke_ssse3::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            }
+
             $(#[$meta])*
             #[inline(always)]
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
@@ -131,6 +139,22 @@ macro_rules! simd_unsafe_generate_all {
                 __run_simd_invoke_sse41::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
             }
 
+            $(#[$meta])*
+            #[inline(always)]
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $vis unsafe fn [<$fn_name _sse42>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
+                let args_tuple = ($($arg,)*);
+                __run_simd_invoke_sse42::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            }
+
+            $(#[$meta])*
+            #[inline(always)]
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $vis unsafe fn [<$fn_name _avx>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
+                let args_tuple = ($($arg,)*);
+                __run_simd_invoke_avx::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            }
+
             $(#[$meta])*
             #[inline(always)]
             #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
@@ -139,6 +163,22 @@ macro_rules! simd_unsafe_generate_all {
                 __run_simd_invoke_avx2::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
             }
 
+            $(#[$meta])*
+            #[inline(always)]
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $vis unsafe fn [<$fn_name _avx2fma>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
+                let args_tuple = ($($arg,)*);
+                __run_simd_invoke_avx2fma::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            }
+
+            $(#[$meta])*
+            #[inline(always)]
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            $vis unsafe fn [<$fn_name _avx512>] $(<$($lt),+>)?($($arg:$typ,)*) -> $rt {
+                let args_tuple = ($($arg,)*);
+                __run_simd_invoke_avx512::<[<__ $fn_name _dispatch_struct>], fix_tuple_type!(($($typ),*)), $rt>(args_tuple)
+            }
+
             $(#[$meta])*
             #[inline(always)]
             #[cfg(target_arch = "aarch64")]
@@ -173,14 +213,37 @@ pub fn __run_simd_runtime_decide<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
 
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     {
+        if is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512bw")
+            && is_x86_feature_detected!("avx512dq")
+        {
+            return unsafe { S::run::<engines::avx512::Avx512>(args) };
+        }
+
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return unsafe { S::run::<engines::avx2fma::Avx2Fma>(args) };
+        }
+
         if is_x86_feature_detected!("avx2") {
             return unsafe { S::run::<engines::avx2::Avx2>(args) };
         }
 
+        if is_x86_feature_detected!("avx") {
+            return unsafe { S::run::<engines::avx::Avx>(args) };
+        }
+
+        if is_x86_feature_detected!("sse4.2") {
+            return unsafe { S::run::<engines::sse42::Sse42>(args) };
+        }
+
         if is_x86_feature_detected!("sse4.1") {
             return unsafe { S::run::<engines::sse41::Sse41>(args) };
         }
 
+        if is_x86_feature_detected!("ssse3") {
+            return unsafe { S::run::<engines::ssse3::Ssse3>(args) };
+        }
+
         if is_x86_feature_detected!("sse2") {
             return unsafe { S::run::<engines::sse2::Sse2>(args) };
         }
@@ -212,12 +275,31 @@ pub fn __run_simd_compiletime_select<S: __SimdRunner<A, R>, A, R>(args: A) -> R
 
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
     {
+        #[cfg(all(
+            target_feature = "avx512f",
+            target_feature = "avx512bw",
+            target_feature = "avx512dq"
+        ))]
+        return unsafe { S::run::<engines::avx512::Avx512>(args) };
+
+        #[cfg(all(target_feature = "avx2", target_feature = "fma"))]
+        return unsafe { S::run::<engines::avx2fma::Avx2Fma>(args) };
+
         #[cfg(target_feature = "avx2")]
         return unsafe { S::run::<engines::avx2::Avx2>(args) };
 
+        #[cfg(target_feature = "avx")]
+        return unsafe { S::run::<engines::avx::Avx>(args) };
+
+        #[cfg(target_feature = "sse4.2")]
+        return unsafe { S::run::<engines::sse42::Sse42>(args) };
+
         #[cfg(target_feature = "sse4.1")]
         return unsafe { S::run::<engines::sse41::Sse41>(args) };
 
+        #[cfg(target_feature = "ssse3")]
+        return unsafe { S::run::<engines::ssse3::Ssse3>(args) };
+
         #[cfg(target_feature = "sse2")]
         return unsafe { S::run::<engines::sse2::Sse2>(args) };
     }
@@ -247,18 +329,48 @@ pub unsafe fn __run_simd_invoke_sse2<S: __SimdRunner<A, R>, A, R>(args: A) -> R
     unsafe { S::run::<engines::sse2::Sse2>(args) }
 }
 
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_ssse3<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::ssse3::Ssse3>(args) }
+}
+
 #[inline(always)]
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub unsafe fn __run_simd_invoke_sse41<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
     unsafe { S::run::<engines::sse41::Sse41>(args) }
 }
 
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_sse42<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::sse42::Sse42>(args) }
+}
+
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_avx<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::avx::Avx>(args) }
+}
+
 #[inline(always)]
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub unsafe fn __run_simd_invoke_avx2<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
     unsafe { S::run::<engines::avx2::Avx2>(args) }
 }
 
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_avx2fma<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::avx2fma::Avx2Fma>(args) }
+}
+
+#[inline(always)]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub unsafe fn __run_simd_invoke_avx512<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
+    unsafe { S::run::<engines::avx512::Avx512>(args) }
+}
+
 #[inline(always)]
 #[cfg(target_feature = "neon")]
 pub unsafe fn __run_simd_invoke_neon<S: __SimdRunner<A, R>, A, R>(args: A) -> R {
@@ -1,18 +1,16 @@
 //! A library that abstracts over SIMD instruction sets, including ones with differing widths.
-//! SIMDeez is designed to allow you to write a function one time and produce scalar, SSE2, SSE41, AVX2 and Neon versions of the function.
+//! SIMDeez is designed to allow you to write a function one time and produce scalar, SSE2, SSSE3, SSE41, SSE42, AVX, AVX2, AVX2+FMA, AVX-512 and Neon versions of the function.
 //! You can either have the version you want selected automatically at runtime, at compiletime, or
 //! select yourself by hand.
 //!
 //! SIMDeez is currently in Beta, if there are intrinsics you need that are not currently implemented, create an issue
 //! and I'll add them. PRs to add more intrinsics are welcome. Currently things are well fleshed out for i32, i64, f32, and f64 types.
 //!
-//! As Rust stabilizes support for AVX-512 I plan to add those as well.
-//!
 //! Refer to the excellent [Intel Intrinsics Guide](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#) for documentation on these functions.
 //!
 //! # Features
 //!
-//! * SSE2, SSE41, AVX2, Neon and scalar fallback
+//! * SSE2, SSSE3, SSE41, SSE42, AVX, AVX2, AVX2+FMA, AVX-512, Neon and scalar fallback
 //! * Can be used with compile time or run time selection
 //! * No runtime overhead
 //! * Uses familiar intel intrinsic naming conventions, easy to port.
@@ -133,8 +131,13 @@
 //! * `distance<S:Simd>` the generic version of your function
 //! * `distance_scalar`  a scalar fallback
 //! * `distance_sse2`    SSE2 version
+//! * `distance_ssse3`   SSSE3 version
 //! * `distance_sse41`   SSE41 version
+//! * `distance_sse42`   SSE42 version
+//! * `distance_avx`     AVX version
 //! * `distance_avx2`    AVX2 version
+//! * `distance_avx2fma` AVX2 with FMA version
+//! * `distance_avx512`  AVX-512 version
 //! * `distance_neon`    Neon version
 //! * `distance_runtime_select`  picks the fastest of the above at runtime
 //!
@@ -187,32 +190,78 @@ pub trait Simd: 'static + Sync + Send {
     /// Vector of i8s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i8
     /// when used with Scalar.
-    type Vi8: SimdInt8<Scalar = i8> + SimdBaseIo;
+    type Vi8: SimdInt8<Engine = Self, Scalar = i8, Mask = Self::Mi8>
+        + SimdBaseIo<GatherIndices = Self::Vi8>;
 
     /// Vector of i16s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i16
     /// when used with Scalar.
-    type Vi16: SimdInt16<Scalar = i16> + SimdBaseIo;
+    type Vi16: SimdInt16<Engine = Self, Scalar = i16, Mask = Self::Mi16>
+        + SimdBaseIo<GatherIndices = Self::Vi16>;
 
     /// Vector of i32s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i32
     /// when used with Scalar.
-    type Vi32: SimdInt32<Engine = Self, Scalar = i32> + SimdBaseIo;
+    type Vi32: SimdInt32<Engine = Self, Scalar = i32, Mask = Self::Mi32>
+        + SimdBaseIo<GatherIndices = Self::Vi32>;
 
     /// Vector of i64s.  Corresponds to __m128i when used
     /// with the Sse impl, __m256i when used with Avx2, or a single i64
     /// when used with Scalar.
-    type Vi64: SimdInt64<Engine = Self, Scalar = i64> + SimdBaseIo;
+    type Vi64: SimdInt64<Engine = Self, Scalar = i64, Mask = Self::Mi64>
+        + SimdBaseIo<GatherIndices = Self::Vi64>;
+
+    /// Vector of u8s.  Shares its underlying type with `Vi8`, and
+    /// can be bit cast to and from it for free.
+    type Vu8: SimdUint8<Engine = Self, Scalar = u8, Mask = Self::Mi8>
+        + SimdBaseIo<GatherIndices = Self::Vi8>;
+
+    /// Vector of u16s.  Shares its underlying type with `Vi16`, and
+    /// can be bit cast to and from it for free.
+    type Vu16: SimdUint16<Engine = Self, Scalar = u16, Mask = Self::Mi16>
+        + SimdBaseIo<GatherIndices = Self::Vi16>;
+
+    /// Vector of u32s.  Shares its underlying type with `Vi32`, and
+    /// can be bit cast to and from it for free.
+    type Vu32: SimdUint32<Engine = Self, Scalar = u32, Mask = Self::Mi32>
+        + SimdBaseIo<GatherIndices = Self::Vi32>;
+
+    /// Vector of u64s.  Shares its underlying type with `Vi64`, and
+    /// can be bit cast to and from it for free.
+    type Vu64: SimdUint64<Engine = Self, Scalar = u64, Mask = Self::Mi64>
+        + SimdBaseIo<GatherIndices = Self::Vi64>;
 
     /// Vector of f32s.  Corresponds to __m128 when used
     /// with the Sse impl, __m256 when used with Avx2, or a single f32
     /// when used with Scalar.
-    type Vf32: SimdFloat32<Engine = Self, Scalar = f32> + SimdBaseIo;
+    type Vf32: SimdFloat32<Engine = Self, Scalar = f32, Mask = Self::Mf32>
+        + SimdBaseIo<GatherIndices = Self::Vi32>;
 
     /// Vector of f64s.  Corresponds to __m128d when used
     /// with the Sse impl, __m256d when used with Avx2, or a single f64
     /// when used with Scalar.
-    type Vf64: SimdFloat64<Engine = Self, Scalar = f64> + SimdBaseIo;
+    type Vf64: SimdFloat64<Engine = Self, Scalar = f64, Mask = Self::Mf64>
+        + SimdBaseIo<GatherIndices = Self::Vi64>;
+
+    /// Mask over the lanes of `Vi8` and `Vu8`, as returned by their comparisons.
+    /// Holds a k-register with Avx512, and a vector with every bit of a lane
+    /// set or cleared otherwise.
+    type Mi8: SimdMask<Engine = Self>;
+
+    /// Mask over the lanes of `Vi16` and `Vu16`.
+    type Mi16: SimdMask<Engine = Self>;
+
+    /// Mask over the lanes of `Vi32` and `Vu32`.
+    type Mi32: SimdMask<Engine = Self>;
+
+    /// Mask over the lanes of `Vi64` and `Vu64`.
+    type Mi64: SimdMask<Engine = Self>;
+
+    /// Mask over the lanes of `Vf32`. The same type as `Mi32` in every engine.
+    type Mf32: SimdMask<Engine = Self>;
+
+    /// Mask over the lanes of `Vf64`. The same type as `Mi64` in every engine.
+    type Mf64: SimdMask<Engine = Self>;
 
     // The width of the vector lane.  Necessary for creating
     // lane width agnostic code.
@@ -480,7 +529,7 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn blendv_epi32(a: Self::Vi32, b: Self::Vi32, mask: Self::Vi32) -> Self::Vi32 {
-        a.blendv(b, mask)
+        a.and_not(mask) | (b & mask)
     }
     /// Note SSE2 will select B only when all bits are 1, while SSE41 and AVX2 only
     /// check the high bit. To maintain portability ensure all bits are 1 when using
@@ -489,7 +538,7 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn blendv_epi64(a: Self::Vi64, b: Self::Vi64, mask: Self::Vi64) -> Self::Vi64 {
-        a.blendv(b, mask)
+        a.and_not(mask) | (b & mask)
     }
     /// Note SSE2 will select B only when all bits are 1, while SSE41 and AVX2 only
     /// check the high bit. To maintain portability ensure all bits are 1 when using
@@ -498,7 +547,7 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn blendv_ps(a: Self::Vf32, b: Self::Vf32, mask: Self::Vf32) -> Self::Vf32 {
-        a.blendv(b, mask)
+        a.and_not(mask) | (b & mask)
     }
     /// Note SSE2 will select B only when all bits are 1, while SSE41 and AVX2 only
     /// check the high bit. To maintain portability ensure all bits are 1 when using
@@ -507,7 +556,7 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn blendv_pd(a: Self::Vf64, b: Self::Vf64, mask: Self::Vf64) -> Self::Vf64 {
-        a.blendv(b, mask)
+        a.and_not(mask) | (b & mask)
     }
 
     #[deprecated(
@@ -581,145 +630,145 @@ pub trait Simd: 'static + Sync + Send {
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_eq(b)
+        Self::Vi64::from_mask(a.cmp_eq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_neq(b)
+        Self::Vi64::from_mask(a.cmp_neq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_gte(b)
+        Self::Vi64::from_mask(a.cmp_gte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_gt(b)
+        Self::Vi64::from_mask(a.cmp_gt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_lte(b)
+        Self::Vi64::from_mask(a.cmp_lte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_epi64(a: Self::Vi64, b: Self::Vi64) -> Self::Vi64 {
-        a.cmp_lt(b)
+        Self::Vi64::from_mask(a.cmp_lt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_eq(b)
+        Self::Vi32::from_mask(a.cmp_eq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_neq(b)
+        Self::Vi32::from_mask(a.cmp_neq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_gte(b)
+        Self::Vi32::from_mask(a.cmp_gte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_gt(b)
+        Self::Vi32::from_mask(a.cmp_gt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_lte(b)
+        Self::Vi32::from_mask(a.cmp_lte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_epi32(a: Self::Vi32, b: Self::Vi32) -> Self::Vi32 {
-        a.cmp_lt(b)
+        Self::Vi32::from_mask(a.cmp_lt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_eq(b)
+        Self::Vf32::from_mask(a.cmp_eq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_neq(b)
+        Self::Vf32::from_mask(a.cmp_neq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_gte(b)
+        Self::Vf32::from_mask(a.cmp_gte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_gt(b)
+        Self::Vf32::from_mask(a.cmp_gt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_lte(b)
+        Self::Vf32::from_mask(a.cmp_lte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_ps(a: Self::Vf32, b: Self::Vf32) -> Self::Vf32 {
-        a.cmp_lt(b)
+        Self::Vf32::from_mask(a.cmp_lt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpeq_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_eq(b)
+        Self::Vf64::from_mask(a.cmp_eq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpneq_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_neq(b)
+        Self::Vf64::from_mask(a.cmp_neq(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpge_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_gte(b)
+        Self::Vf64::from_mask(a.cmp_gte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmpgt_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_gt(b)
+        Self::Vf64::from_mask(a.cmp_gt(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmple_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_lte(b)
+        Self::Vf64::from_mask(a.cmp_lte(b))
     }
     #[deprecated(
         note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
     )]
     unsafe fn cmplt_pd(a: Self::Vf64, b: Self::Vf64) -> Self::Vf64 {
-        a.cmp_lt(b)
+        Self::Vf64::from_mask(a.cmp_lt(b))
     }
 
     #[deprecated(
@@ -782,7 +831,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fast_floor_pd(a: Self::Vf64) -> Self::Vf64 {
         a.fast_floor()
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and add are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -791,7 +840,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fmadd_ps(a: Self::Vf32, b: Self::Vf32, c: Self::Vf32) -> Self::Vf32 {
         a.mul_add(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and add are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -800,7 +849,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fnmadd_ps(a: Self::Vf32, b: Self::Vf32, c: Self::Vf32) -> Self::Vf32 {
         a.neg_mul_add(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and add are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -809,7 +858,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fmadd_pd(a: Self::Vf64, b: Self::Vf64, c: Self::Vf64) -> Self::Vf64 {
         a.mul_add(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and add are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -818,7 +867,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fnmadd_pd(a: Self::Vf64, b: Self::Vf64, c: Self::Vf64) -> Self::Vf64 {
         a.neg_mul_add(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and sub are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -827,7 +876,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fmsub_ps(a: Self::Vf32, b: Self::Vf32, c: Self::Vf32) -> Self::Vf32 {
         a.neg_mul_sub(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and sub are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -836,7 +885,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fnmsub_ps(a: Self::Vf32, b: Self::Vf32, c: Self::Vf32) -> Self::Vf32 {
         a.mul_sub(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and sub are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -845,7 +894,7 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn fmsub_pd(a: Self::Vf64, b: Self::Vf64, c: Self::Vf64) -> Self::Vf64 {
         a.neg_mul_sub(b, c)
     }
-    /// Actual FMA instructions will be used when Avx2 is used,
+    /// Actual FMA instructions will be used when Avx2Fma or Avx512 is used,
     /// otherwise a mul and sub are used to replicate it, allowing you to
     /// just always use FMA in your code and get best perf in both cases.
     #[deprecated(
@@ -868,27 +917,23 @@ pub trait Simd: 'static + Sync + Send {
     unsafe fn horizontal_add_pd(a: Self::Vf64) -> f64 {
         a.horizontal_add()
     }
-    /// Sse2 and Sse41 paths will simulate a gather by breaking out and
-    /// doing scalar array accesses, because gather doesn't exist until Avx2.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use the gather function on the Vi32, Vi64, and Vf32 types instead."
     )]
-    unsafe fn i32gather_epi32(_arr: &[i32], _index: Self::Vi32) -> Self::Vi32 {
-        panic!("Deprecated")
+    unsafe fn i32gather_epi32(arr: &[i32], index: Self::Vi32) -> Self::Vi32 {
+        Self::Vi32::gather(arr, index)
     }
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use the gather function on the Vi32, Vi64, and Vf32 types instead."
     )]
-    unsafe fn i64gather_epi64(_arr: &[i64], _index: Self::Vi64) -> Self::Vi64 {
-        panic!("Deprecated")
+    unsafe fn i64gather_epi64(arr: &[i64], index: Self::Vi64) -> Self::Vi64 {
+        Self::Vi64::gather(arr, index)
     }
-    /// Sse2 and Sse41 paths will simulate a gather by breaking out and
-    /// doing scalar array accesses, because gather doesn't exist until Avx2.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use the gather function on the Vi32, Vi64, and Vf32 types instead."
     )]
-    unsafe fn i32gather_ps(_arr: &[f32], _index: Self::Vi32) -> Self::Vf32 {
-        panic!("Deprecated")
+    unsafe fn i32gather_ps(arr: &[f32], index: Self::Vi32) -> Self::Vf32 {
+        Self::Vf32::gather(arr, index)
     }
 
     #[deprecated(
@@ -946,41 +991,37 @@ pub trait Simd: 'static + Sync + Send {
         SimdBaseIo::load_from_ptr_unaligned(a)
     }
 
-    /// Note, SSE2 and SSE4 will load when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability
-    /// ensure that the high bit is set.
+    /// Loads the lanes where the high bit of `mask` is set, and sets the others to zero.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use `load_from_slice_masked` or `load_from_ptr_masked` on the vector types instead."
     )]
-    unsafe fn maskload_epi32(_mem_addr: &i32, _mask: Self::Vi32) -> Self::Vi32 {
-        panic!("Deprecated")
+    unsafe fn maskload_epi32(mem_addr: &i32, mask: Self::Vi32) -> Self::Vi32 {
+        SimdBaseIo::load_from_ptr_masked(mem_addr, mask.cmp_lt(Self::Vi32::zeroes()))
     }
-    /// Note, SSE2 and SSE4 will load when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability
-    /// ensure that the high bit is set.
+    /// Loads the lanes where the high bit of `mask` is set, and sets the others to zero.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use `load_from_slice_masked` or `load_from_ptr_masked` on the vector types instead."
     )]
-    unsafe fn maskload_epi64(_mem_addr: &i64, _mask: Self::Vi64) -> Self::Vi64 {
-        panic!("Deprecated")
+    unsafe fn maskload_epi64(mem_addr: &i64, mask: Self::Vi64) -> Self::Vi64 {
+        SimdBaseIo::load_from_ptr_masked(mem_addr, mask.cmp_lt(Self::Vi64::zeroes()))
     }
-    /// Note, SSE2 and SSE4 will load when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability
-    /// ensure that the high bit is set.
+    /// Loads the lanes where the high bit of `mask` is set, and sets the others to zero.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use `load_from_slice_masked` or `load_from_ptr_masked` on the vector types instead."
     )]
-    unsafe fn maskload_ps(_mem_addr: &f32, _mask: Self::Vi32) -> Self::Vf32 {
-        panic!("Deprecated")
+    unsafe fn maskload_ps(mem_addr: &f32, mask: Self::Vi32) -> Self::Vf32 {
+        let mask = mask.cmp_lt(Self::Vi32::zeroes());
+        let loaded = Self::Vi32::load_from_ptr_masked(mem_addr as *const f32 as *const i32, mask);
+        loaded.bitcast_f32()
     }
-    /// Note, SSE2 and SSE4 will load when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability
-    /// ensure that the high bit is set.
+    /// Loads the lanes where the high bit of `mask` is set, and sets the others to zero.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "Functions on the Simd trait are deprecated, please use `load_from_slice_masked` or `load_from_ptr_masked` on the vector types instead."
     )]
-    unsafe fn maskload_pd(_mem_addr: &f64, _mask: Self::Vi64) -> Self::Vf64 {
-        panic!("Deprecated")
+    unsafe fn maskload_pd(mem_addr: &f64, mask: Self::Vi64) -> Self::Vf64 {
+        let mask = mask.cmp_lt(Self::Vi64::zeroes());
+        let loaded = Self::Vi64::load_from_ptr_masked(mem_addr as *const f64 as *const i64, mask);
+        loaded.bitcast_f64()
     }
 
     #[deprecated(
@@ -1032,49 +1073,37 @@ pub trait Simd: 'static + Sync + Send {
         SimdBaseIo::copy_to_ptr_unaligned(a, mem_addr)
     }
 
-    /// Note, SSE2 and SSE4 will store when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability ensure the
-    /// high bit is set.
+    /// Stores the lanes where the high bit of `mask` is set.
     #[deprecated(
-        note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
+        note = "Functions on the Simd trait are deprecated, please use `store_to_slice_masked` or `copy_to_ptr_masked` on the vector types instead."
     )]
     unsafe fn maskstore_epi32(mem_addr: &mut i32, mask: Self::Vi32, a: Self::Vi32) {
-        if mask[0] != 0 {
-            *mem_addr = a[0];
-        }
+        a.copy_to_ptr_masked(mem_addr, mask.cmp_lt(Self::Vi32::zeroes()))
     }
-    /// Note, SSE2 and SSE4 will store when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability ensure the
-    /// high bit is set.
+    /// Stores the lanes where the high bit of `mask` is set.
     #[deprecated(
-        note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
+        note = "Functions on the Simd trait are deprecated, please use `store_to_slice_masked` or `copy_to_ptr_masked` on the vector types instead."
     )]
     unsafe fn maskstore_epi64(mem_addr: &mut i64, mask: Self::Vi64, a: Self::Vi64) {
-        if mask[0] != 0 {
-            *mem_addr = a[0];
-        }
+        a.copy_to_ptr_masked(mem_addr, mask.cmp_lt(Self::Vi64::zeroes()))
     }
-    /// Note, SSE2 and SSE4 will store when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability ensure the
-    /// high bit is set.
+    /// Stores the lanes where the high bit of `mask` is set.
     #[deprecated(
-        note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
+        note = "Functions on the Simd trait are deprecated, please use `store_to_slice_masked` or `copy_to_ptr_masked` on the vector types instead."
     )]
     unsafe fn maskstore_ps(mem_addr: &mut f32, mask: Self::Vi32, a: Self::Vf32) {
-        if mask[0] != 0 {
-            *mem_addr = a[0];
-        }
+        let mask = mask.cmp_lt(Self::Vi32::zeroes());
+        let a = a.bitcast_i32();
+        a.copy_to_ptr_masked(mem_addr as *mut f32 as *mut i32, mask)
     }
-    /// Note, SSE2 and SSE4 will store when mask\[i\] is nonzero, where AVX2
-    /// will store only when the high bit is set. To ensure portability ensure the
-    /// high bit is set.
+    /// Stores the lanes where the high bit of `mask` is set.
     #[deprecated(
-        note = "Functions on the Simd trait are deprecated, please use the functions on the Vf32, Vf64, Vi16, Vi32, and Vi64 types instead."
+        note = "Functions on the Simd trait are deprecated, please use `store_to_slice_masked` or `copy_to_ptr_masked` on the vector types instead."
     )]
     unsafe fn maskstore_pd(mem_addr: &mut f64, mask: Self::Vi64, a: Self::Vf64) {
-        if mask[0] != 0 {
-            *mem_addr = a[0];
-        }
+        let mask = mask.cmp_lt(Self::Vi64::zeroes());
+        let a = a.bitcast_i64();
+        a.copy_to_ptr_masked(mem_addr as *mut f64 as *mut i64, mask)
     }
 
     #[deprecated(
@@ -1279,7 +1308,7 @@ pub trait Simd: 'static + Sync + Send {
     /// Using the shuffle function is undefined behavior because imm8 behaves differently on different
     /// architectures.
     #[deprecated(
-        note = "These functions have unpredictable behavior and will be deleted in the future. Please use a manual implementation instead."
+        note = "These functions have unpredictable behavior and will be deleted in the future. Please use `shuffle` or `permute` on the vector types instead."
     )]
     unsafe fn shuffle_epi32<const IMM8: i32>(_a: Self::Vi32) -> Self::Vi32 {
         panic!("Deprecated")
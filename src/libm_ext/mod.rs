@@ -13,6 +13,8 @@ pub trait FloatExt: private::Sealed + Sized {
 
     fn m_round(self) -> Self;
 
+    fn m_round_ties_even(self) -> Self;
+
     fn m_trunc(self) -> Self;
 
     fn m_fract(self) -> Self;
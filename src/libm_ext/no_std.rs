@@ -20,6 +20,11 @@ impl FloatExt for f32 {
         roundf(self)
     }
 
+    #[inline]
+    fn m_round_ties_even(self) -> Self {
+        rintf(self)
+    }
+
     #[inline]
     fn m_trunc(self) -> Self {
         truncf(self)
@@ -189,6 +194,11 @@ impl FloatExt for f64 {
         round(self)
     }
 
+    #[inline]
+    fn m_round_ties_even(self) -> Self {
+        rint(self)
+    }
+
     #[inline]
     fn m_trunc(self) -> Self {
         trunc(self)
@@ -16,6 +16,11 @@ impl FloatExt for f32 {
         f32::round(self)
     }
 
+    #[inline]
+    fn m_round_ties_even(self) -> Self {
+        f32::round_ties_even(self)
+    }
+
     #[inline]
     fn m_trunc(self) -> Self {
         f32::trunc(self)
@@ -178,6 +183,11 @@ impl FloatExt for f64 {
         f64::round(self)
     }
 
+    #[inline]
+    fn m_round_ties_even(self) -> Self {
+        f64::round_ties_even(self)
+    }
+
     #[inline]
     fn m_trunc(self) -> Self {
         f64::trunc(self)
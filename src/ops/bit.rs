@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn bit_and<binary> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_and_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bit_and(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_and_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_and_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bit_and(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_and_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bit_and(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_and_ps(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn bit_or<binary> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_or_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bit_or(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_or_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_or_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bit_or(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_or_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bit_or(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_or_ps(a, b)
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn bit_xor<binary> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_xor_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bit_xor(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_xor_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_xor_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bit_xor(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_xor_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bit_xor(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_xor_ps(a, b)
         }
@@ -71,14 +116,31 @@ impl_op! {
 
 impl_op! {
     fn bit_not<binary> {
+        for Avx512(a: __m512) -> __m512 {
+            let all1 = _mm512_set1_epi32(-1);
+            _mm512_castsi512_ps(_mm512_xor_si512(_mm512_castps_si512(a), all1))
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bit_not(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             let all1 = _mm256_set1_epi32(-1);
             _mm256_castsi256_ps(_mm256_xor_si256(_mm256_castps_si256(a), all1))
         }
+        for Avx(a: __m256) -> __m256 {
+            let all1 = _mm256_castsi256_ps(_mm256_set1_epi32(-1));
+            _mm256_xor_ps(a, all1)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bit_not(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             let all1 = _mm_set1_epi32(-1);
             _mm_castsi128_ps(_mm_xor_si128(_mm_castps_si128(a), all1))
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bit_not(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             let all1 = _mm_set1_epi32(-1);
             _mm_castsi128_ps(_mm_xor_si128(_mm_castps_si128(a), all1))
@@ -97,12 +159,27 @@ impl_op! {
 
 impl_op! {
     fn bit_andnot<binary> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_andnot_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bit_andnot(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_andnot_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_andnot_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bit_andnot(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_andnot_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bit_andnot(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_andnot_ps(a, b)
         }
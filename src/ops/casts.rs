@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn bitcast_binary<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            a
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::bitcast_binary(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             a
         }
+        for Avx(a: __m256) -> __m256 {
+            a
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::bitcast_binary(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             a
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::bitcast_binary(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             a
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_f32<binary> {
+        for Avx512(a: __m512) -> __m512 {
+            a
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, binary>::bitcast_f32(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             a
         }
+        for Avx(a: __m256) -> __m256 {
+            a
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, binary>::bitcast_f32(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             a
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, binary>::bitcast_f32(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             a
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_binary<f64> {
+        for Avx512(a: __m512d) -> __m512 {
+            _mm512_castpd_ps(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256 {
+            Ops::<Avx2, f64>::bitcast_binary(a)
+        }
         for Avx2(a: __m256d) -> __m256 {
             _mm256_castpd_ps(a)
         }
+        for Avx(a: __m256d) -> __m256 {
+            _mm256_castpd_ps(a)
+        }
+        for Sse42(a: __m128d) -> __m128 {
+            Ops::<Sse41, f64>::bitcast_binary(a)
+        }
         for Sse41(a: __m128d) -> __m128 {
             _mm_castpd_ps(a)
         }
+        for Ssse3(a: __m128d) -> __m128 {
+            Ops::<Sse2, f64>::bitcast_binary(a)
+        }
         for Sse2(a: __m128d) -> __m128 {
             _mm_castpd_ps(a)
         }
@@ -71,12 +116,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_f64<binary> {
+        for Avx512(a: __m512) -> __m512d {
+            _mm512_castps_pd(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256d {
+            Ops::<Avx2, binary>::bitcast_f64(a)
+        }
         for Avx2(a: __m256) -> __m256d {
             _mm256_castps_pd(a)
         }
+        for Avx(a: __m256) -> __m256d {
+            _mm256_castps_pd(a)
+        }
+        for Sse42(a: __m128) -> __m128d {
+            Ops::<Sse41, binary>::bitcast_f64(a)
+        }
         for Sse41(a: __m128) -> __m128d {
             _mm_castps_pd(a)
         }
+        for Ssse3(a: __m128) -> __m128d {
+            Ops::<Sse2, binary>::bitcast_f64(a)
+        }
         for Sse2(a: __m128) -> __m128d {
             _mm_castps_pd(a)
         }
@@ -94,12 +154,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_binary<i8> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i8>::bitcast_binary(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_castsi256_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i8>::bitcast_binary(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i8>::bitcast_binary(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
@@ -117,12 +192,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i8<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_i8(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_castps_si256(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_i8(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_i8(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
@@ -140,12 +230,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_binary<i16> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i16>::bitcast_binary(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_castsi256_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i16>::bitcast_binary(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i16>::bitcast_binary(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
@@ -163,12 +268,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i16<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_i16(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_castps_si256(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_i16(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_i16(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
@@ -186,12 +306,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_binary<i32> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i32>::bitcast_binary(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_castsi256_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i32>::bitcast_binary(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i32>::bitcast_binary(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
@@ -209,12 +344,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i32<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_i32(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_castps_si256(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_i32(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_i32(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
@@ -232,12 +382,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_binary<i64> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i64>::bitcast_binary(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_castsi256_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i64>::bitcast_binary(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i64>::bitcast_binary(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
@@ -255,12 +420,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i64<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_i64(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_castps_si256(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_i64(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_i64(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
@@ -275,3 +455,307 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn bitcast_binary<u8> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, u8>::bitcast_binary(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, u8>::bitcast_binary(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, u8>::bitcast_binary(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u8) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint8x16_t) -> int8x16_t {
+            vreinterpretq_s8_u8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u8<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_u8(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_u8(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_u8(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u8 {
+            a as u8
+        }
+        for Neon(a: int8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u16> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, u16>::bitcast_binary(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, u16>::bitcast_binary(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, u16>::bitcast_binary(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u16) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint16x8_t) -> int8x16_t {
+            vreinterpretq_s8_u16(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u16<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_u16(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_u16(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_u16(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u16 {
+            a as u16
+        }
+        for Neon(a: int8x16_t) -> uint16x8_t {
+            vreinterpretq_u16_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u32> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, u32>::bitcast_binary(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, u32>::bitcast_binary(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, u32>::bitcast_binary(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u32) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint32x4_t) -> int8x16_t {
+            vreinterpretq_s8_u32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u32<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_u32(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_u32(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_u32(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u32 {
+            a as u32
+        }
+        for Neon(a: int8x16_t) -> uint32x4_t {
+            vreinterpretq_u32_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_binary<u64> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, u64>::bitcast_binary(a)
+        }
+        for Avx2(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, u64>::bitcast_binary(a)
+        }
+        for Sse41(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, u64>::bitcast_binary(a)
+        }
+        for Sse2(a: __m128i) -> __m128 {
+            _mm_castsi128_ps(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a as u64
+        }
+        for Neon(a: uint64x2_t) -> int8x16_t {
+            vreinterpretq_s8_u64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn bitcast_u64<binary> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, binary>::bitcast_u64(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, binary>::bitcast_u64(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, binary>::bitcast_u64(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            _mm_castps_si128(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a as u64
+        }
+        for Neon(a: int8x16_t) -> uint64x2_t {
+            vreinterpretq_u64_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
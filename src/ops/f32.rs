@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_add_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::add(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_add_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_add_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::add(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_add_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::add(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_add_ps(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_sub_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::sub(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_sub_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_sub_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::sub(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_sub_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::sub(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_sub_ps(a, b)
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn mul<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_mul_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::mul(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_mul_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_mul_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::mul(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_mul_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::mul(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_mul_ps(a, b)
         }
@@ -71,12 +116,27 @@ impl_op! {
 
 impl_op! {
     fn div<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_div_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::div(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_div_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_div_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::div(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_div_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::div(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_div_ps(a, b)
         }
@@ -94,12 +154,27 @@ impl_op! {
 
 impl_op! {
     fn mul_add<f32> {
-        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+        for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
+            _mm512_fmadd_ps(a, b, c)
+        }
+        for Avx2Fma(a: __m256, b: __m256, c: __m256) -> __m256 {
             _mm256_fmadd_ps(a, b, c)
         }
+        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_add_ps(_mm256_mul_ps(a, b), c)
+        }
+        for Avx(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_add_ps(_mm256_mul_ps(a, b), c)
+        }
+        for Sse42(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse41, f32>::mul_add(a, b, c)
+        }
         for Sse41(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_add_ps(_mm_mul_ps(a, b), c)
         }
+        for Ssse3(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse2, f32>::mul_add(a, b, c)
+        }
         for Sse2(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_add_ps(_mm_mul_ps(a, b), c)
         }
@@ -117,12 +192,27 @@ impl_op! {
 
 impl_op! {
     fn mul_sub<f32> {
-        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+        for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
+            _mm512_fmsub_ps(a, b, c)
+        }
+        for Avx2Fma(a: __m256, b: __m256, c: __m256) -> __m256 {
             _mm256_fmsub_ps(a, b, c)
         }
+        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_sub_ps(_mm256_mul_ps(a, b), c)
+        }
+        for Avx(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_sub_ps(_mm256_mul_ps(a, b), c)
+        }
+        for Sse42(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse41, f32>::mul_sub(a, b, c)
+        }
         for Sse41(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_sub_ps(_mm_mul_ps(a, b), c)
         }
+        for Ssse3(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse2, f32>::mul_sub(a, b, c)
+        }
         for Sse2(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_sub_ps(_mm_mul_ps(a, b), c)
         }
@@ -140,12 +230,27 @@ impl_op! {
 
 impl_op! {
     fn neg_mul_add<f32> {
-        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+        for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
+            _mm512_fnmadd_ps(a, b, c)
+        }
+        for Avx2Fma(a: __m256, b: __m256, c: __m256) -> __m256 {
             _mm256_fnmadd_ps(a, b, c)
         }
+        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_sub_ps(c, _mm256_mul_ps(a, b))
+        }
+        for Avx(a: __m256, b: __m256, c: __m256) -> __m256 {
+            _mm256_sub_ps(c, _mm256_mul_ps(a, b))
+        }
+        for Sse42(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse41, f32>::neg_mul_add(a, b, c)
+        }
         for Sse41(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_sub_ps(c, _mm_mul_ps(a, b))
         }
+        for Ssse3(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse2, f32>::neg_mul_add(a, b, c)
+        }
         for Sse2(a: __m128, b: __m128, c: __m128) -> __m128 {
             _mm_sub_ps(c, _mm_mul_ps(a, b))
         }
@@ -163,14 +268,33 @@ impl_op! {
 
 impl_op! {
     fn neg_mul_sub<f32> {
-        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+        for Avx512(a: __m512, b: __m512, c: __m512) -> __m512 {
+            _mm512_fnmsub_ps(a, b, c)
+        }
+        for Avx2Fma(a: __m256, b: __m256, c: __m256) -> __m256 {
             _mm256_fnmsub_ps(a, b, c)
         }
+        for Avx2(a: __m256, b: __m256, c: __m256) -> __m256 {
+            let mul = _mm256_mul_ps(a, b);
+            let neg = _mm256_sub_ps(_mm256_setzero_ps(), mul);
+            _mm256_sub_ps(neg, c)
+        }
+        for Avx(a: __m256, b: __m256, c: __m256) -> __m256 {
+            let mul = _mm256_mul_ps(a, b);
+            let neg = _mm256_sub_ps(_mm256_setzero_ps(), mul);
+            _mm256_sub_ps(neg, c)
+        }
+        for Sse42(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse41, f32>::neg_mul_sub(a, b, c)
+        }
         for Sse41(a: __m128, b: __m128, c: __m128) -> __m128 {
             let mul = _mm_mul_ps(a, b);
             let neg = _mm_sub_ps(_mm_setzero_ps(), mul);
             _mm_sub_ps(neg, c)
         }
+        for Ssse3(a: __m128, b: __m128, c: __m128) -> __m128 {
+            Ops::<Sse2, f32>::neg_mul_sub(a, b, c)
+        }
         for Sse2(a: __m128, b: __m128, c: __m128) -> __m128 {
             let mul = _mm_mul_ps(a, b);
             let neg = _mm_sub_ps(_mm_setzero_ps(), mul);
@@ -190,12 +314,27 @@ impl_op! {
 
 impl_op! {
     fn sqrt<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_sqrt_ps(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::sqrt(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_sqrt_ps(a)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_sqrt_ps(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::sqrt(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_sqrt_ps(a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::sqrt(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             _mm_sqrt_ps(a)
         }
@@ -213,12 +352,27 @@ impl_op! {
 
 impl_op! {
     fn recip<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_rcp14_ps(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::recip(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_rcp_ps(a)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_rcp_ps(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::recip(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_rcp_ps(a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::recip(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             _mm_rcp_ps(a)
         }
@@ -236,12 +390,27 @@ impl_op! {
 
 impl_op! {
     fn rsqrt<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_rsqrt14_ps(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::rsqrt(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_rsqrt_ps(a)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_rsqrt_ps(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::rsqrt(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_rsqrt_ps(a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::rsqrt(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             _mm_rsqrt_ps(a)
         }
@@ -259,12 +428,27 @@ impl_op! {
 
 impl_op! {
     fn min<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_min_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::min(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_min_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_min_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::min(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_min_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::min(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_min_ps(a, b)
         }
@@ -282,12 +466,27 @@ impl_op! {
 
 impl_op! {
     fn max<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_max_ps(a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::max(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_max_ps(a, b)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_max_ps(a, b)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::max(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_max_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::max(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_max_ps(a, b)
         }
@@ -305,12 +504,27 @@ impl_op! {
 
 impl_op! {
     fn abs<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_abs_ps(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::abs(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_andnot_ps(_mm256_set1_ps(-0.0), a)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_andnot_ps(_mm256_set1_ps(-0.0), a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::abs(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_andnot_ps(_mm_set1_ps(-0.0), a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::abs(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             _mm_andnot_ps(_mm_set1_ps(-0.0), a)
         }
@@ -327,20 +541,102 @@ impl_op! {
 }
 
 impl_op! {
-    fn round<f32> {
+    fn round_ties_even<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_roundscale_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::round_ties_even(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::round_ties_even(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::round_ties_even(a)
+        }
         for Sse2(a: __m128) -> __m128 {
+            // Adding and subtracting the magic number pushes the fraction out of the mantissa, which
+            // rounds ties to even. Values at or above the magic number are already integers.
             let sign_mask = _mm_set1_ps(-0.0);
             let magic = _mm_castsi128_ps(_mm_set1_epi32(0x4B000000));
             let sign = _mm_and_ps(a, sign_mask);
             let signed_magic = _mm_or_ps(magic, sign);
-            let b = _mm_add_ps(a, signed_magic);
-            _mm_sub_ps(b, signed_magic)
+            let rounded = _mm_sub_ps(_mm_add_ps(a, signed_magic), signed_magic);
+            let is_small = _mm_cmplt_ps(_mm_andnot_ps(sign_mask, a), magic);
+            let rounded = _mm_or_ps(_mm_and_ps(is_small, rounded), _mm_andnot_ps(is_small, a));
+            _mm_or_ps(rounded, sign)
+        }
+        for Scalar(a: f32) -> f32 {
+            a.m_round_ties_even()
+        }
+        for Neon(a: float32x4_t) -> float32x4_t {
+            vrndnq_f32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            f32x4_nearest(a)
+        }
+    }
+}
+
+impl_op! {
+    fn round_ties_away<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            let sign_mask = _mm512_set1_ps(-0.0);
+            let truncated = _mm512_roundscale_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm512_andnot_ps(sign_mask, _mm512_sub_ps(a, truncated));
+            let sign = _mm512_and_ps(a, sign_mask);
+            let one = _mm512_or_ps(_mm512_set1_ps(1.0), sign);
+            let is_tie_or_above = _mm512_cmp_ps_mask(diff, _mm512_set1_ps(0.5), _CMP_GE_OQ);
+            let rounded = _mm512_mask_add_ps(truncated, is_tie_or_above, truncated, one);
+            _mm512_or_ps(rounded, sign)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::round_ties_away(a)
+        }
+        for Avx2(a: __m256) -> __m256 {
+            Ops::<Avx, f32>::round_ties_away(a)
+        }
+        for Avx(a: __m256) -> __m256 {
+            let sign_mask = _mm256_set1_ps(-0.0);
+            let truncated = _mm256_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm256_andnot_ps(sign_mask, _mm256_sub_ps(a, truncated));
+            let sign = _mm256_and_ps(a, sign_mask);
+            let one = _mm256_or_ps(_mm256_set1_ps(1.0), sign);
+            let carry = _mm256_and_ps(_mm256_cmp_ps(diff, _mm256_set1_ps(0.5), _CMP_GE_OQ), one);
+            _mm256_or_ps(_mm256_add_ps(truncated, carry), sign)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::round_ties_away(a)
+        }
+        for Sse41(a: __m128) -> __m128 {
+            let sign_mask = _mm_set1_ps(-0.0);
+            let truncated = _mm_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm_andnot_ps(sign_mask, _mm_sub_ps(a, truncated));
+            let sign = _mm_and_ps(a, sign_mask);
+            let one = _mm_or_ps(_mm_set1_ps(1.0), sign);
+            let carry = _mm_and_ps(_mm_cmpge_ps(diff, _mm_set1_ps(0.5)), one);
+            _mm_or_ps(_mm_add_ps(truncated, carry), sign)
+        }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::round_ties_away(a)
+        }
+        for Sse2(a: __m128) -> __m128 {
+            let sign_mask = _mm_set1_ps(-0.0);
+            let truncated = Ops::<Sse2, f32>::trunc(a);
+            let diff = _mm_andnot_ps(sign_mask, _mm_sub_ps(a, truncated));
+            let sign = _mm_and_ps(a, sign_mask);
+            let one = _mm_or_ps(_mm_set1_ps(1.0), sign);
+            let carry = _mm_and_ps(_mm_cmpge_ps(diff, _mm_set1_ps(0.5)), one);
+            _mm_or_ps(_mm_add_ps(truncated, carry), sign)
         }
         for Scalar(a: f32) -> f32 {
             a.m_round()
@@ -349,19 +645,85 @@ impl_op! {
             vrndaq_f32(a)
         }
         for Wasm(a: v128) -> v128 {
-            f32x4_nearest(a)
+            let sign_mask = f32x4_splat(-0.0);
+            let truncated = f32x4_trunc(a);
+            let diff = v128_andnot(f32x4_sub(a, truncated), sign_mask);
+            let sign = v128_and(a, sign_mask);
+            let one = v128_or(f32x4_splat(1.0), sign);
+            let carry = v128_and(f32x4_ge(diff, f32x4_splat(0.5)), one);
+            v128_or(f32x4_add(truncated, carry), sign)
+        }
+    }
+}
+
+impl_op! {
+    fn trunc<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_roundscale_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::trunc(a)
+        }
+        for Avx2(a: __m256) -> __m256 {
+            Ops::<Avx, f32>::trunc(a)
+        }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::trunc(a)
+        }
+        for Sse41(a: __m128) -> __m128 {
+            _mm_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::trunc(a)
+        }
+        for Sse2(a: __m128) -> __m128 {
+            // Round to the nearest integer, then step back towards zero where that rounded away.
+            let sign_mask = _mm_set1_ps(-0.0);
+            let rounded = Ops::<Sse2, f32>::round_ties_even(a);
+            let sign = _mm_and_ps(a, sign_mask);
+            let one = _mm_or_ps(_mm_set1_ps(1.0), sign);
+            let magnitude = _mm_andnot_ps(sign_mask, a);
+            let overshot = _mm_cmpgt_ps(_mm_andnot_ps(sign_mask, rounded), magnitude);
+            _mm_or_ps(_mm_sub_ps(rounded, _mm_and_ps(overshot, one)), sign)
+        }
+        for Scalar(a: f32) -> f32 {
+            a.m_trunc()
+        }
+        for Neon(a: float32x4_t) -> float32x4_t {
+            vrndq_f32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            f32x4_trunc(a)
         }
     }
 }
 
 impl_op! {
     fn floor<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_roundscale_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::floor(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_round_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_round_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::floor(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_round_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::floor(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             let nums_arr = core::mem::transmute::<__m128, [f32; 4]>(a);
             let ceil = [
@@ -386,12 +748,27 @@ impl_op! {
 
 impl_op! {
     fn ceil<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            _mm512_roundscale_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::ceil(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             _mm256_round_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256) -> __m256 {
+            _mm256_round_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::ceil(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             _mm_round_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::ceil(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             let nums_arr = core::mem::transmute::<__m128, [f32; 4]>(a);
             let ceil = [
@@ -416,35 +793,65 @@ impl_op! {
 
 impl_op! {
     fn fast_round<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            Self::round_ties_even(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::fast_round(a)
+        }
         for Avx2(a: __m256) -> __m256 {
-            Self::round(a)
+            Self::round_ties_even(a)
+        }
+        for Avx(a: __m256) -> __m256 {
+            Self::round_ties_even(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::fast_round(a)
         }
         for Sse41(a: __m128) -> __m128 {
-            Self::round(a)
+            Self::round_ties_even(a)
+        }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::fast_round(a)
         }
         for Sse2(a: __m128) -> __m128 {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Scalar(a: f32) -> f32 {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Neon(a: float32x4_t) -> float32x4_t {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Wasm(a: v128) -> v128 {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
     }
 }
 
 impl_op! {
     fn fast_floor<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            Self::floor(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::fast_floor(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             Self::floor(a)
         }
+        for Avx(a: __m256) -> __m256 {
+            Self::floor(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::fast_floor(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             Self::floor(a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::fast_floor(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             Self::floor(a)
         }
@@ -462,12 +869,27 @@ impl_op! {
 
 impl_op! {
     fn fast_ceil<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            Self::ceil(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::fast_ceil(a)
+        }
         for Avx2(a: __m256) -> __m256 {
             Self::ceil(a)
         }
+        for Avx(a: __m256) -> __m256 {
+            Self::ceil(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::fast_ceil(a)
+        }
         for Sse41(a: __m128) -> __m128 {
             Self::ceil(a)
         }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::fast_ceil(a)
+        }
         for Sse2(a: __m128) -> __m128 {
             Self::ceil(a)
         }
@@ -485,12 +907,27 @@ impl_op! {
 
 impl_op! {
     fn eq<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_EQ_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::eq(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_EQ_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_EQ_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::eq(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmpeq_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::eq(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmpeq_ps(a, b)
         }
@@ -512,12 +949,27 @@ impl_op! {
 
 impl_op! {
     fn neq<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_NEQ_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::neq(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_NEQ_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_NEQ_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::neq(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmpneq_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::neq(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmpneq_ps(a, b)
         }
@@ -539,12 +991,27 @@ impl_op! {
 
 impl_op! {
     fn lt<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_LT_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::lt(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_LT_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_LT_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::lt(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmplt_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::lt(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmplt_ps(a, b)
         }
@@ -566,12 +1033,27 @@ impl_op! {
 
 impl_op! {
     fn lte<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_LE_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::lte(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_LE_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_LE_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::lte(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmple_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::lte(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmple_ps(a, b)
         }
@@ -593,12 +1075,27 @@ impl_op! {
 
 impl_op! {
     fn gt<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_GT_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::gt(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_GT_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_GT_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::gt(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmpgt_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::gt(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmpgt_ps(a, b)
         }
@@ -620,12 +1117,27 @@ impl_op! {
 
 impl_op! {
     fn gte<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(_mm512_movm_epi32(_mm512_cmp_ps_mask(a, b, _CMP_GE_OQ)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::gte(a, b)
+        }
         for Avx2(a: __m256, b: __m256) -> __m256 {
             _mm256_cmp_ps(a, b, _CMP_GE_OQ)
         }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_cmp_ps(a, b, _CMP_GE_OQ)
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::gte(a, b)
+        }
         for Sse41(a: __m128, b: __m128) -> __m128 {
             _mm_cmpge_ps(a, b)
         }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::gte(a, b)
+        }
         for Sse2(a: __m128, b: __m128) -> __m128 {
             _mm_cmpge_ps(a, b)
         }
@@ -647,12 +1159,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<f32> {
+        for Avx512(a: __m512, b: __m512, mask: __m512) -> __m512 {
+            _mm512_mask_blend_ps(_mm512_movepi32_mask(_mm512_castps_si512(mask)), a, b)
+        }
+        for Avx2Fma(a: __m256, b: __m256, mask: __m256) -> __m256 {
+            Ops::<Avx2, f32>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256, b: __m256, mask: __m256) -> __m256 {
             _mm256_blendv_ps(a, b, mask)
         }
+        for Avx(a: __m256, b: __m256, mask: __m256) -> __m256 {
+            _mm256_blendv_ps(a, b, mask)
+        }
+        for Sse42(a: __m128, b: __m128, mask: __m128) -> __m128 {
+            Ops::<Sse41, f32>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128, b: __m128, mask: __m128) -> __m128 {
             _mm_blendv_ps(a, b, mask)
         }
+        for Ssse3(a: __m128, b: __m128, mask: __m128) -> __m128 {
+            Ops::<Sse2, f32>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128, b: __m128, mask: __m128) -> __m128 {
             _mm_or_ps(_mm_and_ps(mask, b), _mm_andnot_ps(mask, a))
         }
@@ -674,6 +1201,15 @@ impl_op! {
 
 impl_op! {
     fn horizontal_add<f32> {
+        for Avx512(a: __m512) -> f32 {
+            // Sum each 256 bit half the same way Avx2 does, so the rounding matches between engines.
+            let low = _mm512_castps512_ps256(a);
+            let high = _mm256_castpd_ps(_mm512_extractf64x4_pd(_mm512_castps_pd(a), 1));
+            Ops::<Avx2, f32>::horizontal_add(low) + Ops::<Avx2, f32>::horizontal_add(high)
+        }
+        for Avx2Fma(a: __m256) -> f32 {
+            Ops::<Avx2, f32>::horizontal_add(a)
+        }
         for Avx2(a: __m256) -> f32 {
             let a = _mm256_hadd_ps(a, a);
             let b = _mm256_hadd_ps(a, a);
@@ -683,12 +1219,27 @@ impl_op! {
 
             first + second
         }
+        for Avx(a: __m256) -> f32 {
+            let a = _mm256_hadd_ps(a, a);
+            let b = _mm256_hadd_ps(a, a);
+
+            let first = _mm_cvtss_f32(_mm256_extractf128_ps(b, 0));
+            let second = _mm_cvtss_f32(_mm256_extractf128_ps(b, 1));
+
+            first + second
+        }
+        for Sse42(a: __m128) -> f32 {
+            Ops::<Sse41, f32>::horizontal_add(a)
+        }
         for Sse41(a: __m128) -> f32 {
             let a = _mm_hadd_ps(a, a);
             let b = _mm_hadd_ps(a, a);
 
             _mm_cvtss_f32(b)
         }
+        for Ssse3(a: __m128) -> f32 {
+            Ops::<Sse2, f32>::horizontal_add(a)
+        }
         for Sse2(a: __m128) -> f32 {
             let t1 = _mm_movehl_ps(a, a);
             let t2 = _mm_add_ps(a, t1);
@@ -715,12 +1266,27 @@ impl_op! {
 
 impl_op! {
     fn cast_i32<f32> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_cvtps_epi32(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, f32>::cast_i32(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_cvtps_epi32(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_cvtps_epi32(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, f32>::cast_i32(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_cvtps_epi32(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, f32>::cast_i32(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_cvtps_epi32(a)
         }
@@ -741,12 +1307,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i32<f32> {
+        for Avx512(a: __m512) -> __m512i {
+            _mm512_castps_si512(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, f32>::bitcast_i32(a)
+        }
         for Avx2(a: __m256) -> __m256i {
             _mm256_castps_si256(a)
         }
+        for Avx(a: __m256) -> __m256i {
+            _mm256_castps_si256(a)
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, f32>::bitcast_i32(a)
+        }
         for Sse41(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, f32>::bitcast_i32(a)
+        }
         for Sse2(a: __m128) -> __m128i {
             _mm_castps_si128(a)
         }
@@ -764,12 +1345,27 @@ impl_op! {
 
 impl_op! {
     fn zeroes<f32> {
+        for Avx512() -> __m512 {
+            _mm512_setzero_ps()
+        }
+        for Avx2Fma() -> __m256 {
+            Ops::<Avx2, f32>::zeroes()
+        }
         for Avx2() -> __m256 {
             _mm256_setzero_ps()
         }
+        for Avx() -> __m256 {
+            _mm256_setzero_ps()
+        }
+        for Sse42() -> __m128 {
+            Ops::<Sse41, f32>::zeroes()
+        }
         for Sse41() -> __m128 {
             _mm_setzero_ps()
         }
+        for Ssse3() -> __m128 {
+            Ops::<Sse2, f32>::zeroes()
+        }
         for Sse2() -> __m128 {
             _mm_setzero_ps()
         }
@@ -787,12 +1383,27 @@ impl_op! {
 
 impl_op! {
     fn set1<f32> {
+        for Avx512(val: f32) -> __m512 {
+            _mm512_set1_ps(val)
+        }
+        for Avx2Fma(val: f32) -> __m256 {
+            Ops::<Avx2, f32>::set1(val)
+        }
         for Avx2(val: f32) -> __m256 {
             _mm256_set1_ps(val)
         }
+        for Avx(val: f32) -> __m256 {
+            _mm256_set1_ps(val)
+        }
+        for Sse42(val: f32) -> __m128 {
+            Ops::<Sse41, f32>::set1(val)
+        }
         for Sse41(val: f32) -> __m128 {
             _mm_set1_ps(val)
         }
+        for Ssse3(val: f32) -> __m128 {
+            Ops::<Sse2, f32>::set1(val)
+        }
         for Sse2(val: f32) -> __m128 {
             _mm_set1_ps(val)
         }
@@ -810,11 +1421,26 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<f32> {
+        for Avx512(ptr: *const f32) -> __m512 {
+            _mm512_loadu_ps(ptr)
+        }
+        for Avx2Fma(ptr: *const f32) -> __m256 {
+            Ops::<Avx2, f32>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const f32) -> __m256 {
             _mm256_loadu_ps(ptr)
         }
-        for Sse41(ptr: *const f32) -> __m128 {
-            _mm_loadu_ps(ptr)
+        for Avx(ptr: *const f32) -> __m256 {
+            _mm256_loadu_ps(ptr)
+        }
+        for Sse42(ptr: *const f32) -> __m128 {
+            Ops::<Sse41, f32>::load_unaligned(ptr)
+        }
+        for Sse41(ptr: *const f32) -> __m128 {
+            _mm_loadu_ps(ptr)
+        }
+        for Ssse3(ptr: *const f32) -> __m128 {
+            Ops::<Sse2, f32>::load_unaligned(ptr)
         }
         for Sse2(ptr: *const f32) -> __m128 {
             _mm_loadu_ps(ptr)
@@ -833,12 +1459,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<f32> {
+        for Avx512(ptr: *const f32) -> __m512 {
+            _mm512_load_ps(ptr)
+        }
+        for Avx2Fma(ptr: *const f32) -> __m256 {
+            Ops::<Avx2, f32>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const f32) -> __m256 {
             _mm256_load_ps(ptr)
         }
+        for Avx(ptr: *const f32) -> __m256 {
+            _mm256_load_ps(ptr)
+        }
+        for Sse42(ptr: *const f32) -> __m128 {
+            Ops::<Sse41, f32>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const f32) -> __m128 {
             _mm_load_ps(ptr)
         }
+        for Ssse3(ptr: *const f32) -> __m128 {
+            Ops::<Sse2, f32>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const f32) -> __m128 {
             _mm_load_ps(ptr)
         }
@@ -856,12 +1497,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<f32> {
+        for Avx512(ptr: *mut f32, a: __m512) {
+            _mm512_storeu_ps(ptr, a)
+        }
+        for Avx2Fma(ptr: *mut f32, a: __m256) {
+            Ops::<Avx2, f32>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut f32, a: __m256) {
             _mm256_storeu_ps(ptr, a)
         }
+        for Avx(ptr: *mut f32, a: __m256) {
+            _mm256_storeu_ps(ptr, a)
+        }
+        for Sse42(ptr: *mut f32, a: __m128) {
+            Ops::<Sse41, f32>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut f32, a: __m128) {
             _mm_storeu_ps(ptr, a)
         }
+        for Ssse3(ptr: *mut f32, a: __m128) {
+            Ops::<Sse2, f32>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut f32, a: __m128) {
             _mm_storeu_ps(ptr, a)
         }
@@ -879,12 +1535,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<f32> {
+        for Avx512(ptr: *mut f32, a: __m512) {
+            _mm512_store_ps(ptr, a)
+        }
+        for Avx2Fma(ptr: *mut f32, a: __m256) {
+            Ops::<Avx2, f32>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut f32, a: __m256) {
             _mm256_store_ps(ptr, a)
         }
+        for Avx(ptr: *mut f32, a: __m256) {
+            _mm256_store_ps(ptr, a)
+        }
+        for Sse42(ptr: *mut f32, a: __m128) {
+            Ops::<Sse41, f32>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut f32, a: __m128) {
             _mm_store_ps(ptr, a)
         }
+        for Ssse3(ptr: *mut f32, a: __m128) {
+            Ops::<Sse2, f32>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut f32, a: __m128) {
             _mm_store_ps(ptr, a)
         }
@@ -899,3 +1570,641 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn gather_unchecked<f32> {
+        for Avx512(base: *const f32, indices: __m512i) -> __m512 {
+            _mm512_i32gather_ps::<4>(indices, base)
+        }
+        for Avx2Fma(base: *const f32, indices: __m256i) -> __m256 {
+            Ops::<Avx2, f32>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const f32, indices: __m256i) -> __m256 {
+            _mm256_i32gather_ps(base, indices, 4)
+        }
+        for Avx(base: *const f32, indices: __m256i) -> __m256 {
+            let indices = core::mem::transmute::<__m256i, [i32; 8]>(indices);
+            core::mem::transmute::<[f32; 8], __m256>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Sse42(base: *const f32, indices: __m128i) -> __m128 {
+            Ops::<Sse41, f32>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const f32, indices: __m128i) -> __m128 {
+            Ops::<Sse2, f32>::gather_unchecked(base, indices)
+        }
+        for Ssse3(base: *const f32, indices: __m128i) -> __m128 {
+            Ops::<Sse2, f32>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const f32, indices: __m128i) -> __m128 {
+            let indices = core::mem::transmute::<__m128i, [i32; 4]>(indices);
+            core::mem::transmute::<[f32; 4], __m128>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Scalar(base: *const f32, indices: i32) -> f32 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const f32, indices: int32x4_t) -> float32x4_t {
+            let indices = core::mem::transmute::<int32x4_t, [i32; 4]>(indices);
+            core::mem::transmute::<[f32; 4], float32x4_t>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Wasm(base: *const f32, indices: v128) -> v128 {
+            let indices = core::mem::transmute::<v128, [i32; 4]>(indices);
+            core::mem::transmute::<[f32; 4], v128>(indices.map(|i| *base.add(i as usize)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<f32> {
+        for Avx512(base: *mut f32, indices: __m512i, val: __m512) {
+            _mm512_i32scatter_ps::<4>(base, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<f32> {
+        for Avx512(base: *mut f32, indices: __m512i, val: __m512, mask: __mmask16) {
+            _mm512_mask_i32scatter_ps::<4>(base, mask, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<f32> {
+        for Avx512(ptr: *const f32, mask: __mmask16) -> __m512 {
+            _mm512_maskz_loadu_ps(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const f32, mask: __m256i) -> __m256 {
+            Ops::<Avx2, f32>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const f32, mask: __m256i) -> __m256 {
+            _mm256_maskload_ps(ptr, mask)
+        }
+        for Avx(ptr: *const f32, mask: __m256i) -> __m256 {
+            _mm256_maskload_ps(ptr, mask)
+        }
+        for Sse42(ptr: *const f32, mask: __m128i) -> __m128 {
+            Ops::<Sse41, f32>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const f32, mask: __m128i) -> __m128 {
+            Ops::<Sse2, f32>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const f32, mask: __m128i) -> __m128 {
+            Ops::<Sse2, f32>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const f32, mask: __m128i) -> __m128 {
+            load_masked_emulated!(ptr, Ops::<Sse2, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+        for Scalar(ptr: *const f32, mask: i32) -> f32 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0.0
+            }
+        }
+        for Neon(ptr: *const f32, mask: int32x4_t) -> float32x4_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+        for Wasm(ptr: *const f32, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<f32> {
+        for Avx512(ptr: *mut f32, a: __m512, mask: __mmask16) {
+            _mm512_mask_storeu_ps(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut f32, a: __m256, mask: __m256i) {
+            Ops::<Avx2, f32>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut f32, a: __m256, mask: __m256i) {
+            _mm256_maskstore_ps(ptr, mask, a)
+        }
+        for Avx(ptr: *mut f32, a: __m256, mask: __m256i) {
+            _mm256_maskstore_ps(ptr, mask, a)
+        }
+        for Sse42(ptr: *mut f32, a: __m128, mask: __m128i) {
+            Ops::<Sse41, f32>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut f32, a: __m128, mask: __m128i) {
+            Ops::<Sse2, f32>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut f32, a: __m128, mask: __m128i) {
+            Ops::<Sse2, f32>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut f32, a: __m128, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+        for Scalar(ptr: *mut f32, a: f32, mask: i32) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut f32, a: float32x4_t, mask: int32x4_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+        for Wasm(ptr: *mut f32, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i32>::mask_to_bitmask(mask), [f32; 4])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<f32> {
+        for Avx512(a: __m512, indices: __m512i) -> __m512 {
+            _mm512_permutexvar_ps(indices, a)
+        }
+        for Avx2Fma(a: __m256, indices: __m256i) -> __m256 {
+            Ops::<Avx2, f32>::permute(a, indices)
+        }
+        for Avx2(a: __m256, indices: __m256i) -> __m256 {
+            _mm256_permutevar8x32_ps(a, indices)
+        }
+        for Avx(a: __m256, indices: __m256i) -> __m256 {
+            let a = _mm256_castps_si256(a);
+            _mm256_castsi256_ps(Ops::<Avx, i32>::permute(a, indices))
+        }
+        for Sse42(a: __m128, indices: __m128i) -> __m128 {
+            Ops::<Sse41, f32>::permute(a, indices)
+        }
+        for Sse41(a: __m128, indices: __m128i) -> __m128 {
+            let a = _mm_castps_si128(a);
+            _mm_castsi128_ps(Ops::<Sse41, i32>::permute(a, indices))
+        }
+        for Ssse3(a: __m128, indices: __m128i) -> __m128 {
+            let a = _mm_castps_si128(a);
+            _mm_castsi128_ps(Ops::<Ssse3, i32>::permute(a, indices))
+        }
+        for Sse2(a: __m128, indices: __m128i) -> __m128 {
+            let a = _mm_castps_si128(a);
+            _mm_castsi128_ps(Ops::<Sse2, i32>::permute(a, indices))
+        }
+        for Scalar(a: f32, indices: i32) -> f32 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: float32x4_t, indices: int32x4_t) -> float32x4_t {
+            let a = vreinterpretq_s32_f32(a);
+            vreinterpretq_f32_s32(Ops::<Neon, i32>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i32>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(Ops::<Avx512, i32>::zip_lo(_mm512_castps_si512(a), _mm512_castps_si512(b)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256, b: __m256) -> __m256 {
+            _mm256_castsi256_ps(Ops::<Avx2, i32>::zip_lo(_mm256_castps_si256(a), _mm256_castps_si256(b)))
+        }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_castsi256_ps(Ops::<Avx, i32>::zip_lo(_mm256_castps_si256(a), _mm256_castps_si256(b)))
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128, b: __m128) -> __m128 {
+            _mm_castsi128_ps(Ops::<Sse2, i32>::zip_lo(_mm_castps_si128(a), _mm_castps_si128(b)))
+        }
+        for Scalar(a: f32, b: f32) -> f32 {
+            let _ = b;
+            a
+        }
+        for Neon(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+            vreinterpretq_f32_s32(Ops::<Neon, i32>::zip_lo(vreinterpretq_s32_f32(a), vreinterpretq_s32_f32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<f32> {
+        for Avx512(a: __m512, b: __m512) -> __m512 {
+            _mm512_castsi512_ps(Ops::<Avx512, i32>::zip_hi(_mm512_castps_si512(a), _mm512_castps_si512(b)))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> __m256 {
+            Ops::<Avx2, f32>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256, b: __m256) -> __m256 {
+            _mm256_castsi256_ps(Ops::<Avx2, i32>::zip_hi(_mm256_castps_si256(a), _mm256_castps_si256(b)))
+        }
+        for Avx(a: __m256, b: __m256) -> __m256 {
+            _mm256_castsi256_ps(Ops::<Avx, i32>::zip_hi(_mm256_castps_si256(a), _mm256_castps_si256(b)))
+        }
+        for Sse42(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse41, f32>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128, b: __m128) -> __m128 {
+            Ops::<Sse2, f32>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128, b: __m128) -> __m128 {
+            _mm_castsi128_ps(Ops::<Sse2, i32>::zip_hi(_mm_castps_si128(a), _mm_castps_si128(b)))
+        }
+        for Scalar(a: f32, b: f32) -> f32 {
+            let _ = a;
+            b
+        }
+        for Neon(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+            vreinterpretq_f32_s32(Ops::<Neon, i32>::zip_hi(vreinterpretq_s32_f32(a), vreinterpretq_s32_f32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<f32> {
+        for Avx512(a: __m512, b: __m512) -> (__m512, __m512) {
+            let (x, y) = Ops::<Avx512, i32>::unzip(_mm512_castps_si512(a), _mm512_castps_si512(b));
+            (_mm512_castsi512_ps(x), _mm512_castsi512_ps(y))
+        }
+        for Avx2Fma(a: __m256, b: __m256) -> (__m256, __m256) {
+            Ops::<Avx2, f32>::unzip(a, b)
+        }
+        for Avx2(a: __m256, b: __m256) -> (__m256, __m256) {
+            let (x, y) = Ops::<Avx2, i32>::unzip(_mm256_castps_si256(a), _mm256_castps_si256(b));
+            (_mm256_castsi256_ps(x), _mm256_castsi256_ps(y))
+        }
+        for Avx(a: __m256, b: __m256) -> (__m256, __m256) {
+            let (x, y) = Ops::<Avx, i32>::unzip(_mm256_castps_si256(a), _mm256_castps_si256(b));
+            (_mm256_castsi256_ps(x), _mm256_castsi256_ps(y))
+        }
+        for Sse42(a: __m128, b: __m128) -> (__m128, __m128) {
+            Ops::<Sse41, f32>::unzip(a, b)
+        }
+        for Sse41(a: __m128, b: __m128) -> (__m128, __m128) {
+            Ops::<Sse2, f32>::unzip(a, b)
+        }
+        for Ssse3(a: __m128, b: __m128) -> (__m128, __m128) {
+            Ops::<Sse2, f32>::unzip(a, b)
+        }
+        for Sse2(a: __m128, b: __m128) -> (__m128, __m128) {
+            let (x, y) = Ops::<Sse2, i32>::unzip(_mm_castps_si128(a), _mm_castps_si128(b));
+            (_mm_castsi128_ps(x), _mm_castsi128_ps(y))
+        }
+        for Scalar(a: f32, b: f32) -> (f32, f32) {
+            (a, b)
+        }
+        for Neon(a: float32x4_t, b: float32x4_t) -> (float32x4_t, float32x4_t) {
+            let (x, y) = Ops::<Neon, i32>::unzip(vreinterpretq_s32_f32(a), vreinterpretq_s32_f32(b));
+            (vreinterpretq_f32_s32(x), vreinterpretq_f32_s32(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i32>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t) {
+            let loaded = vld2q_f32(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t) {
+            vst2q_f32(ptr, float32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t, float32x4_t) {
+            let loaded = vld3q_f32(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t, c: float32x4_t) {
+            vst3q_f32(ptr, float32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<f32> {
+        for Neon(ptr: *const f32) -> (float32x4_t, float32x4_t, float32x4_t, float32x4_t) {
+            let loaded = vld4q_f32(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<f32> {
+        for Neon(ptr: *mut f32, a: float32x4_t, b: float32x4_t, c: float32x4_t, d: float32x4_t) {
+            vst4q_f32(ptr, float32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn transpose<f32> {
+        for Avx512(rows: [__m512; 16]) -> [__m512; 16] {
+            let mut ints = [_mm512_setzero_si512(); 16];
+            for i in 0..16 {
+                ints[i] = _mm512_castps_si512(rows[i]);
+            }
+            let ints = Ops::<Avx512, i32>::transpose(ints);
+            let mut out = rows;
+            for i in 0..16 {
+                out[i] = _mm512_castsi512_ps(ints[i]);
+            }
+            out
+        }
+        for Avx2Fma(rows: [__m256; 8]) -> [__m256; 8] {
+            Ops::<Avx2, f32>::transpose(rows)
+        }
+        for Avx2(rows: [__m256; 8]) -> [__m256; 8] {
+            Ops::<Avx, f32>::transpose(rows)
+        }
+        for Avx(rows: [__m256; 8]) -> [__m256; 8] {
+            let mut cols = rows;
+            for g in 0..2 {
+                let t0 = _mm256_unpacklo_ps(rows[4 * g], rows[4 * g + 1]);
+                let t1 = _mm256_unpackhi_ps(rows[4 * g], rows[4 * g + 1]);
+                let t2 = _mm256_unpacklo_ps(rows[4 * g + 2], rows[4 * g + 3]);
+                let t3 = _mm256_unpackhi_ps(rows[4 * g + 2], rows[4 * g + 3]);
+                cols[4 * g] = _mm256_shuffle_ps::<0x44>(t0, t2);
+                cols[4 * g + 1] = _mm256_shuffle_ps::<0xEE>(t0, t2);
+                cols[4 * g + 2] = _mm256_shuffle_ps::<0x44>(t1, t3);
+                cols[4 * g + 3] = _mm256_shuffle_ps::<0xEE>(t1, t3);
+            }
+            let mut out = cols;
+            for m in 0..4 {
+                out[m] = _mm256_permute2f128_ps::<0x20>(cols[m], cols[4 + m]);
+                out[4 + m] = _mm256_permute2f128_ps::<0x31>(cols[m], cols[4 + m]);
+            }
+            out
+        }
+        for Sse42(rows: [__m128; 4]) -> [__m128; 4] {
+            Ops::<Sse41, f32>::transpose(rows)
+        }
+        for Sse41(rows: [__m128; 4]) -> [__m128; 4] {
+            Ops::<Sse2, f32>::transpose(rows)
+        }
+        for Ssse3(rows: [__m128; 4]) -> [__m128; 4] {
+            Ops::<Sse2, f32>::transpose(rows)
+        }
+        for Sse2(rows: [__m128; 4]) -> [__m128; 4] {
+            let [mut r0, mut r1, mut r2, mut r3] = rows;
+            _MM_TRANSPOSE4_PS(&mut r0, &mut r1, &mut r2, &mut r3);
+            [r0, r1, r2, r3]
+        }
+        for Scalar(rows: [f32; 1]) -> [f32; 1] {
+            rows
+        }
+        for Neon(rows: [float32x4_t; 4]) -> [float32x4_t; 4] {
+            let [r0, r1, r2, r3] = rows;
+            let ints = Ops::<Neon, i32>::transpose([
+                vreinterpretq_s32_f32(r0),
+                vreinterpretq_s32_f32(r1),
+                vreinterpretq_s32_f32(r2),
+                vreinterpretq_s32_f32(r3),
+            ]);
+            [
+                vreinterpretq_f32_s32(ints[0]),
+                vreinterpretq_f32_s32(ints[1]),
+                vreinterpretq_f32_s32(ints[2]),
+                vreinterpretq_f32_s32(ints[3]),
+            ]
+        }
+        for Wasm(rows: [v128; 4]) -> [v128; 4] {
+            Ops::<Wasm, i32>::transpose(rows)
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            let bits = _mm512_castps_si512(a);
+            let shifted = _mm512_castsi512_ps(_mm512_alignr_epi32::<15>(bits, _mm512_setzero_si512()));
+            let a = _mm512_add_ps(a, shifted);
+            let bits = _mm512_castps_si512(a);
+            let shifted = _mm512_castsi512_ps(_mm512_alignr_epi32::<14>(bits, _mm512_setzero_si512()));
+            let a = _mm512_add_ps(a, shifted);
+            let bits = _mm512_castps_si512(a);
+            let shifted = _mm512_castsi512_ps(_mm512_alignr_epi32::<12>(bits, _mm512_setzero_si512()));
+            let a = _mm512_add_ps(a, shifted);
+            let bits = _mm512_castps_si512(a);
+            let shifted = _mm512_castsi512_ps(_mm512_alignr_epi32::<8>(bits, _mm512_setzero_si512()));
+            _mm512_add_ps(a, shifted)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::prefix_sum(a)
+        }
+        for Avx2(a: __m256) -> __m256 {
+            let bits = _mm256_castps_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            let a = _mm256_add_ps(a, _mm256_castsi256_ps(_mm256_alignr_epi8::<12>(bits, carry)));
+            let bits = _mm256_castps_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            let a = _mm256_add_ps(a, _mm256_castsi256_ps(_mm256_alignr_epi8::<8>(bits, carry)));
+            let bits = _mm256_castps_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            _mm256_add_ps(a, _mm256_castsi256_ps(carry))
+        }
+        for Avx(a: __m256) -> __m256 {
+            let lo = Ops::<Sse41, f32>::prefix_sum(_mm256_castps256_ps128(a));
+            let hi = Ops::<Sse41, f32>::prefix_sum(_mm256_extractf128_ps::<1>(a));
+            let hi = _mm_add_ps(hi, _mm_shuffle_ps::<0xFF>(lo, lo));
+            _mm256_insertf128_ps::<1>(_mm256_castps128_ps256(lo), hi)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::prefix_sum(a)
+        }
+        for Sse41(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::prefix_sum(a)
+        }
+        for Sse2(a: __m128) -> __m128 {
+            let a = _mm_add_ps(a, _mm_castsi128_ps(_mm_slli_si128::<4>(_mm_castps_si128(a))));
+            _mm_add_ps(a, _mm_castsi128_ps(_mm_slli_si128::<8>(_mm_castps_si128(a))))
+        }
+        for Scalar(a: f32) -> f32 {
+            a
+        }
+        for Neon(a: float32x4_t) -> float32x4_t {
+            let a = vaddq_f32(a, vextq_f32::<3>(vdupq_n_f32(0.0), a));
+            vaddq_f32(a, vextq_f32::<2>(vdupq_n_f32(0.0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = f32x4_add(a, i32x4_shuffle::<4, 0, 1, 2>(a, i32x4_splat(0)));
+            f32x4_add(a, i32x4_shuffle::<4, 4, 0, 1>(a, i32x4_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<f32> {
+        for Avx512(a: __m512) -> __m512 {
+            let bits = _mm512_castps_si512(a);
+            let a = _mm512_castsi512_ps(_mm512_alignr_epi32::<15>(bits, _mm512_setzero_si512()));
+            Ops::<Avx512, f32>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256) -> __m256 {
+            Ops::<Avx2, f32>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256) -> __m256 {
+            let bits = _mm256_castps_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            Ops::<Avx2, f32>::prefix_sum(_mm256_castsi256_ps(_mm256_alignr_epi8::<12>(bits, carry)))
+        }
+        for Avx(a: __m256) -> __m256 {
+            let (lo, hi) = split_m256i!(_mm256_castps_si256(a));
+            let a = _mm256_castsi256_ps(join_m128i!(_mm_slli_si128::<4>(lo), _mm_alignr_epi8::<12>(hi, lo)));
+            Ops::<Avx, f32>::prefix_sum(a)
+        }
+        for Sse42(a: __m128) -> __m128 {
+            Ops::<Sse41, f32>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128) -> __m128 {
+            Ops::<Sse2, f32>::prefix_sum(_mm_castsi128_ps(_mm_slli_si128::<4>(_mm_castps_si128(a))))
+        }
+        for Scalar(a: f32) -> f32 {
+            let _ = a;
+            0.0
+        }
+        for Neon(a: float32x4_t) -> float32x4_t {
+            Ops::<Neon, f32>::prefix_sum(vextq_f32::<3>(vdupq_n_f32(0.0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, f32>::prefix_sum(i32x4_shuffle::<4, 0, 1, 2>(a, i32x4_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn extend_f64<f32> {
+        for Avx512(a: __m512) -> (__m512d, __m512d) {
+            let lo = _mm512_cvtps_pd(_mm512_castps512_ps256(a));
+            let hi = _mm512_cvtps_pd(_mm512_extractf32x8_ps::<1>(a));
+            (lo, hi)
+        }
+        for Avx2Fma(a: __m256) -> (__m256d, __m256d) {
+            Ops::<Avx2, f32>::extend_f64(a)
+        }
+        for Avx2(a: __m256) -> (__m256d, __m256d) {
+            Ops::<Avx, f32>::extend_f64(a)
+        }
+        for Avx(a: __m256) -> (__m256d, __m256d) {
+            let lo = _mm256_cvtps_pd(_mm256_castps256_ps128(a));
+            let hi = _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(a));
+            (lo, hi)
+        }
+        for Sse42(a: __m128) -> (__m128d, __m128d) {
+            Ops::<Sse41, f32>::extend_f64(a)
+        }
+        for Sse41(a: __m128) -> (__m128d, __m128d) {
+            Ops::<Sse2, f32>::extend_f64(a)
+        }
+        for Ssse3(a: __m128) -> (__m128d, __m128d) {
+            Ops::<Sse2, f32>::extend_f64(a)
+        }
+        for Sse2(a: __m128) -> (__m128d, __m128d) {
+            (_mm_cvtps_pd(a), _mm_cvtps_pd(_mm_movehl_ps(a, a)))
+        }
+        for Scalar(a: f32) -> (f64, f64) {
+            (a as f64, 0.0)
+        }
+        for Neon(a: float32x4_t) -> (float64x2_t, float64x2_t) {
+            (vcvt_f64_f32(vget_low_f32(a)), vcvt_high_f64_f32(a))
+        }
+        for Wasm(a: v128) -> (v128, v128) {
+            let hi = i32x4_shuffle::<2, 3, 2, 3>(a, a);
+            (f64x2_promote_low_f32x4(a), f64x2_promote_low_f32x4(hi))
+        }
+    }
+}
+
+impl_op! {
+    fn trunc_i32<f32> {
+        for Avx512(a: __m512) -> __m512i {
+            // Lanes that overflow or are NaN come out as i32::MIN, so fix the positive and NaN ones.
+            let truncated = _mm512_cvttps_epi32(a);
+            let too_big = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(a, _mm512_set1_ps(2147483648.0));
+            let saturated = _mm512_mask_mov_epi32(truncated, too_big, _mm512_set1_epi32(i32::MAX));
+            _mm512_maskz_mov_epi32(_mm512_cmp_ps_mask::<_CMP_ORD_Q>(a, a), saturated)
+        }
+        for Avx2Fma(a: __m256) -> __m256i {
+            Ops::<Avx2, f32>::trunc_i32(a)
+        }
+        for Avx2(a: __m256) -> __m256i {
+            // Lanes that overflow or are NaN come out as i32::MIN. Flipping the bits of the positive
+            // ones gives i32::MAX, and the NaN ones are cleared.
+            let truncated = _mm256_cvttps_epi32(a);
+            let too_big = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_GE_OQ>(a, _mm256_set1_ps(2147483648.0)));
+            let not_nan = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_ORD_Q>(a, a));
+            _mm256_and_si256(_mm256_xor_si256(truncated, too_big), not_nan)
+        }
+        for Avx(a: __m256) -> __m256i {
+            let truncated = _mm256_castsi256_ps(_mm256_cvttps_epi32(a));
+            let too_big = _mm256_cmp_ps::<_CMP_GE_OQ>(a, _mm256_set1_ps(2147483648.0));
+            let not_nan = _mm256_cmp_ps::<_CMP_ORD_Q>(a, a);
+            _mm256_castps_si256(_mm256_and_ps(_mm256_xor_ps(truncated, too_big), not_nan))
+        }
+        for Sse42(a: __m128) -> __m128i {
+            Ops::<Sse41, f32>::trunc_i32(a)
+        }
+        for Sse41(a: __m128) -> __m128i {
+            Ops::<Sse2, f32>::trunc_i32(a)
+        }
+        for Ssse3(a: __m128) -> __m128i {
+            Ops::<Sse2, f32>::trunc_i32(a)
+        }
+        for Sse2(a: __m128) -> __m128i {
+            let truncated = _mm_cvttps_epi32(a);
+            let too_big = _mm_castps_si128(_mm_cmpge_ps(a, _mm_set1_ps(2147483648.0)));
+            let not_nan = _mm_castps_si128(_mm_cmpord_ps(a, a));
+            _mm_and_si128(_mm_xor_si128(truncated, too_big), not_nan)
+        }
+        for Scalar(a: f32) -> i32 {
+            a as i32
+        }
+        for Neon(a: float32x4_t) -> int32x4_t {
+            vcvtq_s32_f32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            i32x4_trunc_sat_f32x4(a)
+        }
+    }
+}
@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_add_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::add(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_add_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_add_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::add(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_add_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::add(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_add_pd(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_sub_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::sub(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_sub_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_sub_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::sub(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_sub_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::sub(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_sub_pd(a, b)
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn mul<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_mul_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::mul(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_mul_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_mul_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::mul(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_mul_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::mul(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_mul_pd(a, b)
         }
@@ -71,12 +116,27 @@ impl_op! {
 
 impl_op! {
     fn div<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_div_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::div(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_div_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_div_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::div(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_div_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::div(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_div_pd(a, b)
         }
@@ -94,12 +154,27 @@ impl_op! {
 
 impl_op! {
     fn mul_add<f64> {
-        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+        for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+            _mm512_fmadd_pd(a, b, c)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
             _mm256_fmadd_pd(a, b, c)
         }
+        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_add_pd(_mm256_mul_pd(a, b), c)
+        }
+        for Avx(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_add_pd(_mm256_mul_pd(a, b), c)
+        }
+        for Sse42(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::mul_add(a, b, c)
+        }
         for Sse41(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_add_pd(_mm_mul_pd(a, b), c)
         }
+        for Ssse3(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::mul_add(a, b, c)
+        }
         for Sse2(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_add_pd(_mm_mul_pd(a, b), c)
         }
@@ -117,12 +192,27 @@ impl_op! {
 
 impl_op! {
     fn mul_sub<f64> {
-        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+        for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+            _mm512_fmsub_pd(a, b, c)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
             _mm256_fmsub_pd(a, b, c)
         }
+        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_sub_pd(_mm256_mul_pd(a, b), c)
+        }
+        for Avx(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_sub_pd(_mm256_mul_pd(a, b), c)
+        }
+        for Sse42(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::mul_sub(a, b, c)
+        }
         for Sse41(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_sub_pd(_mm_mul_pd(a, b), c)
         }
+        for Ssse3(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::mul_sub(a, b, c)
+        }
         for Sse2(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_sub_pd(_mm_mul_pd(a, b), c)
         }
@@ -140,12 +230,27 @@ impl_op! {
 
 impl_op! {
     fn neg_mul_add<f64> {
-        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+        for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+            _mm512_fnmadd_pd(a, b, c)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
             _mm256_fnmadd_pd(a, b, c)
         }
+        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_sub_pd(c, _mm256_mul_pd(a, b))
+        }
+        for Avx(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            _mm256_sub_pd(c, _mm256_mul_pd(a, b))
+        }
+        for Sse42(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::neg_mul_add(a, b, c)
+        }
         for Sse41(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_sub_pd(c, _mm_mul_pd(a, b))
         }
+        for Ssse3(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::neg_mul_add(a, b, c)
+        }
         for Sse2(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             _mm_sub_pd(c, _mm_mul_pd(a, b))
         }
@@ -163,14 +268,33 @@ impl_op! {
 
 impl_op! {
     fn neg_mul_sub<f64> {
-        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+        for Avx512(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+            _mm512_fnmsub_pd(a, b, c)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
             _mm256_fnmsub_pd(a, b, c)
         }
+        for Avx2(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            let mul = _mm256_mul_pd(a, b);
+            let neg = _mm256_sub_pd(_mm256_setzero_pd(), mul);
+            _mm256_sub_pd(neg, c)
+        }
+        for Avx(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+            let mul = _mm256_mul_pd(a, b);
+            let neg = _mm256_sub_pd(_mm256_setzero_pd(), mul);
+            _mm256_sub_pd(neg, c)
+        }
+        for Sse42(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::neg_mul_sub(a, b, c)
+        }
         for Sse41(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             let mul = _mm_mul_pd(a, b);
             let neg = _mm_sub_pd(_mm_setzero_pd(), mul);
             _mm_sub_pd(neg, c)
         }
+        for Ssse3(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::neg_mul_sub(a, b, c)
+        }
         for Sse2(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
             let mul = _mm_mul_pd(a, b);
             let neg = _mm_sub_pd(_mm_setzero_pd(), mul);
@@ -190,12 +314,27 @@ impl_op! {
 
 impl_op! {
     fn sqrt<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_sqrt_pd(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::sqrt(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             _mm256_sqrt_pd(a)
         }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_sqrt_pd(a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::sqrt(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             _mm_sqrt_pd(a)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::sqrt(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             _mm_sqrt_pd(a)
         }
@@ -213,14 +352,31 @@ impl_op! {
 
 impl_op! {
     fn rsqrt<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            let one = _mm512_set1_pd(1.0);
+            _mm512_div_pd(one, _mm512_sqrt_pd(a))
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::rsqrt(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             let one = _mm256_set1_pd(1.0);
             _mm256_div_pd(one, _mm256_sqrt_pd(a))
         }
+        for Avx(a: __m256d) -> __m256d {
+            let one = _mm256_set1_pd(1.0);
+            _mm256_div_pd(one, _mm256_sqrt_pd(a))
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::rsqrt(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             let one = _mm_set1_pd(1.0);
             _mm_div_pd(one, _mm_sqrt_pd(a))
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::rsqrt(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             let one = _mm_set1_pd(1.0);
             _mm_div_pd(one, _mm_sqrt_pd(a))
@@ -239,12 +395,27 @@ impl_op! {
 
 impl_op! {
     fn min<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_min_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::min(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_min_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_min_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::min(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_min_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::min(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_min_pd(a, b)
         }
@@ -262,12 +433,27 @@ impl_op! {
 
 impl_op! {
     fn max<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_max_pd(a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::max(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_max_pd(a, b)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_max_pd(a, b)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::max(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_max_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::max(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_max_pd(a, b)
         }
@@ -285,12 +471,27 @@ impl_op! {
 
 impl_op! {
     fn abs<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_abs_pd(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::abs(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             _mm256_andnot_pd(_mm256_set1_pd(-0.0), a)
         }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_andnot_pd(_mm256_set1_pd(-0.0), a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::abs(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             _mm_andnot_pd(_mm_set1_pd(-0.0), a)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::abs(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             _mm_andnot_pd(_mm_set1_pd(-0.0), a)
         }
@@ -307,25 +508,42 @@ impl_op! {
 }
 
 impl_op! {
-    fn round<f64> {
+    fn round_ties_even<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_roundscale_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::round_ties_even(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             _mm256_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::round_ties_even(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             _mm_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::round_ties_even(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
+            // Adding and subtracting the magic number pushes the fraction out of the mantissa, which
+            // rounds ties to even. Values at or above the magic number are already integers.
             let sign_mask = _mm_set1_pd(-0.0);
-            let magic = _mm_castsi128_pd(_mm_set_epi32(
-                0x43300000, 0, 0x43300000, 0,
-            ));
+            let magic = _mm_castsi128_pd(_mm_set1_epi64x(0x4330000000000000));
             let sign = _mm_and_pd(a, sign_mask);
-            let signedmagic = _mm_or_pd(magic, sign);
-            let b = _mm_add_pd(a, signedmagic);
-            _mm_sub_pd(b, signedmagic)
+            let signed_magic = _mm_or_pd(magic, sign);
+            let rounded = _mm_sub_pd(_mm_add_pd(a, signed_magic), signed_magic);
+            let is_small = _mm_cmplt_pd(_mm_andnot_pd(sign_mask, a), magic);
+            let rounded = _mm_or_pd(_mm_and_pd(is_small, rounded), _mm_andnot_pd(is_small, a));
+            _mm_or_pd(rounded, sign)
         }
         for Scalar(a: f64) -> f64 {
-            a.m_round()
+            a.m_round_ties_even()
         }
         for Neon(a: float64x2_t) -> float64x2_t {
             vrndnq_f64(a)
@@ -336,14 +554,143 @@ impl_op! {
     }
 }
 
+impl_op! {
+    fn round_ties_away<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            let sign_mask = _mm512_set1_pd(-0.0);
+            let truncated = _mm512_roundscale_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm512_andnot_pd(sign_mask, _mm512_sub_pd(a, truncated));
+            let sign = _mm512_and_pd(a, sign_mask);
+            let one = _mm512_or_pd(_mm512_set1_pd(1.0), sign);
+            let is_tie_or_above = _mm512_cmp_pd_mask(diff, _mm512_set1_pd(0.5), _CMP_GE_OQ);
+            let rounded = _mm512_mask_add_pd(truncated, is_tie_or_above, truncated, one);
+            _mm512_or_pd(rounded, sign)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::round_ties_away(a)
+        }
+        for Avx2(a: __m256d) -> __m256d {
+            Ops::<Avx, f64>::round_ties_away(a)
+        }
+        for Avx(a: __m256d) -> __m256d {
+            let sign_mask = _mm256_set1_pd(-0.0);
+            let truncated = _mm256_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm256_andnot_pd(sign_mask, _mm256_sub_pd(a, truncated));
+            let sign = _mm256_and_pd(a, sign_mask);
+            let one = _mm256_or_pd(_mm256_set1_pd(1.0), sign);
+            let carry = _mm256_and_pd(_mm256_cmp_pd(diff, _mm256_set1_pd(0.5), _CMP_GE_OQ), one);
+            _mm256_or_pd(_mm256_add_pd(truncated, carry), sign)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::round_ties_away(a)
+        }
+        for Sse41(a: __m128d) -> __m128d {
+            let sign_mask = _mm_set1_pd(-0.0);
+            let truncated = _mm_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+            let diff = _mm_andnot_pd(sign_mask, _mm_sub_pd(a, truncated));
+            let sign = _mm_and_pd(a, sign_mask);
+            let one = _mm_or_pd(_mm_set1_pd(1.0), sign);
+            let carry = _mm_and_pd(_mm_cmpge_pd(diff, _mm_set1_pd(0.5)), one);
+            _mm_or_pd(_mm_add_pd(truncated, carry), sign)
+        }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::round_ties_away(a)
+        }
+        for Sse2(a: __m128d) -> __m128d {
+            let sign_mask = _mm_set1_pd(-0.0);
+            let truncated = Ops::<Sse2, f64>::trunc(a);
+            let diff = _mm_andnot_pd(sign_mask, _mm_sub_pd(a, truncated));
+            let sign = _mm_and_pd(a, sign_mask);
+            let one = _mm_or_pd(_mm_set1_pd(1.0), sign);
+            let carry = _mm_and_pd(_mm_cmpge_pd(diff, _mm_set1_pd(0.5)), one);
+            _mm_or_pd(_mm_add_pd(truncated, carry), sign)
+        }
+        for Scalar(a: f64) -> f64 {
+            a.m_round()
+        }
+        for Neon(a: float64x2_t) -> float64x2_t {
+            vrndaq_f64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let sign_mask = f64x2_splat(-0.0);
+            let truncated = f64x2_trunc(a);
+            let diff = v128_andnot(f64x2_sub(a, truncated), sign_mask);
+            let sign = v128_and(a, sign_mask);
+            let one = v128_or(f64x2_splat(1.0), sign);
+            let carry = v128_and(f64x2_ge(diff, f64x2_splat(0.5)), one);
+            v128_or(f64x2_add(truncated, carry), sign)
+        }
+    }
+}
+
+impl_op! {
+    fn trunc<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_roundscale_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::trunc(a)
+        }
+        for Avx2(a: __m256d) -> __m256d {
+            Ops::<Avx, f64>::trunc(a)
+        }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::trunc(a)
+        }
+        for Sse41(a: __m128d) -> __m128d {
+            _mm_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)
+        }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::trunc(a)
+        }
+        for Sse2(a: __m128d) -> __m128d {
+            // Round to the nearest integer, then step back towards zero where that rounded away.
+            let sign_mask = _mm_set1_pd(-0.0);
+            let rounded = Ops::<Sse2, f64>::round_ties_even(a);
+            let sign = _mm_and_pd(a, sign_mask);
+            let one = _mm_or_pd(_mm_set1_pd(1.0), sign);
+            let magnitude = _mm_andnot_pd(sign_mask, a);
+            let overshot = _mm_cmpgt_pd(_mm_andnot_pd(sign_mask, rounded), magnitude);
+            _mm_or_pd(_mm_sub_pd(rounded, _mm_and_pd(overshot, one)), sign)
+        }
+        for Scalar(a: f64) -> f64 {
+            a.m_trunc()
+        }
+        for Neon(a: float64x2_t) -> float64x2_t {
+            vrndq_f64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            f64x2_trunc(a)
+        }
+    }
+}
+
 impl_op! {
     fn floor<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_roundscale_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::floor(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             _mm256_round_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_round_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::floor(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             _mm_round_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::floor(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
             let ceil = [
@@ -366,12 +713,27 @@ impl_op! {
 
 impl_op! {
     fn ceil<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            _mm512_roundscale_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::ceil(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             _mm256_round_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
         }
+        for Avx(a: __m256d) -> __m256d {
+            _mm256_round_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::ceil(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             _mm_round_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::ceil(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
             let ceil = [
@@ -394,35 +756,65 @@ impl_op! {
 
 impl_op! {
     fn fast_round<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            Self::round_ties_even(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::fast_round(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
-            Self::round(a)
+            Self::round_ties_even(a)
+        }
+        for Avx(a: __m256d) -> __m256d {
+            Self::round_ties_even(a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::fast_round(a)
         }
         for Sse41(a: __m128d) -> __m128d {
-            Self::round(a)
+            Self::round_ties_even(a)
+        }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::fast_round(a)
         }
         for Sse2(a: __m128d) -> __m128d {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Scalar(a: f64) -> f64 {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Neon(a: float64x2_t) -> float64x2_t {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
         for Wasm(a: v128) -> v128 {
-            Self::round(a)
+            Self::round_ties_even(a)
         }
     }
 }
 
 impl_op! {
     fn fast_floor<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            Self::floor(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::fast_floor(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             Self::floor(a)
         }
+        for Avx(a: __m256d) -> __m256d {
+            Self::floor(a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::fast_floor(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             Self::floor(a)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::fast_floor(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             Self::floor(a)
         }
@@ -440,12 +832,27 @@ impl_op! {
 
 impl_op! {
     fn fast_ceil<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            Self::ceil(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::fast_ceil(a)
+        }
         for Avx2(a: __m256d) -> __m256d {
             Self::ceil(a)
         }
+        for Avx(a: __m256d) -> __m256d {
+            Self::ceil(a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::fast_ceil(a)
+        }
         for Sse41(a: __m128d) -> __m128d {
             Self::ceil(a)
         }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::fast_ceil(a)
+        }
         for Sse2(a: __m128d) -> __m128d {
             Self::ceil(a)
         }
@@ -463,12 +870,27 @@ impl_op! {
 
 impl_op! {
     fn eq<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_EQ_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::eq(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_EQ_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_EQ_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::eq(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpeq_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::eq(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpeq_pd(a, b)
         }
@@ -490,12 +912,27 @@ impl_op! {
 
 impl_op! {
     fn neq<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_NEQ_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::neq(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_NEQ_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_NEQ_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::neq(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpneq_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::neq(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpneq_pd(a, b)
         }
@@ -517,12 +954,27 @@ impl_op! {
 
 impl_op! {
     fn lt<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_LT_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::lt(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_LT_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_LT_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::lt(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmplt_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::lt(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmplt_pd(a, b)
         }
@@ -544,12 +996,27 @@ impl_op! {
 
 impl_op! {
     fn lte<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_LE_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::lte(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_LE_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_LE_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::lte(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmple_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::lte(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmple_pd(a, b)
         }
@@ -571,12 +1038,27 @@ impl_op! {
 
 impl_op! {
     fn gt<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_GT_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::gt(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_GT_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_GT_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::gt(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpgt_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::gt(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpgt_pd(a, b)
         }
@@ -598,12 +1080,27 @@ impl_op! {
 
 impl_op! {
     fn gte<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(_mm512_movm_epi64(_mm512_cmp_pd_mask(a, b, _CMP_GE_OQ)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::gte(a, b)
+        }
         for Avx2(a: __m256d, b: __m256d) -> __m256d {
             _mm256_cmp_pd(a, b, _CMP_GE_OQ)
         }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_cmp_pd(a, b, _CMP_GE_OQ)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::gte(a, b)
+        }
         for Sse41(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpge_pd(a, b)
         }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::gte(a, b)
+        }
         for Sse2(a: __m128d, b: __m128d) -> __m128d {
             _mm_cmpge_pd(a, b)
         }
@@ -625,12 +1122,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<f64> {
+        for Avx512(a: __m512d, b: __m512d, mask: __m512d) -> __m512d {
+            _mm512_mask_blend_pd(_mm512_movepi64_mask(_mm512_castpd_si512(mask)), a, b)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d, mask: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256d, b: __m256d, mask: __m256d) -> __m256d {
             _mm256_blendv_pd(a, b, mask)
         }
+        for Avx(a: __m256d, b: __m256d, mask: __m256d) -> __m256d {
+            _mm256_blendv_pd(a, b, mask)
+        }
+        for Sse42(a: __m128d, b: __m128d, mask: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128d, b: __m128d, mask: __m128d) -> __m128d {
             _mm_blendv_pd(a, b, mask)
         }
+        for Ssse3(a: __m128d, b: __m128d, mask: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128d, b: __m128d, mask: __m128d) -> __m128d {
             _mm_or_pd(_mm_and_pd(mask, b), _mm_andnot_pd(mask, a))
         }
@@ -652,6 +1164,15 @@ impl_op! {
 
 impl_op! {
     fn horizontal_add<f64> {
+        for Avx512(a: __m512d) -> f64 {
+            // Sum each 256 bit half the same way Avx2 does, so the rounding matches between engines.
+            let low = _mm512_castpd512_pd256(a);
+            let high = _mm512_extractf64x4_pd(a, 1);
+            Ops::<Avx2, f64>::horizontal_add(low) + Ops::<Avx2, f64>::horizontal_add(high)
+        }
+        for Avx2Fma(a: __m256d) -> f64 {
+            Ops::<Avx2, f64>::horizontal_add(a)
+        }
         for Avx2(a: __m256d) -> f64 {
             let a = _mm256_hadd_pd(a, a);
             let b = _mm256_hadd_pd(a, a);
@@ -661,6 +1182,18 @@ impl_op! {
 
             first + second
         }
+        for Avx(a: __m256d) -> f64 {
+            let a = _mm256_hadd_pd(a, a);
+            let b = _mm256_hadd_pd(a, a);
+
+            let first = _mm_cvtsd_f64(_mm256_extractf128_pd(b, 0));
+            let second = _mm_cvtsd_f64(_mm256_extractf128_pd(b, 1));
+
+            first + second
+        }
+        for Sse42(a: __m128d) -> f64 {
+            Ops::<Sse41, f64>::horizontal_add(a)
+        }
         for Sse41(a: __m128d) -> f64 {
             let a = _mm_hadd_pd(a, a);
 
@@ -669,6 +1202,9 @@ impl_op! {
 
             first + second
         }
+        for Ssse3(a: __m128d) -> f64 {
+            Ops::<Sse2, f64>::horizontal_add(a)
+        }
         for Sse2(a: __m128d) -> f64 {
             let a = _mm_add_pd(a, _mm_shuffle_pd(a, a, 1));
 
@@ -694,6 +1230,14 @@ impl_op! {
 
 impl_op! {
     fn cast_i64<f64> {
+        for Avx512(a: __m512d) -> __m512i {
+            let nums_arr = core::mem::transmute::<__m512d, [f64; 8]>(a);
+            let rounded = nums_arr.map(|num| num.m_round() as i64);
+            core::mem::transmute::<_, __m512i>(rounded)
+        }
+        for Avx2Fma(a: __m256d) -> __m256i {
+            Ops::<Avx2, f64>::cast_i64(a)
+        }
         for Avx2(a: __m256d) -> __m256i {
             let nums_arr = core::mem::transmute::<__m256d, [f64; 4]>(a);
             let ceil = [
@@ -704,6 +1248,19 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m256i>(ceil)
         }
+        for Avx(a: __m256d) -> __m256i {
+            let nums_arr = core::mem::transmute::<__m256d, [f64; 4]>(a);
+            let ceil = [
+                nums_arr[0].m_round() as i64,
+                nums_arr[1].m_round() as i64,
+                nums_arr[2].m_round() as i64,
+                nums_arr[3].m_round() as i64,
+            ];
+            core::mem::transmute::<_, __m256i>(ceil)
+        }
+        for Sse42(a: __m128d) -> __m128i {
+            Ops::<Sse41, f64>::cast_i64(a)
+        }
         for Sse41(a: __m128d) -> __m128i {
             let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
             let ceil = [
@@ -712,6 +1269,9 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m128i>(ceil)
         }
+        for Ssse3(a: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::cast_i64(a)
+        }
         for Sse2(a: __m128d) -> __m128i {
             let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
             let ceil = [
@@ -744,12 +1304,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_i64<f64> {
+        for Avx512(a: __m512d) -> __m512i {
+            _mm512_castpd_si512(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256i {
+            Ops::<Avx2, f64>::bitcast_i64(a)
+        }
         for Avx2(a: __m256d) -> __m256i {
             _mm256_castpd_si256(a)
         }
+        for Avx(a: __m256d) -> __m256i {
+            _mm256_castpd_si256(a)
+        }
+        for Sse42(a: __m128d) -> __m128i {
+            Ops::<Sse41, f64>::bitcast_i64(a)
+        }
         for Sse41(a: __m128d) -> __m128i {
             _mm_castpd_si128(a)
         }
+        for Ssse3(a: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::bitcast_i64(a)
+        }
         for Sse2(a: __m128d) -> __m128i {
             _mm_castpd_si128(a)
         }
@@ -767,12 +1342,27 @@ impl_op! {
 
 impl_op! {
     fn zeroes<f64> {
+        for Avx512() -> __m512d {
+            _mm512_setzero_pd()
+        }
+        for Avx2Fma() -> __m256d {
+            Ops::<Avx2, f64>::zeroes()
+        }
         for Avx2() -> __m256d {
             _mm256_setzero_pd()
         }
+        for Avx() -> __m256d {
+            _mm256_setzero_pd()
+        }
+        for Sse42() -> __m128d {
+            Ops::<Sse41, f64>::zeroes()
+        }
         for Sse41() -> __m128d {
             _mm_setzero_pd()
         }
+        for Ssse3() -> __m128d {
+            Ops::<Sse2, f64>::zeroes()
+        }
         for Sse2() -> __m128d {
             _mm_setzero_pd()
         }
@@ -790,12 +1380,27 @@ impl_op! {
 
 impl_op! {
     fn set1<f64> {
+        for Avx512(val: f64) -> __m512d {
+            _mm512_set1_pd(val)
+        }
+        for Avx2Fma(val: f64) -> __m256d {
+            Ops::<Avx2, f64>::set1(val)
+        }
         for Avx2(val: f64) -> __m256d {
             _mm256_set1_pd(val)
         }
+        for Avx(val: f64) -> __m256d {
+            _mm256_set1_pd(val)
+        }
+        for Sse42(val: f64) -> __m128d {
+            Ops::<Sse41, f64>::set1(val)
+        }
         for Sse41(val: f64) -> __m128d {
             _mm_set1_pd(val)
         }
+        for Ssse3(val: f64) -> __m128d {
+            Ops::<Sse2, f64>::set1(val)
+        }
         for Sse2(val: f64) -> __m128d {
             _mm_set1_pd(val)
         }
@@ -813,12 +1418,27 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<f64> {
+        for Avx512(ptr: *const f64) -> __m512d {
+            _mm512_loadu_pd(ptr)
+        }
+        for Avx2Fma(ptr: *const f64) -> __m256d {
+            Ops::<Avx2, f64>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const f64) -> __m256d {
             _mm256_loadu_pd(ptr)
         }
+        for Avx(ptr: *const f64) -> __m256d {
+            _mm256_loadu_pd(ptr)
+        }
+        for Sse42(ptr: *const f64) -> __m128d {
+            Ops::<Sse41, f64>::load_unaligned(ptr)
+        }
         for Sse41(ptr: *const f64) -> __m128d {
             _mm_loadu_pd(ptr)
         }
+        for Ssse3(ptr: *const f64) -> __m128d {
+            Ops::<Sse2, f64>::load_unaligned(ptr)
+        }
         for Sse2(ptr: *const f64) -> __m128d {
             _mm_loadu_pd(ptr)
         }
@@ -836,12 +1456,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<f64> {
+        for Avx512(ptr: *const f64) -> __m512d {
+            _mm512_load_pd(ptr)
+        }
+        for Avx2Fma(ptr: *const f64) -> __m256d {
+            Ops::<Avx2, f64>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const f64) -> __m256d {
             _mm256_load_pd(ptr)
         }
+        for Avx(ptr: *const f64) -> __m256d {
+            _mm256_load_pd(ptr)
+        }
+        for Sse42(ptr: *const f64) -> __m128d {
+            Ops::<Sse41, f64>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const f64) -> __m128d {
             _mm_load_pd(ptr)
         }
+        for Ssse3(ptr: *const f64) -> __m128d {
+            Ops::<Sse2, f64>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const f64) -> __m128d {
             _mm_load_pd(ptr)
         }
@@ -859,12 +1494,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<f64> {
+        for Avx512(ptr: *mut f64, a: __m512d) {
+            _mm512_storeu_pd(ptr, a)
+        }
+        for Avx2Fma(ptr: *mut f64, a: __m256d) {
+            Ops::<Avx2, f64>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut f64, a: __m256d) {
             _mm256_storeu_pd(ptr, a)
         }
+        for Avx(ptr: *mut f64, a: __m256d) {
+            _mm256_storeu_pd(ptr, a)
+        }
+        for Sse42(ptr: *mut f64, a: __m128d) {
+            Ops::<Sse41, f64>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut f64, a: __m128d) {
             _mm_storeu_pd(ptr, a)
         }
+        for Ssse3(ptr: *mut f64, a: __m128d) {
+            Ops::<Sse2, f64>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut f64, a: __m128d) {
             _mm_storeu_pd(ptr, a)
         }
@@ -882,12 +1532,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<f64> {
+        for Avx512(ptr: *mut f64, a: __m512d) {
+            _mm512_store_pd(ptr, a)
+        }
+        for Avx2Fma(ptr: *mut f64, a: __m256d) {
+            Ops::<Avx2, f64>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut f64, a: __m256d) {
             _mm256_store_pd(ptr, a)
         }
+        for Avx(ptr: *mut f64, a: __m256d) {
+            _mm256_store_pd(ptr, a)
+        }
+        for Sse42(ptr: *mut f64, a: __m128d) {
+            Ops::<Sse41, f64>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut f64, a: __m128d) {
             _mm_store_pd(ptr, a)
         }
+        for Ssse3(ptr: *mut f64, a: __m128d) {
+            Ops::<Sse2, f64>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut f64, a: __m128d) {
             _mm_store_pd(ptr, a)
         }
@@ -902,3 +1567,606 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn gather_unchecked<f64> {
+        for Avx512(base: *const f64, indices: __m512i) -> __m512d {
+            _mm512_i64gather_pd::<8>(indices, base)
+        }
+        for Avx2Fma(base: *const f64, indices: __m256i) -> __m256d {
+            Ops::<Avx2, f64>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const f64, indices: __m256i) -> __m256d {
+            _mm256_i64gather_pd(base, indices, 8)
+        }
+        for Avx(base: *const f64, indices: __m256i) -> __m256d {
+            let indices = core::mem::transmute::<__m256i, [i64; 4]>(indices);
+            core::mem::transmute::<[f64; 4], __m256d>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Sse42(base: *const f64, indices: __m128i) -> __m128d {
+            Ops::<Sse41, f64>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const f64, indices: __m128i) -> __m128d {
+            Ops::<Sse2, f64>::gather_unchecked(base, indices)
+        }
+        for Ssse3(base: *const f64, indices: __m128i) -> __m128d {
+            Ops::<Sse2, f64>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const f64, indices: __m128i) -> __m128d {
+            let indices = core::mem::transmute::<__m128i, [i64; 2]>(indices);
+            core::mem::transmute::<[f64; 2], __m128d>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Scalar(base: *const f64, indices: i64) -> f64 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const f64, indices: int64x2_t) -> float64x2_t {
+            let indices = core::mem::transmute::<int64x2_t, [i64; 2]>(indices);
+            core::mem::transmute::<[f64; 2], float64x2_t>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Wasm(base: *const f64, indices: v128) -> v128 {
+            let indices = core::mem::transmute::<v128, [i64; 2]>(indices);
+            core::mem::transmute::<[f64; 2], v128>(indices.map(|i| *base.add(i as usize)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<f64> {
+        for Avx512(base: *mut f64, indices: __m512i, val: __m512d) {
+            _mm512_i64scatter_pd::<8>(base, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<f64> {
+        for Avx512(base: *mut f64, indices: __m512i, val: __m512d, mask: __mmask8) {
+            _mm512_mask_i64scatter_pd::<8>(base, mask, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<f64> {
+        for Avx512(ptr: *const f64, mask: __mmask8) -> __m512d {
+            _mm512_maskz_loadu_pd(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const f64, mask: __m256i) -> __m256d {
+            Ops::<Avx2, f64>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const f64, mask: __m256i) -> __m256d {
+            _mm256_maskload_pd(ptr, mask)
+        }
+        for Avx(ptr: *const f64, mask: __m256i) -> __m256d {
+            _mm256_maskload_pd(ptr, mask)
+        }
+        for Sse42(ptr: *const f64, mask: __m128i) -> __m128d {
+            Ops::<Sse41, f64>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const f64, mask: __m128i) -> __m128d {
+            Ops::<Sse2, f64>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const f64, mask: __m128i) -> __m128d {
+            Ops::<Sse2, f64>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const f64, mask: __m128i) -> __m128d {
+            load_masked_emulated!(ptr, Ops::<Sse2, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+        for Scalar(ptr: *const f64, mask: i64) -> f64 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0.0
+            }
+        }
+        for Neon(ptr: *const f64, mask: int64x2_t) -> float64x2_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+        for Wasm(ptr: *const f64, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<f64> {
+        for Avx512(ptr: *mut f64, a: __m512d, mask: __mmask8) {
+            _mm512_mask_storeu_pd(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut f64, a: __m256d, mask: __m256i) {
+            Ops::<Avx2, f64>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut f64, a: __m256d, mask: __m256i) {
+            _mm256_maskstore_pd(ptr, mask, a)
+        }
+        for Avx(ptr: *mut f64, a: __m256d, mask: __m256i) {
+            _mm256_maskstore_pd(ptr, mask, a)
+        }
+        for Sse42(ptr: *mut f64, a: __m128d, mask: __m128i) {
+            Ops::<Sse41, f64>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut f64, a: __m128d, mask: __m128i) {
+            Ops::<Sse2, f64>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut f64, a: __m128d, mask: __m128i) {
+            Ops::<Sse2, f64>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut f64, a: __m128d, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+        for Scalar(ptr: *mut f64, a: f64, mask: i64) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut f64, a: float64x2_t, mask: int64x2_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+        for Wasm(ptr: *mut f64, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i64>::mask_to_bitmask(mask), [f64; 2])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<f64> {
+        for Avx512(a: __m512d, indices: __m512i) -> __m512d {
+            _mm512_permutexvar_pd(indices, a)
+        }
+        for Avx2Fma(a: __m256d, indices: __m256i) -> __m256d {
+            Ops::<Avx2, f64>::permute(a, indices)
+        }
+        for Avx2(a: __m256d, indices: __m256i) -> __m256d {
+            let a = _mm256_castpd_si256(a);
+            _mm256_castsi256_pd(Ops::<Avx2, i64>::permute(a, indices))
+        }
+        for Avx(a: __m256d, indices: __m256i) -> __m256d {
+            let a = _mm256_castpd_si256(a);
+            _mm256_castsi256_pd(Ops::<Avx, i64>::permute(a, indices))
+        }
+        for Sse42(a: __m128d, indices: __m128i) -> __m128d {
+            Ops::<Sse41, f64>::permute(a, indices)
+        }
+        for Sse41(a: __m128d, indices: __m128i) -> __m128d {
+            let a = _mm_castpd_si128(a);
+            _mm_castsi128_pd(Ops::<Sse41, i64>::permute(a, indices))
+        }
+        for Ssse3(a: __m128d, indices: __m128i) -> __m128d {
+            let a = _mm_castpd_si128(a);
+            _mm_castsi128_pd(Ops::<Ssse3, i64>::permute(a, indices))
+        }
+        for Sse2(a: __m128d, indices: __m128i) -> __m128d {
+            let a = _mm_castpd_si128(a);
+            _mm_castsi128_pd(Ops::<Sse2, i64>::permute(a, indices))
+        }
+        for Scalar(a: f64, indices: i64) -> f64 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: float64x2_t, indices: int64x2_t) -> float64x2_t {
+            let a = vreinterpretq_s64_f64(a);
+            vreinterpretq_f64_s64(Ops::<Neon, i64>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i64>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(Ops::<Avx512, i64>::zip_lo(_mm512_castpd_si512(a), _mm512_castpd_si512(b)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_castsi256_pd(Ops::<Avx2, i64>::zip_lo(_mm256_castpd_si256(a), _mm256_castpd_si256(b)))
+        }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_castsi256_pd(Ops::<Avx, i64>::zip_lo(_mm256_castpd_si256(a), _mm256_castpd_si256(b)))
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128d {
+            _mm_castsi128_pd(Ops::<Sse2, i64>::zip_lo(_mm_castpd_si128(a), _mm_castpd_si128(b)))
+        }
+        for Scalar(a: f64, b: f64) -> f64 {
+            let _ = b;
+            a
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> float64x2_t {
+            vreinterpretq_f64_s64(Ops::<Neon, i64>::zip_lo(vreinterpretq_s64_f64(a), vreinterpretq_s64_f64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512d {
+            _mm512_castsi512_pd(Ops::<Avx512, i64>::zip_hi(_mm512_castpd_si512(a), _mm512_castpd_si512(b)))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_castsi256_pd(Ops::<Avx2, i64>::zip_hi(_mm256_castpd_si256(a), _mm256_castpd_si256(b)))
+        }
+        for Avx(a: __m256d, b: __m256d) -> __m256d {
+            _mm256_castsi256_pd(Ops::<Avx, i64>::zip_hi(_mm256_castpd_si256(a), _mm256_castpd_si256(b)))
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128d {
+            _mm_castsi128_pd(Ops::<Sse2, i64>::zip_hi(_mm_castpd_si128(a), _mm_castpd_si128(b)))
+        }
+        for Scalar(a: f64, b: f64) -> f64 {
+            let _ = a;
+            b
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> float64x2_t {
+            vreinterpretq_f64_s64(Ops::<Neon, i64>::zip_hi(vreinterpretq_s64_f64(a), vreinterpretq_s64_f64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> (__m512d, __m512d) {
+            let (x, y) = Ops::<Avx512, i64>::unzip(_mm512_castpd_si512(a), _mm512_castpd_si512(b));
+            (_mm512_castsi512_pd(x), _mm512_castsi512_pd(y))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+            Ops::<Avx2, f64>::unzip(a, b)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+            let (x, y) = Ops::<Avx2, i64>::unzip(_mm256_castpd_si256(a), _mm256_castpd_si256(b));
+            (_mm256_castsi256_pd(x), _mm256_castsi256_pd(y))
+        }
+        for Avx(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+            let (x, y) = Ops::<Avx, i64>::unzip(_mm256_castpd_si256(a), _mm256_castpd_si256(b));
+            (_mm256_castsi256_pd(x), _mm256_castsi256_pd(y))
+        }
+        for Sse42(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+            Ops::<Sse41, f64>::unzip(a, b)
+        }
+        for Sse41(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+            Ops::<Sse2, f64>::unzip(a, b)
+        }
+        for Ssse3(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+            Ops::<Sse2, f64>::unzip(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+            let (x, y) = Ops::<Sse2, i64>::unzip(_mm_castpd_si128(a), _mm_castpd_si128(b));
+            (_mm_castsi128_pd(x), _mm_castsi128_pd(y))
+        }
+        for Scalar(a: f64, b: f64) -> (f64, f64) {
+            (a, b)
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> (float64x2_t, float64x2_t) {
+            let (x, y) = Ops::<Neon, i64>::unzip(vreinterpretq_s64_f64(a), vreinterpretq_s64_f64(b));
+            (vreinterpretq_f64_s64(x), vreinterpretq_f64_s64(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i64>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t) {
+            let loaded = vld2q_f64(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t) {
+            vst2q_f64(ptr, float64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t, float64x2_t) {
+            let loaded = vld3q_f64(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t, c: float64x2_t) {
+            vst3q_f64(ptr, float64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<f64> {
+        for Neon(ptr: *const f64) -> (float64x2_t, float64x2_t, float64x2_t, float64x2_t) {
+            let loaded = vld4q_f64(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<f64> {
+        for Neon(ptr: *mut f64, a: float64x2_t, b: float64x2_t, c: float64x2_t, d: float64x2_t) {
+            vst4q_f64(ptr, float64x2x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            let bits = _mm512_castpd_si512(a);
+            let shifted = _mm512_castsi512_pd(_mm512_alignr_epi64::<7>(bits, _mm512_setzero_si512()));
+            let a = _mm512_add_pd(a, shifted);
+            let bits = _mm512_castpd_si512(a);
+            let shifted = _mm512_castsi512_pd(_mm512_alignr_epi64::<6>(bits, _mm512_setzero_si512()));
+            let a = _mm512_add_pd(a, shifted);
+            let bits = _mm512_castpd_si512(a);
+            let shifted = _mm512_castsi512_pd(_mm512_alignr_epi64::<4>(bits, _mm512_setzero_si512()));
+            _mm512_add_pd(a, shifted)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::prefix_sum(a)
+        }
+        for Avx2(a: __m256d) -> __m256d {
+            let bits = _mm256_castpd_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            let a = _mm256_add_pd(a, _mm256_castsi256_pd(_mm256_alignr_epi8::<8>(bits, carry)));
+            let bits = _mm256_castpd_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            _mm256_add_pd(a, _mm256_castsi256_pd(carry))
+        }
+        for Avx(a: __m256d) -> __m256d {
+            let lo = Ops::<Sse41, f64>::prefix_sum(_mm256_castpd256_pd128(a));
+            let hi = Ops::<Sse41, f64>::prefix_sum(_mm256_extractf128_pd::<1>(a));
+            let hi = _mm_add_pd(hi, _mm_unpackhi_pd(lo, lo));
+            _mm256_insertf128_pd::<1>(_mm256_castpd128_pd256(lo), hi)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::prefix_sum(a)
+        }
+        for Sse41(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::prefix_sum(a)
+        }
+        for Sse2(a: __m128d) -> __m128d {
+            _mm_add_pd(a, _mm_castsi128_pd(_mm_slli_si128::<8>(_mm_castpd_si128(a))))
+        }
+        for Scalar(a: f64) -> f64 {
+            a
+        }
+        for Neon(a: float64x2_t) -> float64x2_t {
+            vaddq_f64(a, vextq_f64::<1>(vdupq_n_f64(0.0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            f64x2_add(a, i64x2_shuffle::<2, 0>(a, i64x2_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<f64> {
+        for Avx512(a: __m512d) -> __m512d {
+            let bits = _mm512_castpd_si512(a);
+            let a = _mm512_castsi512_pd(_mm512_alignr_epi64::<7>(bits, _mm512_setzero_si512()));
+            Ops::<Avx512, f64>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256d) -> __m256d {
+            Ops::<Avx2, f64>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256d) -> __m256d {
+            let bits = _mm256_castpd_si256(a);
+            let carry = _mm256_permute2x128_si256::<0x08>(bits, bits);
+            Ops::<Avx2, f64>::prefix_sum(_mm256_castsi256_pd(_mm256_alignr_epi8::<8>(bits, carry)))
+        }
+        for Avx(a: __m256d) -> __m256d {
+            let (lo, hi) = split_m256i!(_mm256_castpd_si256(a));
+            let a = _mm256_castsi256_pd(join_m128i!(_mm_slli_si128::<8>(lo), _mm_alignr_epi8::<8>(hi, lo)));
+            Ops::<Avx, f64>::prefix_sum(a)
+        }
+        for Sse42(a: __m128d) -> __m128d {
+            Ops::<Sse41, f64>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128d) -> __m128d {
+            Ops::<Sse2, f64>::prefix_sum(_mm_castsi128_pd(_mm_slli_si128::<8>(_mm_castpd_si128(a))))
+        }
+        for Scalar(a: f64) -> f64 {
+            let _ = a;
+            0.0
+        }
+        for Neon(a: float64x2_t) -> float64x2_t {
+            Ops::<Neon, f64>::prefix_sum(vextq_f64::<1>(vdupq_n_f64(0.0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, f64>::prefix_sum(i64x2_shuffle::<2, 0>(a, i64x2_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_f32<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512 {
+            let lo = _mm512_castps256_ps512(_mm512_cvtpd_ps(a));
+            _mm512_insertf32x8::<1>(lo, _mm512_cvtpd_ps(b))
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256 {
+            Ops::<Avx2, f64>::narrow_f32(a, b)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256 {
+            Ops::<Avx, f64>::narrow_f32(a, b)
+        }
+        for Avx(a: __m256d, b: __m256d) -> __m256 {
+            let lo = _mm256_castps128_ps256(_mm256_cvtpd_ps(a));
+            _mm256_insertf128_ps::<1>(lo, _mm256_cvtpd_ps(b))
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128 {
+            Ops::<Sse41, f64>::narrow_f32(a, b)
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128 {
+            Ops::<Sse2, f64>::narrow_f32(a, b)
+        }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128 {
+            Ops::<Sse2, f64>::narrow_f32(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128 {
+            _mm_movelh_ps(_mm_cvtpd_ps(a), _mm_cvtpd_ps(b))
+        }
+        for Scalar(a: f64, _b: f64) -> f32 {
+            a as f32
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> float32x4_t {
+            vcvt_high_f32_f64(vcvt_f32_f64(a), b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let lo = f32x4_demote_f64x2_zero(a);
+            let hi = f32x4_demote_f64x2_zero(b);
+            i32x4_shuffle::<0, 1, 4, 5>(lo, hi)
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_i32<f64> {
+        for Avx512(a: __m512d, b: __m512d) -> __m512i {
+            let min = _mm512_set1_pd(i32::MIN as f64);
+            let max = _mm512_set1_pd(i32::MAX as f64);
+            let a = _mm512_maskz_mov_pd(_mm512_cmp_pd_mask::<_CMP_ORD_Q>(a, a), a);
+            let b = _mm512_maskz_mov_pd(_mm512_cmp_pd_mask::<_CMP_ORD_Q>(b, b), b);
+            let lo = _mm512_cvtpd_epi32(_mm512_min_pd(_mm512_max_pd(a, min), max));
+            let hi = _mm512_cvtpd_epi32(_mm512_min_pd(_mm512_max_pd(b, min), max));
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(lo), hi)
+        }
+        for Avx2Fma(a: __m256d, b: __m256d) -> __m256i {
+            Ops::<Avx2, f64>::narrow_i32(a, b)
+        }
+        for Avx2(a: __m256d, b: __m256d) -> __m256i {
+            Ops::<Avx, f64>::narrow_i32(a, b)
+        }
+        for Avx(a: __m256d, b: __m256d) -> __m256i {
+            let min = _mm256_set1_pd(i32::MIN as f64);
+            let max = _mm256_set1_pd(i32::MAX as f64);
+            let a = _mm256_and_pd(a, _mm256_cmp_pd::<_CMP_ORD_Q>(a, a));
+            let b = _mm256_and_pd(b, _mm256_cmp_pd::<_CMP_ORD_Q>(b, b));
+            let lo = _mm256_cvtpd_epi32(_mm256_min_pd(_mm256_max_pd(a, min), max));
+            let hi = _mm256_cvtpd_epi32(_mm256_min_pd(_mm256_max_pd(b, min), max));
+            join_m128i!(lo, hi)
+        }
+        for Sse42(a: __m128d, b: __m128d) -> __m128i {
+            Ops::<Sse41, f64>::narrow_i32(a, b)
+        }
+        for Sse41(a: __m128d, b: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::narrow_i32(a, b)
+        }
+        for Ssse3(a: __m128d, b: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::narrow_i32(a, b)
+        }
+        for Sse2(a: __m128d, b: __m128d) -> __m128i {
+            // The conversion gives i32::MIN for NaN and for every number out of range, so zero the
+            // NaNs and clamp to the range of i32 first.
+            let min = _mm_set1_pd(i32::MIN as f64);
+            let max = _mm_set1_pd(i32::MAX as f64);
+            let a = _mm_and_pd(a, _mm_cmpord_pd(a, a));
+            let b = _mm_and_pd(b, _mm_cmpord_pd(b, b));
+            let lo = _mm_cvtpd_epi32(_mm_min_pd(_mm_max_pd(a, min), max));
+            let hi = _mm_cvtpd_epi32(_mm_min_pd(_mm_max_pd(b, min), max));
+            _mm_unpacklo_epi64(lo, hi)
+        }
+        for Scalar(a: f64, _b: f64) -> i32 {
+            a.m_round_ties_even() as i32
+        }
+        for Neon(a: float64x2_t, b: float64x2_t) -> int32x4_t {
+            vcombine_s32(vqmovn_s64(vcvtnq_s64_f64(a)), vqmovn_s64(vcvtnq_s64_f64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let lo = i32x4_trunc_sat_f64x2_zero(f64x2_nearest(a));
+            let hi = i32x4_trunc_sat_f64x2_zero(f64x2_nearest(b));
+            i64x2_shuffle::<0, 2>(lo, hi)
+        }
+    }
+}
+
+impl_op! {
+    fn trunc_i64<f64> {
+        for Avx512(a: __m512d) -> __m512i {
+            let truncated = _mm512_cvttpd_epi64(a);
+            let too_big = _mm512_cmp_pd_mask::<_CMP_GE_OQ>(a, _mm512_set1_pd(9223372036854775808.0));
+            let saturated = _mm512_mask_mov_epi64(truncated, too_big, _mm512_set1_epi64(i64::MAX));
+            _mm512_maskz_mov_epi64(_mm512_cmp_pd_mask::<_CMP_ORD_Q>(a, a), saturated)
+        }
+        for Avx2Fma(a: __m256d) -> __m256i {
+            Ops::<Avx2, f64>::trunc_i64(a)
+        }
+        for Avx2(a: __m256d) -> __m256i {
+            Ops::<Avx, f64>::trunc_i64(a)
+        }
+        for Avx(a: __m256d) -> __m256i {
+            let nums_arr = core::mem::transmute::<__m256d, [f64; 4]>(a);
+            core::mem::transmute::<_, __m256i>(nums_arr.map(|num| num as i64))
+        }
+        for Sse42(a: __m128d) -> __m128i {
+            Ops::<Sse41, f64>::trunc_i64(a)
+        }
+        for Sse41(a: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::trunc_i64(a)
+        }
+        for Ssse3(a: __m128d) -> __m128i {
+            Ops::<Sse2, f64>::trunc_i64(a)
+        }
+        for Sse2(a: __m128d) -> __m128i {
+            let nums_arr = core::mem::transmute::<__m128d, [f64; 2]>(a);
+            core::mem::transmute::<_, __m128i>(nums_arr.map(|num| num as i64))
+        }
+        for Scalar(a: f64) -> i64 {
+            a as i64
+        }
+        for Neon(a: float64x2_t) -> int64x2_t {
+            vcvtq_s64_f64(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let nums_arr = core::mem::transmute::<v128, [f64; 2]>(a);
+            core::mem::transmute::<_, v128>(nums_arr.map(|num| num as i64))
+        }
+    }
+}
@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_add_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::add(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_add_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::add(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::add(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi16(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::sub(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_sub_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::sub(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::sub(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi16(a, b)
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn mul<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mullo_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mul(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_mullo_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::mul, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mul(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_mullo_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mul(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_mullo_epi16(a, b)
         }
@@ -71,12 +116,27 @@ impl_op! {
 
 impl_op! {
     fn min<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::min(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_min_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::min(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_min_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::min(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_min_epi16(a, b)
         }
@@ -94,12 +154,27 @@ impl_op! {
 
 impl_op! {
     fn max<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::max(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_max_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::max(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_max_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::max(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_max_epi16(a, b)
         }
@@ -117,12 +192,27 @@ impl_op! {
 
 impl_op! {
     fn abs<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_abs_epi16(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::abs(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_abs_epi16(a)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::abs, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::abs(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_abs_epi16(a)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            _mm_abs_epi16(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi16(_mm_setzero_si128(), a);
             _mm_sub_epi16(_mm_xor_si128(a, mask), mask)
@@ -141,12 +231,27 @@ impl_op! {
 
 impl_op! {
     fn eq<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpeq_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::eq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpeq_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::eq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::eq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::eq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi16(a, b)
         }
@@ -168,14 +273,29 @@ impl_op! {
 
 impl_op! {
     fn neq<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpneq_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::neq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let eq = _mm256_cmpeq_epi16(a, b);
             _mm256_xor_si256(eq, _mm256_set1_epi16(u32::MAX as i16))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::neq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::neq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi16(a, b);
             _mm_xor_si128(eq, _mm_set1_epi16(u32::MAX as i16))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::neq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi16(a, b);
             _mm_xor_si128(eq, _mm_set1_epi16(u32::MAX as i16))
@@ -198,16 +318,31 @@ impl_op! {
 
 impl_op! {
     fn lt<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmplt_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::lt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi16(a, b);
             let eq = _mm256_cmpeq_epi16(a, b);
             _mm256_andnot_si256(_mm256_or_si256(gt, eq), _mm256_set1_epi16(u32::MAX as i16))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::lt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             let eq = _mm_cmpeq_epi16(a, b);
             _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi16(u32::MAX as i16))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::lt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             let eq = _mm_cmpeq_epi16(a, b);
@@ -231,14 +366,29 @@ impl_op! {
 
 impl_op! {
     fn lte<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmple_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::lte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi16(a, b);
             _mm256_xor_si256(gt, _mm256_set1_epi16(u32::MAX as i16))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::lte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             _mm_xor_si128(gt, _mm_set1_epi16(u32::MAX as i16))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::lte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             _mm_xor_si128(gt, _mm_set1_epi16(u32::MAX as i16))
@@ -261,12 +411,27 @@ impl_op! {
 
 impl_op! {
     fn gt<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpgt_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::gt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpgt_epi16(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::gt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi16(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::gt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi16(a, b)
         }
@@ -288,16 +453,31 @@ impl_op! {
 
 impl_op! {
     fn gte<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpge_epi16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::gte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi16(a, b);
             let eq = _mm256_cmpeq_epi16(a, b);
             _mm256_or_si256(gt, eq)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::gte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             let eq = _mm_cmpeq_epi16(a, b);
             _mm_or_si128(gt, eq)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::gte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi16(a, b);
             let eq = _mm_cmpeq_epi16(a, b);
@@ -321,12 +501,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<i16> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            _mm512_mask_blend_epi16(_mm512_movepi16_mask(mask), a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
             _mm256_blendv_epi8(a, b, mask)
         }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::blendv, a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_blendv_epi8(a, b, mask)
         }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
@@ -348,12 +543,28 @@ impl_op! {
 
 impl_op! {
     fn shl<i16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sll_epi16(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shl(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_sll_epi16(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i16>::shl(a_lo, rhs), Ops::<Sse41, i16>::shl(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shl(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             _mm_sll_epi16(a, _mm_cvtsi32_si128(rhs))
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shl(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             _mm_sll_epi16(a, _mm_cvtsi32_si128(rhs))
         }
@@ -372,12 +583,28 @@ impl_op! {
 
 impl_op! {
     fn shr<i16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_srl_epi16(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shr(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_srl_epi16(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i16>::shr(a_lo, rhs), Ops::<Sse41, i16>::shr(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shr(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi16(a, _mm_cvtsi32_si128(rhs))
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shr(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi16(a, _mm_cvtsi32_si128(rhs))
         }
@@ -396,12 +623,27 @@ impl_op! {
 
 impl_imm8_op! {
     fn shl_const<i16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shl(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_slli_epi16(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::shl_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_slli_epi16(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_slli_epi16(a, BY)
         }
@@ -419,12 +661,27 @@ impl_imm8_op! {
 
 impl_imm8_op! {
     fn shr_const<i16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shr(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_srli_epi16(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::shr_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_srli_epi16(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_srli_epi16(a, BY)
         }
@@ -440,18 +697,275 @@ impl_imm8_op! {
     }
 }
 
+impl_op! {
+    fn shl_var<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sllv_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Shift the even and odd 16 bit lanes separately inside 32 bit lanes.
+            let low_halves = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_sllv_epi32(a, _mm256_and_si256(b, low_halves));
+            let odd = _mm256_sllv_epi32(_mm256_andnot_si256(low_halves, a), _mm256_srli_epi32::<16>(b));
+            _mm256_blend_epi16::<0b1010_1010>(even, odd)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::shl_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            shift_by_count_bits!(Sse2, i16, shl_const, a, b, (1, 15), (2, 14), (4, 13), (8, 12))
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vshlq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            shift_by_count_bits!(Wasm, i16, shl_const, a, b, (1, 15), (2, 14), (4, 13), (8, 12))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srav_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Shift the even and odd 16 bit lanes separately inside 32 bit lanes.
+            let low_halves = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_srav_epi32(
+                _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(a)),
+                _mm256_and_si256(b, low_halves),
+            );
+            let odd = _mm256_srav_epi32(a, _mm256_srli_epi32::<16>(b));
+            _mm256_blend_epi16::<0b1010_1010>(even, odd)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::shr_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = _mm_srai_epi16::<15>(a);
+            _mm_xor_si128(Self::shr_logical(_mm_xor_si128(a, sign), b), sign)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vshlq_s16(a, vnegq_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = i16x8_shr(a, 15);
+            v128_xor(Self::shr_logical(v128_xor(a, sign), b), sign)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srlv_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Shift the even and odd 16 bit lanes separately inside 32 bit lanes.
+            let low_halves = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_srlv_epi32(_mm256_and_si256(a, low_halves), _mm256_and_si256(b, low_halves));
+            let odd = _mm256_srlv_epi32(a, _mm256_srli_epi32::<16>(b));
+            _mm256_blend_epi16::<0b1010_1010>(even, odd)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::shr_logical, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            shift_by_count_bits!(Sse2, i16, shr_const, a, b, (1, 15), (2, 14), (4, 13), (8, 12))
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            (a as u16).wrapping_shr(b as u32) as i16
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vreinterpretq_s16_u16(vshlq_u16(vreinterpretq_u16_s16(a), vnegq_s16(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            shift_by_count_bits!(Wasm, i16, shr_const, a, b, (1, 15), (2, 14), (4, 13), (8, 12))
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Count the ones in each byte, then add the low byte of each lane to the high one.
+            let bytes = Ops::<Avx512, i8>::count_ones(a);
+            _mm512_srli_epi16::<8>(_mm512_add_epi8(bytes, _mm512_slli_epi16::<8>(bytes)))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let bytes = Ops::<Avx2, i8>::count_ones(a);
+            _mm256_srli_epi16::<8>(_mm256_add_epi8(bytes, _mm256_slli_epi16::<8>(bytes)))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::count_ones, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i16>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let bytes = Ops::<Ssse3, i8>::count_ones(a);
+            _mm_srli_epi16::<8>(_mm_add_epi8(bytes, _mm_slli_epi16::<8>(bytes)))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let bytes = Ops::<Sse2, i8>::count_ones(a);
+            _mm_srli_epi16::<8>(_mm_add_epi8(bytes, _mm_slli_epi16::<8>(bytes)))
+        }
+        for Scalar(a: i16) -> i16 {
+            a.count_ones() as i16
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            vreinterpretq_s16_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_s16(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            i16x8_extadd_pairwise_u8x16(i8x16_popcnt(a))
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Smear the highest set bit into the bits below it, then count the bits left above it.
+            let a = _mm512_or_si512(a, _mm512_srli_epi16::<1>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16::<2>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16::<4>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi16::<8>(a));
+            Self::count_ones(_mm512_xor_si512(a, _mm512_set1_epi8(-1)))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi16::<1>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16::<2>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16::<4>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi16::<8>(a));
+            Self::count_ones(_mm256_xor_si256(a, _mm256_set1_epi8(-1)))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::leading_zeros, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i16>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi16::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<8>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi16::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi16::<8>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Scalar(a: i16) -> i16 {
+            a.leading_zeros() as i16
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            vclzq_s16(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u16x8_shr(a, 1));
+            let a = v128_or(a, u16x8_shr(a, 2));
+            let a = v128_or(a, u16x8_shr(a, 4));
+            let a = v128_or(a, u16x8_shr(a, 8));
+            Self::count_ones(v128_not(a))
+        }
+    }
+}
+
 impl_op! {
     fn extend_i32<i16> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepi16_epi32(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepi16_epi32(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i16>::extend_i32(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepi16_epi32(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepi16_epi32(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i16>::extend_i32(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i16>::extend_i32(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i16>::extend_i32(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             let a = _mm_cvtepi16_epi32(val);
             let b = _mm_cvtepi16_epi32(_mm_shuffle_epi32(val, 0b_01_00_11_10));
             (a, b)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i16>::extend_i32(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i16; 8]>(val);
             let a = [
@@ -486,16 +1000,36 @@ impl_op! {
 
 impl_op! {
     fn unsigned_extend_i32<i16> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepu16_epi32(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepu16_epi32(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i16>::unsigned_extend_i32(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepu16_epi32(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepu16_epi32(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i16>::unsigned_extend_i32(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i16>::unsigned_extend_i32(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i16>::unsigned_extend_i32(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             let a = _mm_cvtepu16_epi32(val);
             let b = _mm_cvtepu16_epi32(_mm_shuffle_epi32(val, 0b_01_00_11_10));
             (a, b)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i16>::unsigned_extend_i32(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i16; 8]>(val);
             let a = [
@@ -530,12 +1064,27 @@ impl_op! {
 
 impl_op! {
     fn zeroes<i16> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, i16>::zeroes()
+        }
         for Avx2() -> __m256i {
             _mm256_setzero_si256()
         }
+        for Avx() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, i16>::zeroes()
+        }
         for Sse41() -> __m128i {
             _mm_setzero_si128()
         }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, i16>::zeroes()
+        }
         for Sse2() -> __m128i {
             _mm_setzero_si128()
         }
@@ -553,12 +1102,27 @@ impl_op! {
 
 impl_op! {
     fn set1<i16> {
+        for Avx512(val: i16) -> __m512i {
+            _mm512_set1_epi16(val)
+        }
+        for Avx2Fma(val: i16) -> __m256i {
+            Ops::<Avx2, i16>::set1(val)
+        }
         for Avx2(val: i16) -> __m256i {
             _mm256_set1_epi16(val)
         }
+        for Avx(val: i16) -> __m256i {
+            _mm256_set1_epi16(val)
+        }
+        for Sse42(val: i16) -> __m128i {
+            Ops::<Sse41, i16>::set1(val)
+        }
         for Sse41(val: i16) -> __m128i {
             _mm_set1_epi16(val)
         }
+        for Ssse3(val: i16) -> __m128i {
+            Ops::<Sse2, i16>::set1(val)
+        }
         for Sse2(val: i16) -> __m128i {
             _mm_set1_epi16(val)
         }
@@ -576,12 +1140,27 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<i16> {
+        for Avx512(ptr: *const i16) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i16) -> __m256i {
+            Ops::<Avx2, i16>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const i16) -> __m256i {
             _mm256_loadu_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i16) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i16) -> __m128i {
+            Ops::<Sse41, i16>::load_unaligned(ptr)
+        }
         for Sse41(ptr: *const i16) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i16) -> __m128i {
+            Ops::<Sse2, i16>::load_unaligned(ptr)
+        }
         for Sse2(ptr: *const i16) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
@@ -599,12 +1178,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<i16> {
+        for Avx512(ptr: *const i16) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i16) -> __m256i {
+            Ops::<Avx2, i16>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const i16) -> __m256i {
             _mm256_load_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i16) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i16) -> __m128i {
+            Ops::<Sse41, i16>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const i16) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i16) -> __m128i {
+            Ops::<Sse2, i16>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const i16) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
@@ -622,12 +1216,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<i16> {
+        for Avx512(ptr: *mut i16, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i16, a: __m256i) {
+            Ops::<Avx2, i16>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut i16, a: __m256i) {
             _mm256_storeu_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i16, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i16, a: __m128i) {
+            Ops::<Sse41, i16>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut i16, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i16, a: __m128i) {
+            Ops::<Sse2, i16>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut i16, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
@@ -645,12 +1254,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<i16> {
+        for Avx512(ptr: *mut i16, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i16, a: __m256i) {
+            Ops::<Avx2, i16>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut i16, a: __m256i) {
             _mm256_store_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i16, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i16, a: __m128i) {
+            Ops::<Sse41, i16>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut i16, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i16, a: __m128i) {
+            Ops::<Sse2, i16>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut i16, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
@@ -665,3 +1289,879 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn get_mask<i16> {
+        for Avx512(val: __m512i) -> u64 {
+            _mm512_movepi16_mask(val) as u64
+        }
+        for Avx2Fma(val: __m256i) -> u64 {
+            Ops::<Avx2, i16>::get_mask(val)
+        }
+        for Avx2(val: __m256i) -> u64 {
+            // Packing saturates, so the sign of every lane is kept in its byte.
+            let packed = _mm256_packs_epi16(val, _mm256_setzero_si256());
+            let ordered = _mm256_permute4x64_epi64(packed, 0b11_01_10_00);
+            (_mm256_movemask_epi8(ordered) as u32 & 0xFFFF) as u64
+        }
+        for Avx(val: __m256i) -> u64 {
+            let (lo, hi) = split_m256i!(val);
+            Ops::<Sse41, i16>::get_mask(lo) | (Ops::<Sse41, i16>::get_mask(hi) << 8)
+        }
+        for Sse42(val: __m128i) -> u64 {
+            Ops::<Sse41, i16>::get_mask(val)
+        }
+        for Sse41(val: __m128i) -> u64 {
+            Ops::<Sse2, i16>::get_mask(val)
+        }
+        for Ssse3(val: __m128i) -> u64 {
+            Ops::<Sse2, i16>::get_mask(val)
+        }
+        for Sse2(val: __m128i) -> u64 {
+            // Packing saturates, so the sign of every lane is kept in its byte.
+            _mm_movemask_epi8(_mm_packs_epi16(val, _mm_setzero_si128())) as u64
+        }
+        for Scalar(val: i16) -> u64 {
+            ((val as u16) >> 15) as u64
+        }
+        for Neon(val: int16x8_t) -> u64 {
+            // Move each sign bit to the bottom of its lane, then shift it into its bit position.
+            let signs = vshrq_n_u16(vreinterpretq_u16_s16(val), 15);
+            let shifts: [i16; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+            vaddvq_u16(vshlq_u16(signs, vld1q_s16(shifts.as_ptr()))) as u64
+        }
+        for Wasm(val: v128) -> u64 {
+            i16x8_bitmask(val) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<i16> {
+        for Avx512(ptr: *const i16, mask: __mmask32) -> __m512i {
+            _mm512_maskz_loadu_epi16(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const i16, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const i16, mask: __m256i) -> __m256i {
+            load_masked_emulated!(ptr, Ops::<Avx2, i16>::mask_to_bitmask(mask), [i16; 16])
+        }
+        for Avx(ptr: *const i16, mask: __m256i) -> __m256i {
+            load_masked_emulated!(ptr, Ops::<Avx, i16>::mask_to_bitmask(mask), [i16; 16])
+        }
+        for Sse42(ptr: *const i16, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const i16, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const i16, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const i16, mask: __m128i) -> __m128i {
+            load_masked_emulated!(ptr, Ops::<Sse2, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+        for Scalar(ptr: *const i16, mask: i16) -> i16 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const i16, mask: int16x8_t) -> int16x8_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+        for Wasm(ptr: *const i16, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<i16> {
+        for Avx512(ptr: *mut i16, a: __m512i, mask: __mmask32) {
+            _mm512_mask_storeu_epi16(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut i16, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i16>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut i16, a: __m256i, mask: __m256i) {
+            store_masked_emulated!(ptr, a, Ops::<Avx2, i16>::mask_to_bitmask(mask), [i16; 16])
+        }
+        for Avx(ptr: *mut i16, a: __m256i, mask: __m256i) {
+            store_masked_emulated!(ptr, a, Ops::<Avx, i16>::mask_to_bitmask(mask), [i16; 16])
+        }
+        for Sse42(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i16>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i16>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i16>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i16, a: __m128i, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+        for Scalar(ptr: *mut i16, a: i16, mask: i16) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut i16, a: int16x8_t, mask: int16x8_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+        for Wasm(ptr: *mut i16, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i16>::mask_to_bitmask(mask), [i16; 8])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i16> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            _mm512_permutexvar_epi16(indices, a)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            let lanes = _mm256_and_si256(indices, _mm256_set1_epi16(15));
+            let low = _mm256_slli_epi16::<1>(lanes);
+            let bytes = _mm256_or_si256(low, _mm256_slli_epi16::<8>(_mm256_add_epi16(low, _mm256_set1_epi16(1))));
+            Ops::<Avx2, i8>::permute(a, bytes)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i16; 16]>(indices);
+            let values = core::mem::transmute::<__m256i, [i16; 16]>(a);
+            core::mem::transmute::<[i16; 16], __m256i>(indices.map(|i| values[i as usize & 15]))
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi16(7));
+            let low = _mm_slli_epi16::<1>(lanes);
+            let bytes = _mm_or_si128(low, _mm_slli_epi16::<8>(_mm_add_epi16(low, _mm_set1_epi16(1))));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi16(7));
+            let low = _mm_slli_epi16::<1>(lanes);
+            let bytes = _mm_or_si128(low, _mm_slli_epi16::<8>(_mm_add_epi16(low, _mm_set1_epi16(1))));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i16; 8]>(indices);
+            let values = core::mem::transmute::<__m128i, [i16; 8]>(a);
+            core::mem::transmute::<[i16; 8], __m128i>(indices.map(|i| values[i as usize & 7]))
+        }
+        for Scalar(a: i16, indices: i16) -> i16 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int16x8_t, indices: int16x8_t) -> int16x8_t {
+            let lanes = vandq_u16(vreinterpretq_u16_s16(indices), vdupq_n_u16(7));
+            let low = vshlq_n_u16::<1>(lanes);
+            let bytes = vreinterpretq_u8_u16(vmlaq_n_u16(vdupq_n_u16(0x0100), low, 0x0101));
+            vreinterpretq_s16_s8(vqtbl1q_s8(vreinterpretq_s8_s16(a), bytes))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            let low = i16x8_shl(v128_and(indices, i16x8_splat(7)), 1);
+            let bytes = i16x8_add(i16x8_mul(low, i16x8_splat(0x0101)), i16x8_splat(0x0100));
+            i8x16_swizzle(a, bytes)
+        }
+    }
+}
+
+impl_op! {
+    fn shuffle<i16> {
+        for Avx512(a: __m512i, indices: [i16; 32]) -> __m512i {
+            Ops::<Avx512, i16>::permute(a, core::mem::transmute::<[i16; 32], __m512i>(indices))
+        }
+        for Avx2Fma(a: __m256i, indices: [i16; 16]) -> __m256i {
+            Ops::<Avx2, i16>::shuffle(a, indices)
+        }
+        for Avx2(a: __m256i, indices: [i16; 16]) -> __m256i {
+            Ops::<Avx2, i8>::shuffle(a, split_lane_indices!(indices, 2, i8))
+        }
+        for Avx(a: __m256i, indices: [i16; 16]) -> __m256i {
+            Ops::<Avx, i8>::shuffle(a, split_lane_indices!(indices, 2, i8))
+        }
+        for Sse42(a: __m128i, indices: [i16; 8]) -> __m128i {
+            Ops::<Sse41, i16>::shuffle(a, indices)
+        }
+        for Sse41(a: __m128i, indices: [i16; 8]) -> __m128i {
+            Ops::<Ssse3, i16>::shuffle(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: [i16; 8]) -> __m128i {
+            Ops::<Ssse3, i8>::shuffle(a, split_lane_indices!(indices, 2, i8))
+        }
+        for Sse2(a: __m128i, indices: [i16; 8]) -> __m128i {
+            // Sse2 only shuffles 16 bit lanes within each half, so this compiles to pshuflw and
+            // pshufhw, with pshufd or unpacks for lanes crossing the halves.
+            pick_lanes!(a: __m128i, indices, [i16; 8])
+        }
+        for Scalar(a: i16, indices: [i16; 1]) -> i16 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int16x8_t, indices: [i16; 8]) -> int16x8_t {
+            let indices = split_lane_indices!(indices, 2, i8);
+            let bytes = Ops::<Neon, i8>::shuffle(vreinterpretq_s8_s16(a), indices);
+            vreinterpretq_s16_s8(bytes)
+        }
+        for Wasm(a: v128, indices: [i16; 8]) -> v128 {
+            Ops::<Wasm, i8>::shuffle(a, split_lane_indices!(indices, 2, i8))
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i16; 32], __m512i>([0, 32, 1, 33, 2, 34, 3, 35, 4, 36, 5, 37, 6, 38, 7, 39, 8, 40, 9, 41, 10, 42, 11, 43, 12, 44, 13, 45, 14, 46, 15, 47]);
+            _mm512_permutex2var_epi16(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi16(a, b);
+            let hi = _mm256_unpackhi_epi16(a, b);
+            _mm256_permute2x128_si256::<0x20>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, _) = split_m256i!(a);
+            let (b_lo, _) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i16>::zip_lo(a_lo, b_lo),
+                Ops::<Sse41, i16>::zip_hi(a_lo, b_lo)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpacklo_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            let _ = b;
+            a
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vzip1q_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_shuffle::<0, 8, 1, 9, 2, 10, 3, 11>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i16; 32], __m512i>([16, 48, 17, 49, 18, 50, 19, 51, 20, 52, 21, 53, 22, 54, 23, 55, 24, 56, 25, 57, 26, 58, 27, 59, 28, 60, 29, 61, 30, 62, 31, 63]);
+            _mm512_permutex2var_epi16(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi16(a, b);
+            let hi = _mm256_unpackhi_epi16(a, b);
+            _mm256_permute2x128_si256::<0x31>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (_, a_hi) = split_m256i!(a);
+            let (_, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i16>::zip_lo(a_hi, b_hi),
+                Ops::<Sse41, i16>::zip_hi(a_hi, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpackhi_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            let _ = a;
+            b
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vzip2q_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_shuffle::<4, 12, 5, 13, 6, 14, 7, 15>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let even = core::mem::transmute::<[i16; 32], __m512i>([0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62]);
+            let odd = core::mem::transmute::<[i16; 32], __m512i>([1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35, 37, 39, 41, 43, 45, 47, 49, 51, 53, 55, 57, 59, 61, 63]);
+            (_mm512_permutex2var_epi16(a, even, b), _mm512_permutex2var_epi16(a, odd, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i16>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            // Unzip each 128 bit half, then gather the halves coming from the same input.
+            let low = _mm256_set1_epi32(0xFFFF);
+            let even = _mm256_packus_epi32(_mm256_and_si256(a, low), _mm256_and_si256(b, low));
+            let odd = _mm256_packus_epi32(_mm256_srli_epi32::<16>(a), _mm256_srli_epi32::<16>(b));
+            (_mm256_permute4x64_epi64::<0b11_01_10_00>(even), _mm256_permute4x64_epi64::<0b11_01_10_00>(odd))
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            let (a_even, a_odd) = Ops::<Sse41, i16>::unzip(a_lo, a_hi);
+            let (b_even, b_odd) = Ops::<Sse41, i16>::unzip(b_lo, b_hi);
+            (join_m128i!(a_even, b_even), join_m128i!(a_odd, b_odd))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i16>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let low = _mm_set1_epi32(0xFFFF);
+            let even = _mm_packus_epi32(_mm_and_si128(a, low), _mm_and_si128(b, low));
+            let odd = _mm_packus_epi32(_mm_srli_epi32::<16>(a), _mm_srli_epi32::<16>(b));
+            (even, odd)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i16>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            // Sign extend each half into the whole 32 bit lane, so that the saturating pack keeps it.
+            let a_even = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(a));
+            let b_even = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(b));
+            let even = _mm_packs_epi32(a_even, b_even);
+            let odd = _mm_packs_epi32(_mm_srai_epi32::<16>(a), _mm_srai_epi32::<16>(b));
+            (even, odd)
+        }
+        for Scalar(a: i16, b: i16) -> (i16, i16) {
+            (a, b)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> (int16x8_t, int16x8_t) {
+            (vuzp1q_s16(a, b), vuzp2q_s16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let even = i16x8_shuffle::<0, 2, 4, 6, 8, 10, 12, 14>(a, b);
+            let odd = i16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(a, b);
+            (even, odd)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t) {
+            let loaded = vld2q_s16(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t) {
+            vst2q_s16(ptr, int16x8x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t, int16x8_t) {
+            let loaded = vld3q_s16(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t, c: int16x8_t) {
+            vst3q_s16(ptr, int16x8x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<i16> {
+        for Neon(ptr: *const i16) -> (int16x8_t, int16x8_t, int16x8_t, int16x8_t) {
+            let loaded = vld4q_s16(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i16> {
+        for Neon(ptr: *mut i16, a: int16x8_t, b: int16x8_t, c: int16x8_t, d: int16x8_t) {
+            vst4q_s16(ptr, int16x8x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            let carry = _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512());
+            let a = _mm512_add_epi16(a, _mm512_alignr_epi8::<14>(a, carry));
+            let a = _mm512_add_epi16(a, _mm512_alignr_epi32::<15>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi16(a, _mm512_alignr_epi32::<14>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi16(a, _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512()));
+            _mm512_add_epi16(a, _mm512_alignr_epi32::<8>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi16(a, _mm256_alignr_epi8::<14>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi16(a, _mm256_alignr_epi8::<12>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi16(a, _mm256_alignr_epi8::<8>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            _mm256_add_epi16(a, carry)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let lo = Ops::<Sse41, i16>::prefix_sum(lo);
+            let hi = Ops::<Sse41, i16>::prefix_sum(hi);
+            // Add the total of the lower half, held in its last lane, to every lane of the upper half.
+            join_m128i!(lo, _mm_add_epi16(hi, _mm_shuffle_epi32::<0xFF>(_mm_shufflehi_epi16::<0xFF>(lo))))
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_add_epi16(a, _mm_slli_si128::<2>(a));
+            let a = _mm_add_epi16(a, _mm_slli_si128::<4>(a));
+            _mm_add_epi16(a, _mm_slli_si128::<8>(a))
+        }
+        for Scalar(a: i16) -> i16 {
+            a
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            let a = vaddq_s16(a, vextq_s16::<7>(vdupq_n_s16(0), a));
+            let a = vaddq_s16(a, vextq_s16::<6>(vdupq_n_s16(0), a));
+            vaddq_s16(a, vextq_s16::<4>(vdupq_n_s16(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = i16x8_add(a, i16x8_shuffle::<8, 0, 1, 2, 3, 4, 5, 6>(a, i16x8_splat(0)));
+            let a = i16x8_add(a, i16x8_shuffle::<8, 8, 0, 1, 2, 3, 4, 5>(a, i16x8_splat(0)));
+            i16x8_add(a, i16x8_shuffle::<8, 8, 8, 8, 0, 1, 2, 3>(a, i16x8_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<i16> {
+        for Avx512(a: __m512i) -> __m512i {
+            let carry = _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512());
+            Ops::<Avx512, i16>::prefix_sum(_mm512_alignr_epi8::<14>(a, carry))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            Ops::<Avx2, i16>::prefix_sum(_mm256_alignr_epi8::<14>(a, carry))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let a = join_m128i!(_mm_slli_si128::<2>(lo), _mm_alignr_epi8::<14>(hi, lo));
+            Ops::<Avx, i16>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::prefix_sum(_mm_slli_si128::<2>(a))
+        }
+        for Scalar(a: i16) -> i16 {
+            let _ = a;
+            0
+        }
+        for Neon(a: int16x8_t) -> int16x8_t {
+            Ops::<Neon, i16>::prefix_sum(vextq_s16::<7>(vdupq_n_s16(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::prefix_sum(i16x8_shuffle::<8, 0, 1, 2, 3, 4, 5, 6>(a, i16x8_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::saturating_add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epi16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::saturating_add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::saturating_add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::saturating_add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vqaddq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::saturating_sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epi16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::saturating_sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::saturating_sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::saturating_sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vqsubq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_sub_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul_hi<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mulhi_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mul_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_mulhi_epi16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::mul_hi, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mul_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mul_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhi_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            ((a as i32 * b as i32) >> 16) as i16
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            let lo = vreinterpretq_s16_s32(vmull_s16(vget_low_s16(a), vget_low_s16(b)));
+            let hi = vreinterpretq_s16_s32(vmull_high_s16(a, b));
+            vuzp2q_s16(lo, hi)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let lo = i32x4_extmul_low_i16x8(a, b);
+            let hi = i32x4_extmul_high_i16x8(a, b);
+            i16x8_shuffle::<1, 3, 5, 7, 9, 11, 13, 15>(lo, hi)
+        }
+    }
+}
+
+impl_op! {
+    fn mulhrs<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mulhrs_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mulhrs(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_mulhrs_epi16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::mulhrs, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mulhrs(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhrs_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            _mm_mulhrs_epi16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Build the full 32 bit products, round them, and keep the low 16 bits of the result like
+            // `_mm_mulhrs_epi16` does.
+            let lo = _mm_mullo_epi16(a, b);
+            let hi = _mm_mulhi_epi16(a, b);
+            let round = _mm_set1_epi32(0x4000);
+            let first = _mm_srai_epi32::<15>(_mm_add_epi32(_mm_unpacklo_epi16(lo, hi), round));
+            let second = _mm_srai_epi32::<15>(_mm_add_epi32(_mm_unpackhi_epi16(lo, hi), round));
+            let first = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(first));
+            let second = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(second));
+            _mm_packs_epi32(first, second)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            ((a as i32 * b as i32 + 0x4000) >> 15) as i16
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            // The doubling multiply saturates `i16::MIN * i16::MIN`, which wraps on the other engines.
+            let min = vdupq_n_s16(i16::MIN);
+            let both_min = vandq_u16(vceqq_s16(a, min), vceqq_s16(b, min));
+            vbslq_s16(both_min, min, vqrdmulhq_s16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // The saturating multiply clamps `i16::MIN * i16::MIN`, which wraps on the other engines.
+            let min = i16x8_splat(i16::MIN);
+            let both_min = v128_and(i16x8_eq(a, min), i16x8_eq(b, min));
+            v128_bitselect(min, i16x8_q15mulr_sat(a, b), both_min)
+        }
+    }
+}
+
+impl_op! {
+    fn madd<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_madd_epi16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::madd(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_madd_epi16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i16>::madd, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::madd(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_madd_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::madd(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_madd_epi16(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i32 {
+            a as i32 * b as i32
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int32x4_t {
+            let lo = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+            let hi = vmull_high_s16(a, b);
+            vpaddq_s32(lo, hi)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_dot_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_saturating_i8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Packing works on each 128 bit quarter, so gather the quarters coming from the same input.
+            let packed = _mm512_packs_epi16(a, b);
+            _mm512_permutexvar_epi64(_mm512_setr_epi64(0, 2, 4, 6, 1, 3, 5, 7), packed)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::narrow_saturating_i8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Packing works on each 128 bit half, so gather the halves coming from the same input.
+            _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packs_epi16(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i16>::narrow_saturating_i8(a_lo, a_hi),
+                Ops::<Sse41, i16>::narrow_saturating_i8(b_lo, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::narrow_saturating_i8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::narrow_saturating_i8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi16(a, b)
+        }
+        for Scalar(a: i16, _b: i16) -> i8 {
+            a.clamp(i8::MIN as i16, i8::MAX as i16) as i8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int8x16_t {
+            vcombine_s8(vqmovn_s16(a), vqmovn_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_narrow_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unsigned_narrow_saturating_u8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Packing works on each 128 bit quarter, so gather the quarters coming from the same input.
+            let packed = _mm512_packus_epi16(a, b);
+            _mm512_permutexvar_epi64(_mm512_setr_epi64(0, 2, 4, 6, 1, 3, 5, 7), packed)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::unsigned_narrow_saturating_u8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Packing works on each 128 bit half, so gather the halves coming from the same input.
+            _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packus_epi16(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i16>::unsigned_narrow_saturating_u8(a_lo, a_hi),
+                Ops::<Sse41, i16>::unsigned_narrow_saturating_u8(b_lo, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::unsigned_narrow_saturating_u8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::unsigned_narrow_saturating_u8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi16(a, b)
+        }
+        for Scalar(a: i16, _b: i16) -> u8 {
+            a.clamp(0, u8::MAX as i16) as u8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> uint8x16_t {
+            vcombine_u8(vqmovun_s16(a), vqmovun_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_narrow_i16x8(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_truncating_i8<i16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // The low byte of every lane sits at an even byte index, which is what unzip gathers.
+            Ops::<Avx512, i8>::unzip(a, b).0
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::narrow_truncating_i8(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::unzip(a, b).0
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::unzip(a, b).0
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::narrow_truncating_i8(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::unzip(a, b).0
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::narrow_truncating_i8(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::unzip(a, b).0
+        }
+        for Scalar(a: i16, _b: i16) -> i8 {
+            a as i8
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int8x16_t {
+            vcombine_s8(vmovn_s16(a), vmovn_s16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::unzip(a, b).0
+        }
+    }
+}
@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_add_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::add(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_add_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::add(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::add(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi32(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::sub(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_sub_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::sub(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::sub(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi32(a, b)
         }
@@ -48,12 +78,27 @@ impl_op! {
 
 impl_op! {
     fn mul<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mullo_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mul(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_mullo_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::mul, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mul(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_mullo_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mul(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let a_arr = core::mem::transmute::<__m128i, [i32; 4]>(a);
             let b_arr = core::mem::transmute::<__m128i, [i32; 4]>(b);
@@ -79,12 +124,27 @@ impl_op! {
 
 impl_op! {
     fn min<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::min(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_min_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::min(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_min_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::min(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi32(a, b);
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
@@ -103,12 +163,27 @@ impl_op! {
 
 impl_op! {
     fn max<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::max(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_max_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::max(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_max_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::max(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi32(a, b);
             _mm_or_si128(_mm_and_si128(mask, a), _mm_andnot_si128(mask, b))
@@ -127,12 +202,27 @@ impl_op! {
 
 impl_op! {
     fn abs<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_abs_epi32(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::abs(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_abs_epi32(a)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::abs, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::abs(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_abs_epi32(a)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            _mm_abs_epi32(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi32(_mm_setzero_si128(), a);
             _mm_sub_epi32(_mm_xor_si128(a, mask), mask)
@@ -151,12 +241,27 @@ impl_op! {
 
 impl_op! {
     fn eq<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpeq_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::eq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpeq_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::eq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::eq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::eq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi32(a, b)
         }
@@ -178,14 +283,29 @@ impl_op! {
 
 impl_op! {
     fn neq<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpneq_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::neq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let eq = _mm256_cmpeq_epi32(a, b);
             _mm256_xor_si256(eq, _mm256_set1_epi32(u32::MAX as i32))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::neq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::neq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi32(a, b);
             _mm_xor_si128(eq, _mm_set1_epi32(u32::MAX as i32))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::neq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi32(a, b);
             _mm_xor_si128(eq, _mm_set1_epi32(u32::MAX as i32))
@@ -208,16 +328,31 @@ impl_op! {
 
 impl_op! {
     fn lt<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmplt_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::lt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi32(a, b);
             let eq = _mm256_cmpeq_epi32(a, b);
             _mm256_andnot_si256(_mm256_or_si256(gt, eq), _mm256_set1_epi32(u32::MAX as i32))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::lt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             let eq = _mm_cmpeq_epi32(a, b);
             _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi32(u32::MAX as i32))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::lt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             let eq = _mm_cmpeq_epi32(a, b);
@@ -241,14 +376,29 @@ impl_op! {
 
 impl_op! {
     fn lte<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmple_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::lte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi32(a, b);
             _mm256_xor_si256(gt, _mm256_set1_epi32(u32::MAX as i32))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::lte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             _mm_xor_si128(gt, _mm_set1_epi32(u32::MAX as i32))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::lte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             _mm_xor_si128(gt, _mm_set1_epi32(u32::MAX as i32))
@@ -271,12 +421,27 @@ impl_op! {
 
 impl_op! {
     fn gt<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpgt_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::gt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpgt_epi32(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::gt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi32(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::gt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi32(a, b)
         }
@@ -298,16 +463,31 @@ impl_op! {
 
 impl_op! {
     fn gte<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpge_epi32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::gte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi32(a, b);
             let eq = _mm256_cmpeq_epi32(a, b);
             _mm256_or_si256(gt, eq)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::gte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             let eq = _mm_cmpeq_epi32(a, b);
             _mm_or_si128(gt, eq)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::gte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi32(a, b);
             let eq = _mm_cmpeq_epi32(a, b);
@@ -331,12 +511,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<i32> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            _mm512_mask_blend_epi32(_mm512_movepi32_mask(mask), a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
             _mm256_blendv_epi8(a, b, mask)
         }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::blendv, a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_blendv_epi8(a, b, mask)
         }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
@@ -358,12 +553,28 @@ impl_op! {
 
 impl_op! {
     fn shl<i32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sll_epi32(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shl(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_sll_epi32(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i32>::shl(a_lo, rhs), Ops::<Sse41, i32>::shl(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shl(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             _mm_sll_epi32(a, _mm_cvtsi32_si128(rhs))
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shl(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             _mm_sll_epi32(a, _mm_cvtsi32_si128(rhs))
         }
@@ -382,12 +593,28 @@ impl_op! {
 
 impl_op! {
     fn shr<i32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_srl_epi32(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shr(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_srl_epi32(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i32>::shr(a_lo, rhs), Ops::<Sse41, i32>::shr(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shr(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi32(a, _mm_cvtsi32_si128(rhs))
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shr(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi32(a, _mm_cvtsi32_si128(rhs))
         }
@@ -406,12 +633,27 @@ impl_op! {
 
 impl_imm8_op! {
     fn shl_const<i32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shl(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_slli_epi32(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::shl_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_slli_epi32(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_slli_epi32(a, BY)
         }
@@ -429,12 +671,27 @@ impl_imm8_op! {
 
 impl_imm8_op! {
     fn shr_const<i32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shr(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_srli_epi32(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::shr_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_srli_epi32(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_srli_epi32(a, BY)
         }
@@ -450,14 +707,266 @@ impl_imm8_op! {
     }
 }
 
+impl_op! {
+    fn shl_var<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sllv_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sllv_epi32(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::shl_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            m128i_shift_epi32_by_lane!(_mm_sll_epi32, a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vshlq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let shifted0 = i32x4_shl(a, i32x4_extract_lane::<0>(b) as u32);
+            let shifted1 = i32x4_shl(a, i32x4_extract_lane::<1>(b) as u32);
+            let shifted2 = i32x4_shl(a, i32x4_extract_lane::<2>(b) as u32);
+            let shifted3 = i32x4_shl(a, i32x4_extract_lane::<3>(b) as u32);
+            let low = i32x4_shuffle::<0, 5, 2, 7>(shifted0, shifted1);
+            let high = i32x4_shuffle::<0, 5, 2, 7>(shifted2, shifted3);
+            i32x4_shuffle::<0, 1, 6, 7>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srav_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_srav_epi32(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::shr_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            m128i_shift_epi32_by_lane!(_mm_sra_epi32, a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vshlq_s32(a, vnegq_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let shifted0 = i32x4_shr(a, i32x4_extract_lane::<0>(b) as u32);
+            let shifted1 = i32x4_shr(a, i32x4_extract_lane::<1>(b) as u32);
+            let shifted2 = i32x4_shr(a, i32x4_extract_lane::<2>(b) as u32);
+            let shifted3 = i32x4_shr(a, i32x4_extract_lane::<3>(b) as u32);
+            let low = i32x4_shuffle::<0, 5, 2, 7>(shifted0, shifted1);
+            let high = i32x4_shuffle::<0, 5, 2, 7>(shifted2, shifted3);
+            i32x4_shuffle::<0, 1, 6, 7>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srlv_epi32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_srlv_epi32(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::shr_logical, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            m128i_shift_epi32_by_lane!(_mm_srl_epi32, a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            (a as u32).wrapping_shr(b as u32) as i32
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vreinterpretq_s32_u32(vshlq_u32(vreinterpretq_u32_s32(a), vnegq_s32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let shifted0 = u32x4_shr(a, i32x4_extract_lane::<0>(b) as u32);
+            let shifted1 = u32x4_shr(a, i32x4_extract_lane::<1>(b) as u32);
+            let shifted2 = u32x4_shr(a, i32x4_extract_lane::<2>(b) as u32);
+            let shifted3 = u32x4_shr(a, i32x4_extract_lane::<3>(b) as u32);
+            let low = i32x4_shuffle::<0, 5, 2, 7>(shifted0, shifted1);
+            let high = i32x4_shuffle::<0, 5, 2, 7>(shifted2, shifted3);
+            i32x4_shuffle::<0, 1, 6, 7>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_madd_epi16(Ops::<Avx512, i16>::count_ones(a), _mm512_set1_epi16(1))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            _mm256_madd_epi16(Ops::<Avx2, i16>::count_ones(a), _mm256_set1_epi16(1))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::count_ones, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i32>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            _mm_madd_epi16(Ops::<Ssse3, i16>::count_ones(a), _mm_set1_epi16(1))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            _mm_madd_epi16(Ops::<Sse2, i16>::count_ones(a), _mm_set1_epi16(1))
+        }
+        for Scalar(a: i32) -> i32 {
+            a.count_ones() as i32
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            vreinterpretq_s32_u32(vpaddlq_u16(vpaddlq_u8(vcntq_u8(vreinterpretq_u8_s32(a)))))
+        }
+        for Wasm(a: v128) -> v128 {
+            i32x4_extadd_pairwise_u16x8(Ops::<Wasm, i16>::count_ones(a))
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Smear the highest set bit into the bits below it, then count the bits left above it.
+            let a = _mm512_or_si512(a, _mm512_srli_epi32::<1>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32::<2>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32::<4>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32::<8>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi32::<16>(a));
+            Self::count_ones(_mm512_xor_si512(a, _mm512_set1_epi8(-1)))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi32::<1>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32::<2>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32::<4>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32::<8>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi32::<16>(a));
+            Self::count_ones(_mm256_xor_si256(a, _mm256_set1_epi8(-1)))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::leading_zeros, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i32>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi32::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<8>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<16>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi32::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<8>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi32::<16>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Scalar(a: i32) -> i32 {
+            a.leading_zeros() as i32
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            vclzq_s32(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u32x4_shr(a, 1));
+            let a = v128_or(a, u32x4_shr(a, 2));
+            let a = v128_or(a, u32x4_shr(a, 4));
+            let a = v128_or(a, u32x4_shr(a, 8));
+            let a = v128_or(a, u32x4_shr(a, 16));
+            Self::count_ones(v128_not(a))
+        }
+    }
+}
+
 impl_op! {
     fn cast_f32<i32> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_cvtepi32_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i32>::cast_f32(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_cvtepi32_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_cvtepi32_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i32>::cast_f32(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_cvtepi32_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i32>::cast_f32(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_cvtepi32_ps(a)
         }
@@ -475,12 +984,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_f32<i32> {
+        for Avx512(a: __m512i) -> __m512 {
+            _mm512_castsi512_ps(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256 {
+            Ops::<Avx2, i32>::bitcast_f32(a)
+        }
         for Avx2(a: __m256i) -> __m256 {
             _mm256_castsi256_ps(a)
         }
+        for Avx(a: __m256i) -> __m256 {
+            _mm256_castsi256_ps(a)
+        }
+        for Sse42(a: __m128i) -> __m128 {
+            Ops::<Sse41, i32>::bitcast_f32(a)
+        }
         for Sse41(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
+        for Ssse3(a: __m128i) -> __m128 {
+            Ops::<Sse2, i32>::bitcast_f32(a)
+        }
         for Sse2(a: __m128i) -> __m128 {
             _mm_castsi128_ps(a)
         }
@@ -498,14 +1022,34 @@ impl_op! {
 
 impl_op! {
     fn extend_i64<i32> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepi32_epi64(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepi32_epi64(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i32>::extend_i64(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepi32_epi64(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepi32_epi64(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i32>::extend_i64(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i32>::extend_i64(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i32>::extend_i64(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             Ops::<Sse2, i32>::extend_i64(val)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i32>::extend_i64(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i32; 4]>(val);
             let a = [arr[0] as i64, arr[1] as i64];
@@ -533,14 +1077,34 @@ impl_op! {
 
 impl_op! {
     fn unsigned_extend_i64<i32> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepu32_epi64(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepu32_epi64(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i32>::unsigned_extend_i64(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepu32_epi64(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepu32_epi64(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i32>::unsigned_extend_i64(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i32>::unsigned_extend_i64(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i32>::unsigned_extend_i64(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             Ops::<Sse2, i32>::unsigned_extend_i64(val)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i32>::unsigned_extend_i64(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i32; 4]>(val);
             let a = [arr[0] as u32 as u64 as i64, arr[1] as u32 as u64 as i64];
@@ -565,12 +1129,27 @@ impl_op! {
 
 impl_op! {
     fn zeroes<i32> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, i32>::zeroes()
+        }
         for Avx2() -> __m256i {
             _mm256_setzero_si256()
         }
+        for Avx() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, i32>::zeroes()
+        }
         for Sse41() -> __m128i {
             _mm_setzero_si128()
         }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, i32>::zeroes()
+        }
         for Sse2() -> __m128i {
             _mm_setzero_si128()
         }
@@ -588,12 +1167,27 @@ impl_op! {
 
 impl_op! {
     fn set1<i32> {
+        for Avx512(val: i32) -> __m512i {
+            _mm512_set1_epi32(val)
+        }
+        for Avx2Fma(val: i32) -> __m256i {
+            Ops::<Avx2, i32>::set1(val)
+        }
         for Avx2(val: i32) -> __m256i {
             _mm256_set1_epi32(val)
         }
+        for Avx(val: i32) -> __m256i {
+            _mm256_set1_epi32(val)
+        }
+        for Sse42(val: i32) -> __m128i {
+            Ops::<Sse41, i32>::set1(val)
+        }
         for Sse41(val: i32) -> __m128i {
             _mm_set1_epi32(val)
         }
+        for Ssse3(val: i32) -> __m128i {
+            Ops::<Sse2, i32>::set1(val)
+        }
         for Sse2(val: i32) -> __m128i {
             _mm_set1_epi32(val)
         }
@@ -611,12 +1205,27 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<i32> {
+        for Avx512(ptr: *const i32) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i32) -> __m256i {
+            Ops::<Avx2, i32>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const i32) -> __m256i {
             _mm256_loadu_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i32) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i32) -> __m128i {
+            Ops::<Sse41, i32>::load_unaligned(ptr)
+        }
         for Sse41(ptr: *const i32) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i32) -> __m128i {
+            Ops::<Sse2, i32>::load_unaligned(ptr)
+        }
         for Sse2(ptr: *const i32) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
@@ -634,12 +1243,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<i32> {
+        for Avx512(ptr: *const i32) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i32) -> __m256i {
+            Ops::<Avx2, i32>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const i32) -> __m256i {
             _mm256_load_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i32) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i32) -> __m128i {
+            Ops::<Sse41, i32>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const i32) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i32) -> __m128i {
+            Ops::<Sse2, i32>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const i32) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
@@ -657,12 +1281,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<i32> {
+        for Avx512(ptr: *mut i32, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i32, a: __m256i) {
+            Ops::<Avx2, i32>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut i32, a: __m256i) {
             _mm256_storeu_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i32, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i32, a: __m128i) {
+            Ops::<Sse41, i32>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut i32, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i32, a: __m128i) {
+            Ops::<Sse2, i32>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut i32, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
@@ -680,12 +1319,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<i32> {
+        for Avx512(ptr: *mut i32, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i32, a: __m256i) {
+            Ops::<Avx2, i32>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut i32, a: __m256i) {
             _mm256_store_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i32, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i32, a: __m128i) {
+            Ops::<Sse41, i32>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut i32, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i32, a: __m128i) {
+            Ops::<Sse2, i32>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut i32, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
@@ -700,3 +1354,949 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn get_mask<i32> {
+        for Avx512(val: __m512i) -> u64 {
+            _mm512_movepi32_mask(val) as u64
+        }
+        for Avx2Fma(val: __m256i) -> u64 {
+            Ops::<Avx2, i32>::get_mask(val)
+        }
+        for Avx2(val: __m256i) -> u64 {
+            Ops::<Avx, i32>::get_mask(val)
+        }
+        for Avx(val: __m256i) -> u64 {
+            _mm256_movemask_ps(_mm256_castsi256_ps(val)) as u64
+        }
+        for Sse42(val: __m128i) -> u64 {
+            Ops::<Sse41, i32>::get_mask(val)
+        }
+        for Sse41(val: __m128i) -> u64 {
+            Ops::<Sse2, i32>::get_mask(val)
+        }
+        for Ssse3(val: __m128i) -> u64 {
+            Ops::<Sse2, i32>::get_mask(val)
+        }
+        for Sse2(val: __m128i) -> u64 {
+            _mm_movemask_ps(_mm_castsi128_ps(val)) as u64
+        }
+        for Scalar(val: i32) -> u64 {
+            ((val as u32) >> 31) as u64
+        }
+        for Neon(val: int32x4_t) -> u64 {
+            // Move each sign bit to the bottom of its lane, then shift it into its bit position.
+            let signs = vshrq_n_u32(vreinterpretq_u32_s32(val), 31);
+            let shifts: [i32; 4] = [0, 1, 2, 3];
+            vaddvq_u32(vshlq_u32(signs, vld1q_s32(shifts.as_ptr()))) as u64
+        }
+        for Wasm(val: v128) -> u64 {
+            i32x4_bitmask(val) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn gather_unchecked<i32> {
+        for Avx512(base: *const i32, indices: __m512i) -> __m512i {
+            _mm512_i32gather_epi32::<4>(indices, base)
+        }
+        for Avx2Fma(base: *const i32, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const i32, indices: __m256i) -> __m256i {
+            _mm256_i32gather_epi32(base, indices, 4)
+        }
+        for Avx(base: *const i32, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i32; 8]>(indices);
+            core::mem::transmute::<[i32; 8], __m256i>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Sse42(base: *const i32, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const i32, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::gather_unchecked(base, indices)
+        }
+        for Ssse3(base: *const i32, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const i32, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i32; 4]>(indices);
+            core::mem::transmute::<[i32; 4], __m128i>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Scalar(base: *const i32, indices: i32) -> i32 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const i32, indices: int32x4_t) -> int32x4_t {
+            let indices = core::mem::transmute::<int32x4_t, [i32; 4]>(indices);
+            core::mem::transmute::<[i32; 4], int32x4_t>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Wasm(base: *const i32, indices: v128) -> v128 {
+            let indices = core::mem::transmute::<v128, [i32; 4]>(indices);
+            core::mem::transmute::<[i32; 4], v128>(indices.map(|i| *base.add(i as usize)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<i32> {
+        for Avx512(base: *mut i32, indices: __m512i, val: __m512i) {
+            _mm512_i32scatter_epi32::<4>(base, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<i32> {
+        for Avx512(base: *mut i32, indices: __m512i, val: __m512i, mask: __mmask16) {
+            _mm512_mask_i32scatter_epi32::<4>(base, mask, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<i32> {
+        for Avx512(ptr: *const i32, mask: __mmask16) -> __m512i {
+            _mm512_maskz_loadu_epi32(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const i32, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const i32, mask: __m256i) -> __m256i {
+            _mm256_maskload_epi32(ptr, mask)
+        }
+        for Avx(ptr: *const i32, mask: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_maskload_ps(ptr as *const f32, mask))
+        }
+        for Sse42(ptr: *const i32, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const i32, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const i32, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const i32, mask: __m128i) -> __m128i {
+            load_masked_emulated!(ptr, Ops::<Sse2, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+        for Scalar(ptr: *const i32, mask: i32) -> i32 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const i32, mask: int32x4_t) -> int32x4_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+        for Wasm(ptr: *const i32, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<i32> {
+        for Avx512(ptr: *mut i32, a: __m512i, mask: __mmask16) {
+            _mm512_mask_storeu_epi32(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut i32, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i32>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut i32, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_epi32(ptr, mask, a)
+        }
+        for Avx(ptr: *mut i32, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_ps(ptr as *mut f32, mask, _mm256_castsi256_ps(a))
+        }
+        for Sse42(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i32>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i32>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i32>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i32, a: __m128i, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+        for Scalar(ptr: *mut i32, a: i32, mask: i32) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut i32, a: int32x4_t, mask: int32x4_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+        for Wasm(ptr: *mut i32, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i32>::mask_to_bitmask(mask), [i32; 4])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i32> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            _mm512_permutexvar_epi32(indices, a)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            _mm256_permutevar8x32_epi32(a, indices)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i32; 8]>(indices);
+            let values = core::mem::transmute::<__m256i, [i32; 8]>(a);
+            core::mem::transmute::<[i32; 8], __m256i>(indices.map(|i| values[i as usize & 7]))
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi32(3));
+            let low = _mm_slli_epi32::<2>(lanes);
+            let low = _mm_or_si128(low, _mm_slli_epi32::<8>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi32::<16>(low));
+            let bytes = _mm_add_epi32(low, _mm_set1_epi32(0x03020100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi32(3));
+            let low = _mm_slli_epi32::<2>(lanes);
+            let low = _mm_or_si128(low, _mm_slli_epi32::<8>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi32::<16>(low));
+            let bytes = _mm_add_epi32(low, _mm_set1_epi32(0x03020100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i32; 4]>(indices);
+            let values = core::mem::transmute::<__m128i, [i32; 4]>(a);
+            core::mem::transmute::<[i32; 4], __m128i>(indices.map(|i| values[i as usize & 3]))
+        }
+        for Scalar(a: i32, indices: i32) -> i32 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int32x4_t, indices: int32x4_t) -> int32x4_t {
+            let lanes = vandq_u32(vreinterpretq_u32_s32(indices), vdupq_n_u32(3));
+            let low = vshlq_n_u32::<2>(lanes);
+            let bytes = vreinterpretq_u8_u32(vmlaq_n_u32(vdupq_n_u32(0x03020100), low, 0x01010101));
+            vreinterpretq_s32_s8(vqtbl1q_s8(vreinterpretq_s8_s32(a), bytes))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            let low = i32x4_shl(v128_and(indices, i32x4_splat(3)), 2);
+            let bytes = i32x4_add(i32x4_mul(low, i32x4_splat(0x01010101)), i32x4_splat(0x03020100));
+            i8x16_swizzle(a, bytes)
+        }
+    }
+}
+
+impl_op! {
+    fn shuffle<i32> {
+        for Avx512(a: __m512i, indices: [i32; 16]) -> __m512i {
+            Ops::<Avx512, i32>::permute(a, core::mem::transmute::<[i32; 16], __m512i>(indices))
+        }
+        for Avx2Fma(a: __m256i, indices: [i32; 8]) -> __m256i {
+            Ops::<Avx2, i32>::shuffle(a, indices)
+        }
+        for Avx2(a: __m256i, indices: [i32; 8]) -> __m256i {
+            Ops::<Avx2, i32>::permute(a, core::mem::transmute::<[i32; 8], __m256i>(indices))
+        }
+        for Avx(a: __m256i, indices: [i32; 8]) -> __m256i {
+            // vpermilps only looks within each 128 bit half, so look up both halves and pick the
+            // result from the half each index points into.
+            let a = _mm256_castsi256_ps(a);
+            let lanes = core::mem::transmute::<[i32; 8], __m256i>(indices);
+            let same = _mm256_permutevar_ps(a, lanes);
+            let other = _mm256_permutevar_ps(_mm256_permute2f128_ps::<0x01>(a, a), lanes);
+            let crossing: [i32; 8] = core::array::from_fn(|i| -((indices[i] ^ i as i32) >> 2));
+            let crossing = _mm256_castsi256_ps(core::mem::transmute::<[i32; 8], __m256i>(crossing));
+            _mm256_castps_si256(_mm256_blendv_ps(same, other, crossing))
+        }
+        for Sse42(a: __m128i, indices: [i32; 4]) -> __m128i {
+            Ops::<Sse41, i32>::shuffle(a, indices)
+        }
+        for Sse41(a: __m128i, indices: [i32; 4]) -> __m128i {
+            Ops::<Sse2, i32>::shuffle(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: [i32; 4]) -> __m128i {
+            Ops::<Sse2, i32>::shuffle(a, indices)
+        }
+        for Sse2(a: __m128i, indices: [i32; 4]) -> __m128i {
+            // Compiles to pshufd.
+            pick_lanes!(a: __m128i, indices, [i32; 4])
+        }
+        for Scalar(a: i32, indices: [i32; 1]) -> i32 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int32x4_t, indices: [i32; 4]) -> int32x4_t {
+            let indices = split_lane_indices!(indices, 4, i8);
+            let bytes = Ops::<Neon, i8>::shuffle(vreinterpretq_s8_s32(a), indices);
+            vreinterpretq_s32_s8(bytes)
+        }
+        for Wasm(a: v128, indices: [i32; 4]) -> v128 {
+            Ops::<Wasm, i8>::shuffle(a, split_lane_indices!(indices, 4, i8))
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i32; 16], __m512i>([0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23]);
+            _mm512_permutex2var_epi32(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi32(a, b);
+            let hi = _mm256_unpackhi_epi32(a, b);
+            _mm256_permute2x128_si256::<0x20>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, _) = split_m256i!(a);
+            let (b_lo, _) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i32>::zip_lo(a_lo, b_lo),
+                Ops::<Sse41, i32>::zip_hi(a_lo, b_lo)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpacklo_epi32(a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            let _ = b;
+            a
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vzip1q_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_shuffle::<0, 4, 1, 5>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i32; 16], __m512i>([8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31]);
+            _mm512_permutex2var_epi32(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi32(a, b);
+            let hi = _mm256_unpackhi_epi32(a, b);
+            _mm256_permute2x128_si256::<0x31>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (_, a_hi) = split_m256i!(a);
+            let (_, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i32>::zip_lo(a_hi, b_hi),
+                Ops::<Sse41, i32>::zip_hi(a_hi, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpackhi_epi32(a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            let _ = a;
+            b
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vzip2q_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i32x4_shuffle::<2, 6, 3, 7>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let even = core::mem::transmute::<[i32; 16], __m512i>([0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30]);
+            let odd = core::mem::transmute::<[i32; 16], __m512i>([1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31]);
+            (_mm512_permutex2var_epi32(a, even, b), _mm512_permutex2var_epi32(a, odd, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i32>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            // Unzip each 128 bit half, then gather the halves coming from the same input.
+            let (a, b) = (_mm256_castsi256_ps(a), _mm256_castsi256_ps(b));
+            let even = _mm256_castps_si256(_mm256_shuffle_ps::<0b10_00_10_00>(a, b));
+            let odd = _mm256_castps_si256(_mm256_shuffle_ps::<0b11_01_11_01>(a, b));
+            (_mm256_permute4x64_epi64::<0b11_01_10_00>(even), _mm256_permute4x64_epi64::<0b11_01_10_00>(odd))
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            let (a_even, a_odd) = Ops::<Sse41, i32>::unzip(a_lo, a_hi);
+            let (b_even, b_odd) = Ops::<Sse41, i32>::unzip(b_lo, b_hi);
+            (join_m128i!(a_even, b_even), join_m128i!(a_odd, b_odd))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i32>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i32>::unzip(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i32>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (a, b) = (_mm_castsi128_ps(a), _mm_castsi128_ps(b));
+            let even = _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(a, b));
+            let odd = _mm_castps_si128(_mm_shuffle_ps::<0b11_01_11_01>(a, b));
+            (even, odd)
+        }
+        for Scalar(a: i32, b: i32) -> (i32, i32) {
+            (a, b)
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> (int32x4_t, int32x4_t) {
+            (vuzp1q_s32(a, b), vuzp2q_s32(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let even = i32x4_shuffle::<0, 2, 4, 6>(a, b);
+            let odd = i32x4_shuffle::<1, 3, 5, 7>(a, b);
+            (even, odd)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t) {
+            let loaded = vld2q_s32(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t) {
+            vst2q_s32(ptr, int32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t, int32x4_t) {
+            let loaded = vld3q_s32(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t, c: int32x4_t) {
+            vst3q_s32(ptr, int32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<i32> {
+        for Neon(ptr: *const i32) -> (int32x4_t, int32x4_t, int32x4_t, int32x4_t) {
+            let loaded = vld4q_s32(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i32> {
+        for Neon(ptr: *mut i32, a: int32x4_t, b: int32x4_t, c: int32x4_t, d: int32x4_t) {
+            vst4q_s32(ptr, int32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn transpose<i32> {
+        for Avx512(rows: [__m512i; 16]) -> [__m512i; 16] {
+            let mut cols = rows;
+            for g in 0..4 {
+                let t0 = _mm512_unpacklo_epi32(rows[4 * g], rows[4 * g + 1]);
+                let t1 = _mm512_unpackhi_epi32(rows[4 * g], rows[4 * g + 1]);
+                let t2 = _mm512_unpacklo_epi32(rows[4 * g + 2], rows[4 * g + 3]);
+                let t3 = _mm512_unpackhi_epi32(rows[4 * g + 2], rows[4 * g + 3]);
+                cols[4 * g] = _mm512_unpacklo_epi64(t0, t2);
+                cols[4 * g + 1] = _mm512_unpackhi_epi64(t0, t2);
+                cols[4 * g + 2] = _mm512_unpacklo_epi64(t1, t3);
+                cols[4 * g + 3] = _mm512_unpackhi_epi64(t1, t3);
+            }
+            // Each 128-bit lane is transposed, so what remains is a 4x4 transpose of the lanes.
+            let mut out = cols;
+            for m in 0..4 {
+                let p0 = _mm512_shuffle_i32x4::<0x88>(cols[m], cols[4 + m]);
+                let p1 = _mm512_shuffle_i32x4::<0xDD>(cols[m], cols[4 + m]);
+                let q0 = _mm512_shuffle_i32x4::<0x88>(cols[8 + m], cols[12 + m]);
+                let q1 = _mm512_shuffle_i32x4::<0xDD>(cols[8 + m], cols[12 + m]);
+                out[m] = _mm512_shuffle_i32x4::<0x88>(p0, q0);
+                out[4 + m] = _mm512_shuffle_i32x4::<0x88>(p1, q1);
+                out[8 + m] = _mm512_shuffle_i32x4::<0xDD>(p0, q0);
+                out[12 + m] = _mm512_shuffle_i32x4::<0xDD>(p1, q1);
+            }
+            out
+        }
+        for Avx2Fma(rows: [__m256i; 8]) -> [__m256i; 8] {
+            Ops::<Avx2, i32>::transpose(rows)
+        }
+        for Avx2(rows: [__m256i; 8]) -> [__m256i; 8] {
+            let mut cols = rows;
+            for g in 0..2 {
+                let t0 = _mm256_unpacklo_epi32(rows[4 * g], rows[4 * g + 1]);
+                let t1 = _mm256_unpackhi_epi32(rows[4 * g], rows[4 * g + 1]);
+                let t2 = _mm256_unpacklo_epi32(rows[4 * g + 2], rows[4 * g + 3]);
+                let t3 = _mm256_unpackhi_epi32(rows[4 * g + 2], rows[4 * g + 3]);
+                cols[4 * g] = _mm256_unpacklo_epi64(t0, t2);
+                cols[4 * g + 1] = _mm256_unpackhi_epi64(t0, t2);
+                cols[4 * g + 2] = _mm256_unpacklo_epi64(t1, t3);
+                cols[4 * g + 3] = _mm256_unpackhi_epi64(t1, t3);
+            }
+            let mut out = cols;
+            for m in 0..4 {
+                out[m] = _mm256_permute2x128_si256::<0x20>(cols[m], cols[4 + m]);
+                out[4 + m] = _mm256_permute2x128_si256::<0x31>(cols[m], cols[4 + m]);
+            }
+            out
+        }
+        for Avx(rows: [__m256i; 8]) -> [__m256i; 8] {
+            let mut floats = [_mm256_setzero_ps(); 8];
+            for i in 0..8 {
+                floats[i] = _mm256_castsi256_ps(rows[i]);
+            }
+            let floats = Ops::<Avx, f32>::transpose(floats);
+            let mut out = rows;
+            for i in 0..8 {
+                out[i] = _mm256_castps_si256(floats[i]);
+            }
+            out
+        }
+        for Sse42(rows: [__m128i; 4]) -> [__m128i; 4] {
+            Ops::<Sse41, i32>::transpose(rows)
+        }
+        for Sse41(rows: [__m128i; 4]) -> [__m128i; 4] {
+            Ops::<Sse2, i32>::transpose(rows)
+        }
+        for Ssse3(rows: [__m128i; 4]) -> [__m128i; 4] {
+            Ops::<Sse2, i32>::transpose(rows)
+        }
+        for Sse2(rows: [__m128i; 4]) -> [__m128i; 4] {
+            let [r0, r1, r2, r3] = rows;
+            let t0 = _mm_unpacklo_epi32(r0, r1);
+            let t1 = _mm_unpackhi_epi32(r0, r1);
+            let t2 = _mm_unpacklo_epi32(r2, r3);
+            let t3 = _mm_unpackhi_epi32(r2, r3);
+            [
+                _mm_unpacklo_epi64(t0, t2),
+                _mm_unpackhi_epi64(t0, t2),
+                _mm_unpacklo_epi64(t1, t3),
+                _mm_unpackhi_epi64(t1, t3),
+            ]
+        }
+        for Scalar(rows: [i32; 1]) -> [i32; 1] {
+            rows
+        }
+        for Neon(rows: [int32x4_t; 4]) -> [int32x4_t; 4] {
+            let [r0, r1, r2, r3] = rows;
+            let t0 = vreinterpretq_s64_s32(vtrn1q_s32(r0, r1));
+            let t1 = vreinterpretq_s64_s32(vtrn2q_s32(r0, r1));
+            let t2 = vreinterpretq_s64_s32(vtrn1q_s32(r2, r3));
+            let t3 = vreinterpretq_s64_s32(vtrn2q_s32(r2, r3));
+            [
+                vreinterpretq_s32_s64(vzip1q_s64(t0, t2)),
+                vreinterpretq_s32_s64(vzip1q_s64(t1, t3)),
+                vreinterpretq_s32_s64(vzip2q_s64(t0, t2)),
+                vreinterpretq_s32_s64(vzip2q_s64(t1, t3)),
+            ]
+        }
+        for Wasm(rows: [v128; 4]) -> [v128; 4] {
+            let [r0, r1, r2, r3] = rows;
+            let t0 = i32x4_shuffle::<0, 4, 1, 5>(r0, r1);
+            let t1 = i32x4_shuffle::<2, 6, 3, 7>(r0, r1);
+            let t2 = i32x4_shuffle::<0, 4, 1, 5>(r2, r3);
+            let t3 = i32x4_shuffle::<2, 6, 3, 7>(r2, r3);
+            [
+                i64x2_shuffle::<0, 2>(t0, t2),
+                i64x2_shuffle::<1, 3>(t0, t2),
+                i64x2_shuffle::<0, 2>(t1, t3),
+                i64x2_shuffle::<1, 3>(t1, t3),
+            ]
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            let a = _mm512_add_epi32(a, _mm512_alignr_epi32::<15>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi32(a, _mm512_alignr_epi32::<14>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi32(a, _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512()));
+            _mm512_add_epi32(a, _mm512_alignr_epi32::<8>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi32(a, _mm256_alignr_epi8::<12>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi32(a, _mm256_alignr_epi8::<8>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            _mm256_add_epi32(a, carry)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let lo = Ops::<Sse41, i32>::prefix_sum(lo);
+            let hi = Ops::<Sse41, i32>::prefix_sum(hi);
+            // Add the total of the lower half, held in its last lane, to every lane of the upper half.
+            join_m128i!(lo, _mm_add_epi32(hi, _mm_shuffle_epi32::<0xFF>(lo)))
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_add_epi32(a, _mm_slli_si128::<4>(a));
+            _mm_add_epi32(a, _mm_slli_si128::<8>(a))
+        }
+        for Scalar(a: i32) -> i32 {
+            a
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            let a = vaddq_s32(a, vextq_s32::<3>(vdupq_n_s32(0), a));
+            vaddq_s32(a, vextq_s32::<2>(vdupq_n_s32(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = i32x4_add(a, i32x4_shuffle::<4, 0, 1, 2>(a, i32x4_splat(0)));
+            i32x4_add(a, i32x4_shuffle::<4, 4, 0, 1>(a, i32x4_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::prefix_sum(_mm512_alignr_epi32::<15>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            Ops::<Avx2, i32>::prefix_sum(_mm256_alignr_epi8::<12>(a, carry))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let a = join_m128i!(_mm_slli_si128::<4>(lo), _mm_alignr_epi8::<12>(hi, lo));
+            Ops::<Avx, i32>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::prefix_sum(_mm_slli_si128::<4>(a))
+        }
+        for Scalar(a: i32) -> i32 {
+            let _ = a;
+            0
+        }
+        for Neon(a: int32x4_t) -> int32x4_t {
+            Ops::<Neon, i32>::prefix_sum(vextq_s32::<3>(vdupq_n_s32(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::prefix_sum(i32x4_shuffle::<4, 0, 1, 2>(a, i32x4_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn mul_hi<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Multiply the even lanes and the odd lanes into 64 bit products, then put the high halves
+            // back into their lanes.
+            let even = _mm512_mul_epi32(a, b);
+            let odd = _mm512_mul_epi32(_mm512_srli_epi64::<32>(a), _mm512_srli_epi64::<32>(b));
+            _mm512_mask_blend_epi32(0xAAAA, _mm512_srli_epi64::<32>(even), odd)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mul_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Multiply the even lanes and the odd lanes into 64 bit products, then put the high halves
+            // back into their lanes.
+            let even = _mm256_mul_epi32(a, b);
+            let odd = _mm256_mul_epi32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+            _mm256_blend_epi32::<0b10101010>(_mm256_srli_epi64::<32>(even), odd)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i32>::mul_hi, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mul_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            // Multiply the even lanes and the odd lanes into 64 bit products, then put the high halves
+            // back into their lanes.
+            let even = _mm_mul_epi32(a, b);
+            let odd = _mm_mul_epi32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+            _mm_blend_epi16::<0b11001100>(_mm_srli_epi64::<32>(even), odd)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mul_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Multiply the even lanes and the odd lanes into 64 bit products, then put the high halves
+            // back into their lanes.
+            let even = _mm_mul_epu32(a, b);
+            let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+            let odd_mask = _mm_set_epi32(-1, 0, -1, 0);
+            let unsigned = _mm_or_si128(_mm_srli_epi64::<32>(even), _mm_and_si128(odd, odd_mask));
+            // Turn the unsigned high halves into signed ones by subtracting `b` where `a` is negative
+            // and `a` where `b` is negative.
+            let fix_a = _mm_and_si128(_mm_srai_epi32::<31>(a), b);
+            let fix_b = _mm_and_si128(_mm_srai_epi32::<31>(b), a);
+            _mm_sub_epi32(_mm_sub_epi32(unsigned, fix_a), fix_b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            ((a as i64 * b as i64) >> 32) as i32
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            let lo = vreinterpretq_s32_s64(vmull_s32(vget_low_s32(a), vget_low_s32(b)));
+            let hi = vreinterpretq_s32_s64(vmull_high_s32(a, b));
+            vuzp2q_s32(lo, hi)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let lo = i64x2_extmul_low_i32x4(a, b);
+            let hi = i64x2_extmul_high_i32x4(a, b);
+            i32x4_shuffle::<1, 3, 5, 7>(lo, hi)
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_saturating_i16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Packing works on each 128 bit quarter, so gather the quarters coming from the same input.
+            let packed = _mm512_packs_epi32(a, b);
+            _mm512_permutexvar_epi64(_mm512_setr_epi64(0, 2, 4, 6, 1, 3, 5, 7), packed)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::narrow_saturating_i16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Packing works on each 128 bit half, so gather the halves coming from the same input.
+            _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packs_epi32(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i32>::narrow_saturating_i16(a_lo, a_hi),
+                Ops::<Sse41, i32>::narrow_saturating_i16(b_lo, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::narrow_saturating_i16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi32(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::narrow_saturating_i16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packs_epi32(a, b)
+        }
+        for Scalar(a: i32, _b: i32) -> i16 {
+            a.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int16x8_t {
+            vcombine_s16(vqmovn_s32(a), vqmovn_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i16x8_narrow_i32x4(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unsigned_narrow_saturating_u16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Packing works on each 128 bit quarter, so gather the quarters coming from the same input.
+            let packed = _mm512_packus_epi32(a, b);
+            _mm512_permutexvar_epi64(_mm512_setr_epi64(0, 2, 4, 6, 1, 3, 5, 7), packed)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::unsigned_narrow_saturating_u16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Packing works on each 128 bit half, so gather the halves coming from the same input.
+            _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_packus_epi32(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i32>::unsigned_narrow_saturating_u16(a_lo, a_hi),
+                Ops::<Sse41, i32>::unsigned_narrow_saturating_u16(b_lo, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::unsigned_narrow_saturating_u16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_packus_epi32(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::unsigned_narrow_saturating_u16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // There is no unsigned pack, so clamp the negative lanes to zero and bias the rest
+            // into the signed range, letting the signed pack saturate at the top.
+            let a = _mm_andnot_si128(_mm_srai_epi32::<31>(a), a);
+            let b = _mm_andnot_si128(_mm_srai_epi32::<31>(b), b);
+            let bias = _mm_set1_epi32(0x8000);
+            let packed = _mm_packs_epi32(_mm_sub_epi32(a, bias), _mm_sub_epi32(b, bias));
+            _mm_xor_si128(packed, _mm_set1_epi16(i16::MIN))
+        }
+        for Scalar(a: i32, _b: i32) -> u16 {
+            a.clamp(0, u16::MAX as i32) as u16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> uint16x8_t {
+            vcombine_u16(vqmovun_s32(a), vqmovun_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_narrow_i32x4(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_truncating_i16<i32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // The low half of every lane sits at an even 16 bit index, which is what unzip gathers.
+            Ops::<Avx512, i16>::unzip(a, b).0
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::narrow_truncating_i16(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::unzip(a, b).0
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::unzip(a, b).0
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::narrow_truncating_i16(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::unzip(a, b).0
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::narrow_truncating_i16(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::unzip(a, b).0
+        }
+        for Scalar(a: i32, _b: i32) -> i16 {
+            a as i16
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int16x8_t {
+            vcombine_s16(vmovn_s32(a), vmovn_s32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::unzip(a, b).0
+        }
+    }
+}
+
+impl_op! {
+    fn extend_f64<i32> {
+        for Avx512(a: __m512i) -> (__m512d, __m512d) {
+            let lo = _mm512_cvtepi32_pd(_mm512_castsi512_si256(a));
+            let hi = _mm512_cvtepi32_pd(_mm512_extracti64x4_epi64::<1>(a));
+            (lo, hi)
+        }
+        for Avx2Fma(a: __m256i) -> (__m256d, __m256d) {
+            Ops::<Avx2, i32>::extend_f64(a)
+        }
+        for Avx2(a: __m256i) -> (__m256d, __m256d) {
+            let lo = _mm256_cvtepi32_pd(_mm256_castsi256_si128(a));
+            let hi = _mm256_cvtepi32_pd(_mm256_extracti128_si256::<1>(a));
+            (lo, hi)
+        }
+        for Avx(a: __m256i) -> (__m256d, __m256d) {
+            let lo = _mm256_cvtepi32_pd(_mm256_castsi256_si128(a));
+            let hi = _mm256_cvtepi32_pd(_mm256_extractf128_si256::<1>(a));
+            (lo, hi)
+        }
+        for Sse42(a: __m128i) -> (__m128d, __m128d) {
+            Ops::<Sse41, i32>::extend_f64(a)
+        }
+        for Sse41(a: __m128i) -> (__m128d, __m128d) {
+            (_mm_cvtepi32_pd(a), _mm_cvtepi32_pd(_mm_unpackhi_epi64(a, a)))
+        }
+        for Ssse3(a: __m128i) -> (__m128d, __m128d) {
+            Ops::<Sse2, i32>::extend_f64(a)
+        }
+        for Sse2(a: __m128i) -> (__m128d, __m128d) {
+            (_mm_cvtepi32_pd(a), _mm_cvtepi32_pd(_mm_unpackhi_epi64(a, a)))
+        }
+        for Scalar(a: i32) -> (f64, f64) {
+            (a as f64, 0.0)
+        }
+        for Neon(a: int32x4_t) -> (float64x2_t, float64x2_t) {
+            let lo = vcvtq_f64_s64(vmovl_s32(vget_low_s32(a)));
+            let hi = vcvtq_f64_s64(vmovl_high_s32(a));
+            (lo, hi)
+        }
+        for Wasm(a: v128) -> (v128, v128) {
+            let hi = i32x4_shuffle::<2, 3, 2, 3>(a, a);
+            (f64x2_convert_low_i32x4(a), f64x2_convert_low_i32x4(hi))
+        }
+    }
+}
@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_add_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::add(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_add_epi64(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::add(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi64(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::add(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi64(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::sub(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_sub_epi64(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::sub(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi64(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::sub(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi64(a, b)
         }
@@ -48,6 +78,12 @@ impl_op! {
 
 impl_op! {
     fn mul<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_mullo_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mul(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let a_arr = core::mem::transmute::<__m256i, [i64; 4]>(a);
             let b_arr = core::mem::transmute::<__m256i, [i64; 4]>(b);
@@ -59,6 +95,12 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m256i>(c_arr)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::mul, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mul(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let a_arr = core::mem::transmute::<__m128i, [i64; 2]>(a);
             let b_arr = core::mem::transmute::<__m128i, [i64; 2]>(b);
@@ -68,6 +110,9 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m128i>(c_arr)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mul(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let a_arr = core::mem::transmute::<__m128i, [i64; 2]>(a);
             let b_arr = core::mem::transmute::<__m128i, [i64; 2]>(b);
@@ -97,16 +142,32 @@ impl_op! {
 
 impl_op! {
     fn min<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::min(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let mask = _mm256_cmpgt_epi64(a, b);
             _mm256_or_si256(_mm256_and_si256(mask, b), _mm256_andnot_si256(mask, a))
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi64(a, b);
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let mask = Ops::<Sse2, i64>::gt(a, b);
+            _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::min(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let mask = _mm_cmpgt_epi64(a, b);
+            let mask = Ops::<Sse2, i64>::gt(a, b);
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
         for Scalar(a: i64, b: i64) -> i64 {
@@ -126,16 +187,32 @@ impl_op! {
 
 impl_op! {
     fn max<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::max(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let mask = _mm256_cmpgt_epi64(a, b);
             _mm256_or_si256(_mm256_and_si256(mask, a), _mm256_andnot_si256(mask, b))
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi64(a, b);
             _mm_or_si128(_mm_and_si128(mask, a), _mm_andnot_si128(mask, b))
         }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let mask = Ops::<Sse2, i64>::gt(a, b);
+            _mm_or_si128(_mm_and_si128(mask, a), _mm_andnot_si128(mask, b))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::max(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let mask = _mm_cmpgt_epi64(a, b);
+            let mask = Ops::<Sse2, i64>::gt(a, b);
             _mm_or_si128(_mm_and_si128(mask, a), _mm_andnot_si128(mask, b))
         }
         for Scalar(a: i64, b: i64) -> i64 {
@@ -155,16 +232,32 @@ impl_op! {
 
 impl_op! {
     fn abs<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_abs_epi64(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::abs(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             let mask = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
             _mm256_sub_epi64(_mm256_xor_si256(a, mask), mask)
         }
-        for Sse41(a: __m128i) -> __m128i {
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::abs, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi64(_mm_setzero_si128(), a);
             _mm_sub_epi64(_mm_xor_si128(a, mask), mask)
         }
+        for Sse41(a: __m128i) -> __m128i {
+            let mask = Ops::<Sse2, i64>::gt(_mm_setzero_si128(), a);
+            _mm_sub_epi64(_mm_xor_si128(a, mask), mask)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::abs(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
-            let mask = _mm_cmpgt_epi64(_mm_setzero_si128(), a);
+            let mask = Ops::<Sse2, i64>::gt(_mm_setzero_si128(), a);
             _mm_sub_epi64(_mm_xor_si128(a, mask), mask)
         }
         for Scalar(a: i64) -> i64 {
@@ -181,14 +274,30 @@ impl_op! {
 
 impl_op! {
     fn eq<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpeq_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::eq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpeq_epi64(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::eq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::eq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi64(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::eq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            _mm_cmpeq_epi64(a, b)
+            let eq = _mm_cmpeq_epi32(a, b);
+            _mm_and_si128(eq, _mm_shuffle_epi32(eq, 0b10_11_00_01))
         }
         for Scalar(a: i64, b: i64) -> i64 {
             if a == b {
@@ -208,16 +317,31 @@ impl_op! {
 
 impl_op! {
     fn neq<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpneq_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::neq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let eq = _mm256_cmpeq_epi64(a, b);
             _mm256_xor_si256(eq, _mm256_set1_epi64x(u64::MAX as i64))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::neq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::neq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi64(a, b);
             _mm_xor_si128(eq, _mm_set1_epi64x(u64::MAX as i64))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::neq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let eq = _mm_cmpeq_epi64(a, b);
+            let eq = Ops::<Sse2, i64>::eq(a, b);
             _mm_xor_si128(eq, _mm_set1_epi64x(u64::MAX as i64))
         }
         for Scalar(a: i64, b: i64) -> i64 {
@@ -238,21 +362,38 @@ impl_op! {
 
 impl_op! {
     fn lt<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmplt_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::lt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi64(a, b);
             let eq = _mm256_cmpeq_epi64(a, b);
             _mm256_andnot_si256(_mm256_or_si256(gt, eq), _mm256_set1_epi64x(u64::MAX as i64))
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi64(a, b);
             let eq = _mm_cmpeq_epi64(a, b);
             _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi64x(u64::MAX as i64))
         }
-        for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let gt = _mm_cmpgt_epi64(a, b);
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let gt = Ops::<Sse2, i64>::gt(a, b);
             let eq = _mm_cmpeq_epi64(a, b);
             _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi64x(u64::MAX as i64))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::lt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let gt = Ops::<Sse2, i64>::gt(a, b);
+            let eq = Ops::<Sse2, i64>::eq(a, b);
+            _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi64x(u64::MAX as i64))
+        }
         for Scalar(a: i64, b: i64) -> i64 {
             if a < b {
                 u64::MAX as i64
@@ -271,16 +412,32 @@ impl_op! {
 
 impl_op! {
     fn lte<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmple_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::lte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi64(a, b);
             _mm256_xor_si256(gt, _mm256_set1_epi64x(u64::MAX as i64))
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi64(a, b);
             _mm_xor_si128(gt, _mm_set1_epi64x(u64::MAX as i64))
         }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let gt = Ops::<Sse2, i64>::gt(a, b);
+            _mm_xor_si128(gt, _mm_set1_epi64x(u64::MAX as i64))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::lte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let gt = _mm_cmpgt_epi64(a, b);
+            let gt = Ops::<Sse2, i64>::gt(a, b);
             _mm_xor_si128(gt, _mm_set1_epi64x(u64::MAX as i64))
         }
         for Scalar(a: i64, b: i64) -> i64 {
@@ -301,14 +458,31 @@ impl_op! {
 
 impl_op! {
     fn gt<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpgt_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::gt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpgt_epi64(a, b)
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi64(a, b)
         }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gt(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            _mm_cmpgt_epi64(a, b)
+            let eq_hi = _mm_and_si128(_mm_cmpeq_epi32(a, b), _mm_sub_epi64(b, a));
+            let gt = _mm_or_si128(eq_hi, _mm_cmpgt_epi32(a, b));
+            _mm_shuffle_epi32(gt, 0b11_11_01_01)
         }
         for Scalar(a: i64, b: i64) -> i64 {
             if a > b {
@@ -328,21 +502,38 @@ impl_op! {
 
 impl_op! {
     fn gte<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpge_epi64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::gte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi64(a, b);
             let eq = _mm256_cmpeq_epi64(a, b);
             _mm256_or_si256(gt, eq)
         }
-        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, i64>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi64(a, b);
             let eq = _mm_cmpeq_epi64(a, b);
             _mm_or_si128(gt, eq)
         }
-        for Sse2(a: __m128i, b: __m128i) -> __m128i {
-            let gt = _mm_cmpgt_epi64(a, b);
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let gt = Ops::<Sse2, i64>::gt(a, b);
             let eq = _mm_cmpeq_epi64(a, b);
             _mm_or_si128(gt, eq)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let gt = Ops::<Sse2, i64>::gt(a, b);
+            let eq = Ops::<Sse2, i64>::eq(a, b);
+            _mm_or_si128(gt, eq)
+        }
         for Scalar(a: i64, b: i64) -> i64 {
             if a >= b {
                 u64::MAX as i64
@@ -361,12 +552,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<i64> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            _mm512_mask_blend_epi64(_mm512_movepi64_mask(mask), a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
             _mm256_blendv_epi8(a, b, mask)
         }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::blendv, a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_blendv_epi8(a, b, mask)
         }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
@@ -388,12 +594,28 @@ impl_op! {
 
 impl_op! {
     fn shl<i64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_sll_epi64(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shl(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_sll_epi64(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i64>::shl(a_lo, rhs), Ops::<Sse41, i64>::shl(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, b: i32) -> __m128i {
+            Ops::<Sse41, i64>::shl(a, b)
+        }
         for Sse41(a: __m128i, b: i32) -> __m128i {
             _mm_sll_epi64(a, _mm_cvtsi32_si128(b))
         }
+        for Ssse3(a: __m128i, b: i32) -> __m128i {
+            Ops::<Sse2, i64>::shl(a, b)
+        }
         for Sse2(a: __m128i, b: i32) -> __m128i {
             _mm_sll_epi64(a, _mm_cvtsi32_si128(b))
         }
@@ -412,12 +634,28 @@ impl_op! {
 
 impl_op! {
     fn shr<i64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            _mm512_srl_epi64(a, _mm_cvtsi32_si128(rhs))
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shr(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             _mm256_srl_epi64(a, _mm_cvtsi32_si128(rhs))
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i64>::shr(a_lo, rhs), Ops::<Sse41, i64>::shr(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shr(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi64(a, _mm_cvtsi32_si128(rhs))
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shr(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             _mm_srl_epi64(a, _mm_cvtsi32_si128(rhs))
         }
@@ -436,12 +674,27 @@ impl_op! {
 
 impl_imm8_op! {
     fn shl_const<i64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shl(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_slli_epi64(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::shl_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_slli_epi64(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_slli_epi64(a, BY)
         }
@@ -459,12 +712,27 @@ impl_imm8_op! {
 
 impl_imm8_op! {
     fn shr_const<i64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shr(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_srli_epi64(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::shr_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_srli_epi64(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             _mm_srli_epi64(a, BY)
         }
@@ -480,8 +748,268 @@ impl_imm8_op! {
     }
 }
 
+impl_op! {
+    fn shl_var<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sllv_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_sllv_epi64(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::shl_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let low = _mm_sll_epi64(a, b);
+            let high = _mm_sll_epi64(a, _mm_unpackhi_epi64(b, b));
+            _mm_castpd_si128(_mm_move_sd(_mm_castsi128_pd(high), _mm_castsi128_pd(low)))
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vshlq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let low = i64x2_shl(a, i64x2_extract_lane::<0>(b) as u32);
+            let high = i64x2_shl(a, i64x2_extract_lane::<1>(b) as u32);
+            i64x2_shuffle::<0, 3>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srav_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+            _mm256_xor_si256(Self::shr_logical(_mm256_xor_si256(a, sign), b), sign)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::shr_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = _mm_shuffle_epi32::<0b11_11_01_01>(_mm_srai_epi32::<31>(a));
+            _mm_xor_si128(Self::shr_logical(_mm_xor_si128(a, sign), b), sign)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vshlq_s64(a, vnegq_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let low = i64x2_shr(a, i64x2_extract_lane::<0>(b) as u32);
+            let high = i64x2_shr(a, i64x2_extract_lane::<1>(b) as u32);
+            i64x2_shuffle::<0, 3>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_srlv_epi64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_srlv_epi64(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::shr_logical, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let low = _mm_srl_epi64(a, b);
+            let high = _mm_srl_epi64(a, _mm_unpackhi_epi64(b, b));
+            _mm_castpd_si128(_mm_move_sd(_mm_castsi128_pd(high), _mm_castsi128_pd(low)))
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            (a as u64).wrapping_shr(b as u32) as i64
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vreinterpretq_s64_u64(vshlq_u64(vreinterpretq_u64_s64(a), vnegq_s64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift by each lane's count separately, then take one lane from each result.
+            let low = u64x2_shr(a, i64x2_extract_lane::<0>(b) as u32);
+            let high = u64x2_shr(a, i64x2_extract_lane::<1>(b) as u32);
+            i64x2_shuffle::<0, 3>(low, high)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_sad_epu8(Ops::<Avx512, i8>::count_ones(a), _mm512_setzero_si512())
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            _mm256_sad_epu8(Ops::<Avx2, i8>::count_ones(a), _mm256_setzero_si256())
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::count_ones, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i64>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            _mm_sad_epu8(Ops::<Ssse3, i8>::count_ones(a), _mm_setzero_si128())
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            _mm_sad_epu8(Ops::<Sse2, i8>::count_ones(a), _mm_setzero_si128())
+        }
+        for Scalar(a: i64) -> i64 {
+            a.count_ones() as i64
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            let bytes = vcntq_u8(vreinterpretq_u8_s64(a));
+            vreinterpretq_s64_u64(vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(bytes))))
+        }
+        for Wasm(a: v128) -> v128 {
+            // There is no pairwise add of 32 bit lanes, so add the two halves of each lane by hand.
+            let halves = Ops::<Wasm, i32>::count_ones(a);
+            i64x2_add(v128_and(halves, i64x2_splat(0xFFFF_FFFF)), u64x2_shr(halves, 32))
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Smear the highest set bit into the bits below it, then count the bits left above it.
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<1>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<2>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<4>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<8>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<16>(a));
+            let a = _mm512_or_si512(a, _mm512_srli_epi64::<32>(a));
+            Self::count_ones(_mm512_xor_si512(a, _mm512_set1_epi8(-1)))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<1>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<2>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<4>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<8>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<16>(a));
+            let a = _mm256_or_si256(a, _mm256_srli_epi64::<32>(a));
+            Self::count_ones(_mm256_xor_si256(a, _mm256_set1_epi8(-1)))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i64>::leading_zeros, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i64>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi64::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<8>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<16>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<32>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, _mm_srli_epi64::<1>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<2>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<4>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<8>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<16>(a));
+            let a = _mm_or_si128(a, _mm_srli_epi64::<32>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Scalar(a: i64) -> i64 {
+            a.leading_zeros() as i64
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            // There is no 64 bit count leading zeros, so smear the highest set bit into the bits below
+            // it and count the bits left above it.
+            let a = vreinterpretq_u64_s64(a);
+            let a = vorrq_u64(a, vshrq_n_u64(a, 1));
+            let a = vorrq_u64(a, vshrq_n_u64(a, 2));
+            let a = vorrq_u64(a, vshrq_n_u64(a, 4));
+            let a = vorrq_u64(a, vshrq_n_u64(a, 8));
+            let a = vorrq_u64(a, vshrq_n_u64(a, 16));
+            let a = vorrq_u64(a, vshrq_n_u64(a, 32));
+            Self::count_ones(vreinterpretq_s64_u32(vmvnq_u32(vreinterpretq_u32_u64(a))))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u64x2_shr(a, 1));
+            let a = v128_or(a, u64x2_shr(a, 2));
+            let a = v128_or(a, u64x2_shr(a, 4));
+            let a = v128_or(a, u64x2_shr(a, 8));
+            let a = v128_or(a, u64x2_shr(a, 16));
+            let a = v128_or(a, u64x2_shr(a, 32));
+            Self::count_ones(v128_not(a))
+        }
+    }
+}
+
 impl_op! {
     fn cast_f64<i64> {
+        for Avx512(a: __m512i) -> __m512d {
+            _mm512_cvtepi64_pd(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256d {
+            Ops::<Avx2, i64>::cast_f64(a)
+        }
         for Avx2(a: __m256i) -> __m256d {
             let arr = core::mem::transmute::<__m256i, [i64; 4]>(a);
             let result = [
@@ -492,6 +1020,13 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m256d>(result)
         }
+        for Avx(a: __m256i) -> __m256d {
+            let (lo, hi) = split_m256i!(a);
+            _mm256_set_m128d(Ops::<Sse41, i64>::cast_f64(hi), Ops::<Sse41, i64>::cast_f64(lo))
+        }
+        for Sse42(a: __m128i) -> __m128d {
+            Ops::<Sse41, i64>::cast_f64(a)
+        }
         for Sse41(a: __m128i) -> __m128d {
             let arr = core::mem::transmute::<__m128i, [i64; 2]>(a);
             let result = [
@@ -500,6 +1035,9 @@ impl_op! {
             ];
             core::mem::transmute::<_, __m128d>(result)
         }
+        for Ssse3(a: __m128i) -> __m128d {
+            Ops::<Sse2, i64>::cast_f64(a)
+        }
         for Sse2(a: __m128i) -> __m128d {
             let arr = core::mem::transmute::<__m128i, [i64; 2]>(a);
             let result = [
@@ -527,12 +1065,27 @@ impl_op! {
 
 impl_op! {
     fn bitcast_f64<i64> {
+        for Avx512(a: __m512i) -> __m512d {
+            _mm512_castsi512_pd(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256d {
+            Ops::<Avx2, i64>::bitcast_f64(a)
+        }
         for Avx2(a: __m256i) -> __m256d {
             _mm256_castsi256_pd(a)
         }
+        for Avx(a: __m256i) -> __m256d {
+            _mm256_castsi256_pd(a)
+        }
+        for Sse42(a: __m128i) -> __m128d {
+            Ops::<Sse41, i64>::bitcast_f64(a)
+        }
         for Sse41(a: __m128i) -> __m128d {
             _mm_castsi128_pd(a)
         }
+        for Ssse3(a: __m128i) -> __m128d {
+            Ops::<Sse2, i64>::bitcast_f64(a)
+        }
         for Sse2(a: __m128i) -> __m128d {
             _mm_castsi128_pd(a)
         }
@@ -550,6 +1103,12 @@ impl_op! {
 
 impl_op! {
     fn horizontal_add<i64> {
+        for Avx512(val: __m512i) -> i64 {
+            _mm512_reduce_add_epi64(val)
+        }
+        for Avx2Fma(val: __m256i) -> i64 {
+            Ops::<Avx2, i64>::horizontal_add(val)
+        }
         for Avx2(val: __m256i) -> i64 {
             let a = val;
             let b = _mm256_permute4x64_epi64(a, 0b00_01_10_11); // Shuffle [0, 1, 2, 3]
@@ -558,11 +1117,21 @@ impl_op! {
             let val2 = _mm256_extract_epi64(c, 1);
             val1.wrapping_add(val2)
         }
+        for Avx(val: __m256i) -> i64 {
+            let (lo, hi) = split_m256i!(val);
+            Ops::<Sse41, i64>::horizontal_add(lo).wrapping_add(Ops::<Sse41, i64>::horizontal_add(hi))
+        }
+        for Sse42(val: __m128i) -> i64 {
+            Ops::<Sse41, i64>::horizontal_add(val)
+        }
         for Sse41(val: __m128i) -> i64 {
             let first = _mm_cvtsi128_si64(val);
             let second = _mm_cvtsi128_si64(_mm_shuffle_epi32(val, 0b_01_00_11_10));
             first.wrapping_add(second)
         }
+        for Ssse3(val: __m128i) -> i64 {
+            Ops::<Sse2, i64>::horizontal_add(val)
+        }
         for Sse2(val: __m128i) -> i64 {
             let first = _mm_cvtsi128_si64(val);
             let second = _mm_cvtsi128_si64(_mm_shuffle_epi32(val, 0b_01_00_11_10));
@@ -587,12 +1156,27 @@ impl_op! {
 
 impl_op! {
     fn zeroes<i64> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, i64>::zeroes()
+        }
         for Avx2() -> __m256i {
             _mm256_setzero_si256()
         }
+        for Avx() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, i64>::zeroes()
+        }
         for Sse41() -> __m128i {
             _mm_setzero_si128()
         }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, i64>::zeroes()
+        }
         for Sse2() -> __m128i {
             _mm_setzero_si128()
         }
@@ -610,12 +1194,27 @@ impl_op! {
 
 impl_op! {
     fn set1<i64> {
+        for Avx512(val: i64) -> __m512i {
+            _mm512_set1_epi64(val)
+        }
+        for Avx2Fma(val: i64) -> __m256i {
+            Ops::<Avx2, i64>::set1(val)
+        }
         for Avx2(val: i64) -> __m256i {
             _mm256_set1_epi64x(val)
         }
+        for Avx(val: i64) -> __m256i {
+            _mm256_set1_epi64x(val)
+        }
+        for Sse42(val: i64) -> __m128i {
+            Ops::<Sse41, i64>::set1(val)
+        }
         for Sse41(val: i64) -> __m128i {
             _mm_set1_epi64x(val)
         }
+        for Ssse3(val: i64) -> __m128i {
+            Ops::<Sse2, i64>::set1(val)
+        }
         for Sse2(val: i64) -> __m128i {
             _mm_set1_epi64x(val)
         }
@@ -633,12 +1232,27 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<i64> {
+        for Avx512(ptr: *const i64) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i64) -> __m256i {
+            Ops::<Avx2, i64>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const i64) -> __m256i {
             _mm256_loadu_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i64) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i64) -> __m128i {
+            Ops::<Sse41, i64>::load_unaligned(ptr)
+        }
         for Sse41(ptr: *const i64) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i64) -> __m128i {
+            Ops::<Sse2, i64>::load_unaligned(ptr)
+        }
         for Sse2(ptr: *const i64) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
@@ -656,12 +1270,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<i64> {
+        for Avx512(ptr: *const i64) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i64) -> __m256i {
+            Ops::<Avx2, i64>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const i64) -> __m256i {
             _mm256_load_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i64) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i64) -> __m128i {
+            Ops::<Sse41, i64>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const i64) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i64) -> __m128i {
+            Ops::<Sse2, i64>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const i64) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
@@ -679,12 +1308,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<i64> {
+        for Avx512(ptr: *mut i64, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i64, a: __m256i) {
+            Ops::<Avx2, i64>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut i64, a: __m256i) {
             _mm256_storeu_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i64, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i64, a: __m128i) {
+            Ops::<Sse41, i64>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut i64, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i64, a: __m128i) {
+            Ops::<Sse2, i64>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut i64, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
@@ -702,12 +1346,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<i64> {
+        for Avx512(ptr: *mut i64, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i64, a: __m256i) {
+            Ops::<Avx2, i64>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut i64, a: __m256i) {
             _mm256_store_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i64, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i64, a: __m128i) {
+            Ops::<Sse41, i64>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut i64, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i64, a: __m128i) {
+            Ops::<Sse2, i64>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut i64, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
@@ -722,3 +1381,731 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn get_mask<i64> {
+        for Avx512(val: __m512i) -> u64 {
+            _mm512_movepi64_mask(val) as u64
+        }
+        for Avx2Fma(val: __m256i) -> u64 {
+            Ops::<Avx2, i64>::get_mask(val)
+        }
+        for Avx2(val: __m256i) -> u64 {
+            Ops::<Avx, i64>::get_mask(val)
+        }
+        for Avx(val: __m256i) -> u64 {
+            _mm256_movemask_pd(_mm256_castsi256_pd(val)) as u64
+        }
+        for Sse42(val: __m128i) -> u64 {
+            Ops::<Sse41, i64>::get_mask(val)
+        }
+        for Sse41(val: __m128i) -> u64 {
+            Ops::<Sse2, i64>::get_mask(val)
+        }
+        for Ssse3(val: __m128i) -> u64 {
+            Ops::<Sse2, i64>::get_mask(val)
+        }
+        for Sse2(val: __m128i) -> u64 {
+            _mm_movemask_pd(_mm_castsi128_pd(val)) as u64
+        }
+        for Scalar(val: i64) -> u64 {
+            (val as u64) >> 63
+        }
+        for Neon(val: int64x2_t) -> u64 {
+            // Move each sign bit to the bottom of its lane, then shift it into its bit position.
+            let signs = vshrq_n_u64(vreinterpretq_u64_s64(val), 63);
+            let shifts: [i64; 2] = [0, 1];
+            vaddvq_u64(vshlq_u64(signs, vld1q_s64(shifts.as_ptr()))) as u64
+        }
+        for Wasm(val: v128) -> u64 {
+            i64x2_bitmask(val) as u64
+        }
+    }
+}
+
+impl_op! {
+    fn gather_unchecked<i64> {
+        for Avx512(base: *const i64, indices: __m512i) -> __m512i {
+            _mm512_i64gather_epi64::<8>(indices, base)
+        }
+        for Avx2Fma(base: *const i64, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const i64, indices: __m256i) -> __m256i {
+            _mm256_i64gather_epi64(base, indices, 8)
+        }
+        for Avx(base: *const i64, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i64; 4]>(indices);
+            core::mem::transmute::<[i64; 4], __m256i>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Sse42(base: *const i64, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const i64, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gather_unchecked(base, indices)
+        }
+        for Ssse3(base: *const i64, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const i64, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i64; 2]>(indices);
+            core::mem::transmute::<[i64; 2], __m128i>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Scalar(base: *const i64, indices: i64) -> i64 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const i64, indices: int64x2_t) -> int64x2_t {
+            let indices = core::mem::transmute::<int64x2_t, [i64; 2]>(indices);
+            core::mem::transmute::<[i64; 2], int64x2_t>(indices.map(|i| *base.add(i as usize)))
+        }
+        for Wasm(base: *const i64, indices: v128) -> v128 {
+            let indices = core::mem::transmute::<v128, [i64; 2]>(indices);
+            core::mem::transmute::<[i64; 2], v128>(indices.map(|i| *base.add(i as usize)))
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<i64> {
+        for Avx512(base: *mut i64, indices: __m512i, val: __m512i) {
+            _mm512_i64scatter_epi64::<8>(base, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<i64> {
+        for Avx512(base: *mut i64, indices: __m512i, val: __m512i, mask: __mmask8) {
+            _mm512_mask_i64scatter_epi64::<8>(base, mask, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<i64> {
+        for Avx512(ptr: *const i64, mask: __mmask8) -> __m512i {
+            _mm512_maskz_loadu_epi64(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const i64, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const i64, mask: __m256i) -> __m256i {
+            _mm256_maskload_epi64(ptr, mask)
+        }
+        for Avx(ptr: *const i64, mask: __m256i) -> __m256i {
+            _mm256_castpd_si256(_mm256_maskload_pd(ptr as *const f64, mask))
+        }
+        for Sse42(ptr: *const i64, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const i64, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const i64, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const i64, mask: __m128i) -> __m128i {
+            load_masked_emulated!(ptr, Ops::<Sse2, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+        for Scalar(ptr: *const i64, mask: i64) -> i64 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const i64, mask: int64x2_t) -> int64x2_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+        for Wasm(ptr: *const i64, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<i64> {
+        for Avx512(ptr: *mut i64, a: __m512i, mask: __mmask8) {
+            _mm512_mask_storeu_epi64(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut i64, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i64>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut i64, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_epi64(ptr, mask, a)
+        }
+        for Avx(ptr: *mut i64, a: __m256i, mask: __m256i) {
+            _mm256_maskstore_pd(ptr as *mut f64, mask, _mm256_castsi256_pd(a))
+        }
+        for Sse42(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i64>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i64>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i64>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i64, a: __m128i, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+        for Scalar(ptr: *mut i64, a: i64, mask: i64) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut i64, a: int64x2_t, mask: int64x2_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+        for Wasm(ptr: *mut i64, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i64>::mask_to_bitmask(mask), [i64; 2])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i64> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            _mm512_permutexvar_epi64(indices, a)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            let low = _mm256_slli_epi64::<1>(_mm256_and_si256(indices, _mm256_set1_epi64x(3)));
+            let pairs = _mm256_or_si256(low, _mm256_slli_epi64::<32>(low));
+            let pairs = _mm256_add_epi32(pairs, _mm256_set1_epi64x(1 << 32));
+            _mm256_permutevar8x32_epi32(a, pairs)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i64; 4]>(indices);
+            let values = core::mem::transmute::<__m256i, [i64; 4]>(a);
+            core::mem::transmute::<[i64; 4], __m256i>(indices.map(|i| values[i as usize & 3]))
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi64x(1));
+            let low = _mm_slli_epi64::<3>(lanes);
+            let low = _mm_or_si128(low, _mm_slli_epi64::<8>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi64::<16>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi64::<32>(low));
+            let bytes = _mm_add_epi64(low, _mm_set1_epi64x(0x0706050403020100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            let lanes = _mm_and_si128(indices, _mm_set1_epi64x(1));
+            let low = _mm_slli_epi64::<3>(lanes);
+            let low = _mm_or_si128(low, _mm_slli_epi64::<8>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi64::<16>(low));
+            let low = _mm_or_si128(low, _mm_slli_epi64::<32>(low));
+            let bytes = _mm_add_epi64(low, _mm_set1_epi64x(0x0706050403020100));
+            _mm_shuffle_epi8(a, bytes)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i64; 2]>(indices);
+            let values = core::mem::transmute::<__m128i, [i64; 2]>(a);
+            core::mem::transmute::<[i64; 2], __m128i>(indices.map(|i| values[i as usize & 1]))
+        }
+        for Scalar(a: i64, indices: i64) -> i64 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int64x2_t, indices: int64x2_t) -> int64x2_t {
+            let lanes = vandq_u64(vreinterpretq_u64_s64(indices), vdupq_n_u64(1));
+            let low = vreinterpretq_u32_u64(vshlq_n_u64::<3>(lanes));
+            let low = vtrn1q_u32(low, low);
+            let offsets = vreinterpretq_u32_u64(vdupq_n_u64(0x0706050403020100));
+            let bytes = vreinterpretq_u8_u32(vmlaq_n_u32(offsets, low, 0x01010101));
+            vreinterpretq_s64_s8(vqtbl1q_s8(vreinterpretq_s8_s64(a), bytes))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            let low = i64x2_shl(v128_and(indices, i64x2_splat(1)), 3);
+            let bytes = i64x2_add(
+                i64x2_mul(low, i64x2_splat(0x0101010101010101)),
+                i64x2_splat(0x0706050403020100),
+            );
+            i8x16_swizzle(a, bytes)
+        }
+    }
+}
+
+impl_op! {
+    fn shuffle<i64> {
+        for Avx512(a: __m512i, indices: [i64; 8]) -> __m512i {
+            Ops::<Avx512, i64>::permute(a, core::mem::transmute::<[i64; 8], __m512i>(indices))
+        }
+        for Avx2Fma(a: __m256i, indices: [i64; 4]) -> __m256i {
+            Ops::<Avx2, i64>::shuffle(a, indices)
+        }
+        for Avx2(a: __m256i, indices: [i64; 4]) -> __m256i {
+            Ops::<Avx2, i32>::shuffle(a, split_lane_indices!(indices, 2, i32))
+        }
+        for Avx(a: __m256i, indices: [i64; 4]) -> __m256i {
+            Ops::<Avx, i32>::shuffle(a, split_lane_indices!(indices, 2, i32))
+        }
+        for Sse42(a: __m128i, indices: [i64; 2]) -> __m128i {
+            Ops::<Sse41, i64>::shuffle(a, indices)
+        }
+        for Sse41(a: __m128i, indices: [i64; 2]) -> __m128i {
+            Ops::<Sse2, i64>::shuffle(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: [i64; 2]) -> __m128i {
+            Ops::<Sse2, i64>::shuffle(a, indices)
+        }
+        for Sse2(a: __m128i, indices: [i64; 2]) -> __m128i {
+            Ops::<Sse2, i32>::shuffle(a, split_lane_indices!(indices, 2, i32))
+        }
+        for Scalar(a: i64, indices: [i64; 1]) -> i64 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int64x2_t, indices: [i64; 2]) -> int64x2_t {
+            let indices = split_lane_indices!(indices, 8, i8);
+            let bytes = Ops::<Neon, i8>::shuffle(vreinterpretq_s8_s64(a), indices);
+            vreinterpretq_s64_s8(bytes)
+        }
+        for Wasm(a: v128, indices: [i64; 2]) -> v128 {
+            Ops::<Wasm, i8>::shuffle(a, split_lane_indices!(indices, 8, i8))
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i64; 8], __m512i>([0, 8, 1, 9, 2, 10, 3, 11]);
+            _mm512_permutex2var_epi64(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi64(a, b);
+            let hi = _mm256_unpackhi_epi64(a, b);
+            _mm256_permute2x128_si256::<0x20>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, _) = split_m256i!(a);
+            let (b_lo, _) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i64>::zip_lo(a_lo, b_lo),
+                Ops::<Sse41, i64>::zip_hi(a_lo, b_lo)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpacklo_epi64(a, b)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            let _ = b;
+            a
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vzip1q_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i64x2_shuffle::<0, 2>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let indices = core::mem::transmute::<[i64; 8], __m512i>([4, 12, 5, 13, 6, 14, 7, 15]);
+            _mm512_permutex2var_epi64(a, indices, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi64(a, b);
+            let hi = _mm256_unpackhi_epi64(a, b);
+            _mm256_permute2x128_si256::<0x31>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (_, a_hi) = split_m256i!(a);
+            let (_, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i64>::zip_lo(a_hi, b_hi),
+                Ops::<Sse41, i64>::zip_hi(a_hi, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpackhi_epi64(a, b)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            let _ = a;
+            b
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vzip2q_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i64x2_shuffle::<1, 3>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let even = core::mem::transmute::<[i64; 8], __m512i>([0, 2, 4, 6, 8, 10, 12, 14]);
+            let odd = core::mem::transmute::<[i64; 8], __m512i>([1, 3, 5, 7, 9, 11, 13, 15]);
+            (_mm512_permutex2var_epi64(a, even, b), _mm512_permutex2var_epi64(a, odd, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i64>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            // Unzip each 128 bit half, then gather the halves coming from the same input.
+            let even = _mm256_unpacklo_epi64(a, b);
+            let odd = _mm256_unpackhi_epi64(a, b);
+            (_mm256_permute4x64_epi64::<0b11_01_10_00>(even), _mm256_permute4x64_epi64::<0b11_01_10_00>(odd))
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            let (a_even, a_odd) = Ops::<Sse41, i64>::unzip(a_lo, a_hi);
+            let (b_even, b_odd) = Ops::<Sse41, i64>::unzip(b_lo, b_hi);
+            (join_m128i!(a_even, b_even), join_m128i!(a_odd, b_odd))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i64>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i64>::unzip(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i64>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            (_mm_unpacklo_epi64(a, b), _mm_unpackhi_epi64(a, b))
+        }
+        for Scalar(a: i64, b: i64) -> (i64, i64) {
+            (a, b)
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> (int64x2_t, int64x2_t) {
+            (vuzp1q_s64(a, b), vuzp2q_s64(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let even = i64x2_shuffle::<0, 2>(a, b);
+            let odd = i64x2_shuffle::<1, 3>(a, b);
+            (even, odd)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t) {
+            let loaded = vld2q_s64(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t) {
+            vst2q_s64(ptr, int64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t, int64x2_t) {
+            let loaded = vld3q_s64(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t, c: int64x2_t) {
+            vst3q_s64(ptr, int64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<i64> {
+        for Neon(ptr: *const i64) -> (int64x2_t, int64x2_t, int64x2_t, int64x2_t) {
+            let loaded = vld4q_s64(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i64> {
+        for Neon(ptr: *mut i64, a: int64x2_t, b: int64x2_t, c: int64x2_t, d: int64x2_t) {
+            vst4q_s64(ptr, int64x2x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            let a = _mm512_add_epi64(a, _mm512_alignr_epi64::<7>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi64(a, _mm512_alignr_epi64::<6>(a, _mm512_setzero_si512()));
+            _mm512_add_epi64(a, _mm512_alignr_epi64::<4>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi64(a, _mm256_alignr_epi8::<8>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            _mm256_add_epi64(a, carry)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let lo = Ops::<Sse41, i64>::prefix_sum(lo);
+            let hi = Ops::<Sse41, i64>::prefix_sum(hi);
+            // Add the total of the lower half, held in its last lane, to every lane of the upper half.
+            join_m128i!(lo, _mm_add_epi64(hi, _mm_shuffle_epi32::<0xEE>(lo)))
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            _mm_add_epi64(a, _mm_slli_si128::<8>(a))
+        }
+        for Scalar(a: i64) -> i64 {
+            a
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            vaddq_s64(a, vextq_s64::<1>(vdupq_n_s64(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            i64x2_add(a, i64x2_shuffle::<2, 0>(a, i64x2_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<i64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::prefix_sum(_mm512_alignr_epi64::<7>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            Ops::<Avx2, i64>::prefix_sum(_mm256_alignr_epi8::<8>(a, carry))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let a = join_m128i!(_mm_slli_si128::<8>(lo), _mm_alignr_epi8::<8>(hi, lo));
+            Ops::<Avx, i64>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::prefix_sum(_mm_slli_si128::<8>(a))
+        }
+        for Scalar(a: i64) -> i64 {
+            let _ = a;
+            0
+        }
+        for Neon(a: int64x2_t) -> int64x2_t {
+            Ops::<Neon, i64>::prefix_sum(vextq_s64::<1>(vdupq_n_s64(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::prefix_sum(i64x2_shuffle::<2, 0>(a, i64x2_splat(0)))
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_saturating_i32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let a = _mm512_cvtsepi64_epi32(a);
+            let b = _mm512_cvtsepi64_epi32(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(a), b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::narrow_saturating_i32(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Clamp to the narrow range, then keep the low half of every lane.
+            let (min, max) = (_mm256_set1_epi64x(i32::MIN as i64), _mm256_set1_epi64x(i32::MAX as i64));
+            let a = Ops::<Avx2, i64>::min(Ops::<Avx2, i64>::max(a, min), max);
+            let b = Ops::<Avx2, i64>::min(Ops::<Avx2, i64>::max(b, min), max);
+            Ops::<Avx2, i32>::unzip(a, b).0
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (min, max) = (_mm256_set1_epi64x(i32::MIN as i64), _mm256_set1_epi64x(i32::MAX as i64));
+            let a = Ops::<Avx, i64>::min(Ops::<Avx, i64>::max(a, min), max);
+            let b = Ops::<Avx, i64>::min(Ops::<Avx, i64>::max(b, min), max);
+            Ops::<Avx, i32>::unzip(a, b).0
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::narrow_saturating_i32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let (min, max) = (_mm_set1_epi64x(i32::MIN as i64), _mm_set1_epi64x(i32::MAX as i64));
+            let a = Ops::<Sse41, i64>::min(Ops::<Sse41, i64>::max(a, min), max);
+            let b = Ops::<Sse41, i64>::min(Ops::<Sse41, i64>::max(b, min), max);
+            Ops::<Sse41, i32>::unzip(a, b).0
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::narrow_saturating_i32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let (min, max) = (_mm_set1_epi64x(i32::MIN as i64), _mm_set1_epi64x(i32::MAX as i64));
+            let a = Ops::<Sse2, i64>::min(Ops::<Sse2, i64>::max(a, min), max);
+            let b = Ops::<Sse2, i64>::min(Ops::<Sse2, i64>::max(b, min), max);
+            Ops::<Sse2, i32>::unzip(a, b).0
+        }
+        for Scalar(a: i64, _b: i64) -> i32 {
+            a.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int32x4_t {
+            vcombine_s32(vqmovn_s64(a), vqmovn_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let (min, max) = (i64x2_splat(i32::MIN as i64), i64x2_splat(i32::MAX as i64));
+            let a = Ops::<Wasm, i64>::min(Ops::<Wasm, i64>::max(a, min), max);
+            let b = Ops::<Wasm, i64>::min(Ops::<Wasm, i64>::max(b, min), max);
+            Ops::<Wasm, i32>::unzip(a, b).0
+        }
+    }
+}
+
+impl_op! {
+    fn unsigned_narrow_saturating_u32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // The conversion treats its input as unsigned, so the negative lanes are cleared first.
+            let zero = _mm512_setzero_si512();
+            let a = _mm512_cvtusepi64_epi32(_mm512_max_epi64(a, zero));
+            let b = _mm512_cvtusepi64_epi32(_mm512_max_epi64(b, zero));
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(a), b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::unsigned_narrow_saturating_u32(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Clamp to the narrow range, then keep the low half of every lane.
+            let (min, max) = (_mm256_set1_epi64x(0), _mm256_set1_epi64x(u32::MAX as i64));
+            let a = Ops::<Avx2, i64>::min(Ops::<Avx2, i64>::max(a, min), max);
+            let b = Ops::<Avx2, i64>::min(Ops::<Avx2, i64>::max(b, min), max);
+            Ops::<Avx2, i32>::unzip(a, b).0
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (min, max) = (_mm256_set1_epi64x(0), _mm256_set1_epi64x(u32::MAX as i64));
+            let a = Ops::<Avx, i64>::min(Ops::<Avx, i64>::max(a, min), max);
+            let b = Ops::<Avx, i64>::min(Ops::<Avx, i64>::max(b, min), max);
+            Ops::<Avx, i32>::unzip(a, b).0
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::unsigned_narrow_saturating_u32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let (min, max) = (_mm_set1_epi64x(0), _mm_set1_epi64x(u32::MAX as i64));
+            let a = Ops::<Sse41, i64>::min(Ops::<Sse41, i64>::max(a, min), max);
+            let b = Ops::<Sse41, i64>::min(Ops::<Sse41, i64>::max(b, min), max);
+            Ops::<Sse41, i32>::unzip(a, b).0
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::unsigned_narrow_saturating_u32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            let (min, max) = (_mm_set1_epi64x(0), _mm_set1_epi64x(u32::MAX as i64));
+            let a = Ops::<Sse2, i64>::min(Ops::<Sse2, i64>::max(a, min), max);
+            let b = Ops::<Sse2, i64>::min(Ops::<Sse2, i64>::max(b, min), max);
+            Ops::<Sse2, i32>::unzip(a, b).0
+        }
+        for Scalar(a: i64, _b: i64) -> u32 {
+            a.clamp(0, u32::MAX as i64) as u32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> uint32x4_t {
+            vcombine_u32(vqmovun_s64(a), vqmovun_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let (min, max) = (i64x2_splat(0), i64x2_splat(u32::MAX as i64));
+            let a = Ops::<Wasm, i64>::min(Ops::<Wasm, i64>::max(a, min), max);
+            let b = Ops::<Wasm, i64>::min(Ops::<Wasm, i64>::max(b, min), max);
+            Ops::<Wasm, i32>::unzip(a, b).0
+        }
+    }
+}
+
+impl_op! {
+    fn narrow_truncating_i32<i64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            let a = _mm512_cvtepi64_epi32(a);
+            let b = _mm512_cvtepi64_epi32(b);
+            _mm512_inserti64x4::<1>(_mm512_castsi256_si512(a), b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::narrow_truncating_i32(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // The low half of every lane sits at an even 32 bit index, which is what unzip gathers.
+            Ops::<Avx2, i32>::unzip(a, b).0
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::unzip(a, b).0
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::narrow_truncating_i32(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::unzip(a, b).0
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::narrow_truncating_i32(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::unzip(a, b).0
+        }
+        for Scalar(a: i64, _b: i64) -> i32 {
+            a as i32
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int32x4_t {
+            vcombine_s32(vmovn_s64(a), vmovn_s64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::unzip(a, b).0
+        }
+    }
+}
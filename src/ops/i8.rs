@@ -2,12 +2,27 @@ use super::*;
 
 impl_op! {
     fn add<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_add_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::add(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_add_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::add(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::add(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_add_epi8(a, b)
         }
@@ -25,12 +40,27 @@ impl_op! {
 
 impl_op! {
     fn sub<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_sub_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::sub(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_sub_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::sub(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::sub(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_sub_epi8(a, b)
         }
@@ -48,6 +78,16 @@ impl_op! {
 
 impl_op! {
     fn mul<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Multiply the even and odd bytes as 16 bit lanes, then merge the low bytes back.
+            let even = _mm512_mullo_epi16(a, b);
+            let odd = _mm512_mullo_epi16(_mm512_srli_epi16(a, 8), _mm512_srli_epi16(b, 8));
+            let low_bytes = _mm512_set1_epi16(0x00FF);
+            _mm512_or_si512(_mm512_slli_epi16(odd, 8), _mm512_and_si512(even, low_bytes))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mul(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let mut arr1 = core::mem::transmute::<__m256i, [i8; 32]>(a);
             let arr2 = core::mem::transmute::<__m256i, [i8; 32]>(b);
@@ -56,6 +96,12 @@ impl_op! {
             }
             core::mem::transmute::<_, _>(arr1)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::mul, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mul(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let mut arr1 = core::mem::transmute::<__m128i, [i8; 16]>(a);
             let arr2 = core::mem::transmute::<__m128i, [i8; 16]>(b);
@@ -64,6 +110,9 @@ impl_op! {
             }
             core::mem::transmute::<_, _>(arr1)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mul(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let mut arr1 = core::mem::transmute::<__m128i, [i8; 16]>(a);
             let arr2 = core::mem::transmute::<__m128i, [i8; 16]>(b);
@@ -91,12 +140,27 @@ impl_op! {
 
 impl_op! {
     fn min<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::min(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_min_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::min(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_min_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::min(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi8(a, b);
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
@@ -115,12 +179,27 @@ impl_op! {
 
 impl_op! {
     fn max<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::max(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_max_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::max(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_max_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::max(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi8(a, b);
             _mm_or_si128(_mm_and_si128(mask, a), _mm_andnot_si128(mask, b))
@@ -139,12 +218,27 @@ impl_op! {
 
 impl_op! {
     fn abs<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            _mm512_abs_epi8(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::abs(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             _mm256_abs_epi8(a)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::abs, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::abs(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             _mm_abs_epi8(a)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            _mm_abs_epi8(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             let mask = _mm_cmpgt_epi8(_mm_setzero_si128(), a);
             _mm_sub_epi8(_mm_xor_si128(a, mask), mask)
@@ -163,12 +257,27 @@ impl_op! {
 
 impl_op! {
     fn eq<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpeq_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::eq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpeq_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::eq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::eq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::eq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpeq_epi8(a, b)
         }
@@ -190,14 +299,29 @@ impl_op! {
 
 impl_op! {
     fn neq<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpneq_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::neq(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let eq = _mm256_cmpeq_epi8(a, b);
             _mm256_xor_si256(eq, _mm256_set1_epi8(u32::MAX as i8))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::neq, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::neq(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi8(a, b);
             _mm_xor_si128(eq, _mm_set1_epi8(u32::MAX as i8))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::neq(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let eq = _mm_cmpeq_epi8(a, b);
             _mm_xor_si128(eq, _mm_set1_epi8(u32::MAX as i8))
@@ -220,16 +344,31 @@ impl_op! {
 
 impl_op! {
     fn lt<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmplt_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::lt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi8(a, b);
             let eq = _mm256_cmpeq_epi8(a, b);
             _mm256_andnot_si256(_mm256_or_si256(gt, eq), _mm256_set1_epi8(u32::MAX as i8))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::lt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             let eq = _mm_cmpeq_epi8(a, b);
             _mm_andnot_si128(_mm_or_si128(gt, eq), _mm_set1_epi8(u32::MAX as i8))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::lt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             let eq = _mm_cmpeq_epi8(a, b);
@@ -253,14 +392,29 @@ impl_op! {
 
 impl_op! {
     fn lte<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmple_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::lte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi8(a, b);
             _mm256_xor_si256(gt, _mm256_set1_epi8(u32::MAX as i8))
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::lte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             _mm_xor_si128(gt, _mm_set1_epi8(u32::MAX as i8))
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::lte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             _mm_xor_si128(gt, _mm_set1_epi8(u32::MAX as i8))
@@ -283,12 +437,27 @@ impl_op! {
 
 impl_op! {
     fn gt<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpgt_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::gt(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             _mm256_cmpgt_epi8(a, b)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::gt(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi8(a, b)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::gt(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             _mm_cmpgt_epi8(a, b)
         }
@@ -310,16 +479,31 @@ impl_op! {
 
 impl_op! {
     fn gte<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpge_epi8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::gte(a, b)
+        }
         for Avx2(a: __m256i, b: __m256i) -> __m256i {
             let gt = _mm256_cmpgt_epi8(a, b);
             let eq = _mm256_cmpeq_epi8(a, b);
             _mm256_or_si256(gt, eq)
         }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::gte(a, b)
+        }
         for Sse41(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             let eq = _mm_cmpeq_epi8(a, b);
             _mm_or_si128(gt, eq)
         }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::gte(a, b)
+        }
         for Sse2(a: __m128i, b: __m128i) -> __m128i {
             let gt = _mm_cmpgt_epi8(a, b);
             let eq = _mm_cmpeq_epi8(a, b);
@@ -343,12 +527,27 @@ impl_op! {
 
 impl_op! {
     fn blendv<i8> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            _mm512_mask_blend_epi8(_mm512_movepi8_mask(mask), a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::blendv(a, b, mask)
+        }
         for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
             _mm256_blendv_epi8(a, b, mask)
         }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::blendv, a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::blendv(a, b, mask)
+        }
         for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_blendv_epi8(a, b, mask)
         }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::blendv(a, b, mask)
+        }
         for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
             _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
         }
@@ -370,6 +569,18 @@ impl_op! {
 
 impl_op! {
     fn shl<i8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            // Do 16 bit shifts, then mask out the bits that are shifted in.
+            let rhs2 = _mm_cvtsi32_si128(rhs);
+            let shifted_i16 = _mm512_sll_epi16(a, rhs2);
+
+            let mask = 0x00FFu16 >> (8 - rhs) << 8;
+            let mask = _mm512_set1_epi16(mask as i16);
+            _mm512_andnot_si512(mask, shifted_i16)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shl(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             // Do 16 bit shifts, then mask out the bits that are shifted in.
             let rhs2 = _mm_cvtsi32_si128(rhs);
@@ -379,9 +590,19 @@ impl_op! {
             let mask = _mm256_set1_epi16(mask as i16);
             _mm256_andnot_si256(mask, shifted_i16)
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i8>::shl(a_lo, rhs), Ops::<Sse41, i8>::shl(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shl(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             Ops::<Sse2, i8>::shl(a, rhs)
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shl(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             // Do 16 bit shifts, then mask out the bits that are shifted in.
             let rhs2 = _mm_cvtsi32_si128(rhs);
@@ -406,6 +627,18 @@ impl_op! {
 
 impl_op! {
     fn shr<i8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            // Do 16 bit shifts, then mask out the bits that are shifted in.
+            let rhs2 = _mm_cvtsi32_si128(rhs);
+            let shifted_i16 = _mm512_srl_epi16(a, rhs2);
+
+            let mask = 0xFF00u16 << (8 - rhs) >> 8;
+            let mask = _mm512_set1_epi16(mask as i16);
+            _mm512_andnot_si512(mask, shifted_i16)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shr(a, rhs)
+        }
         for Avx2(a: __m256i, rhs: i32) -> __m256i {
             // Do 16 bit shifts, then mask out the bits that are shifted in.
             let rhs2 = _mm_cvtsi32_si128(rhs);
@@ -415,9 +648,19 @@ impl_op! {
             let mask = _mm256_set1_epi16(mask as i16);
             _mm256_andnot_si256(mask, shifted_i16)
         }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            join_m128i!(Ops::<Sse41, i8>::shr(a_lo, rhs), Ops::<Sse41, i8>::shr(a_hi, rhs))
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shr(a, rhs)
+        }
         for Sse41(a: __m128i, rhs: i32) -> __m128i {
             Ops::<Sse2, i8>::shr(a, rhs)
         }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shr(a, rhs)
+        }
         for Sse2(a: __m128i, rhs: i32) -> __m128i {
             // Do 16 bit shifts, then mask out the bits that are shifted in.
             let rhs2 = _mm_cvtsi32_si128(rhs);
@@ -442,12 +685,27 @@ impl_op! {
 
 impl_imm8_op! {
     fn shl_const<i8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shl(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             Self::shl(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::shl_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             Self::shl(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             Self::shl(a, BY)
         }
@@ -465,12 +723,27 @@ impl_imm8_op! {
 
 impl_imm8_op! {
     fn shr_const<i8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Self::shr(a, BY)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_const::<BY>(a)
+        }
         for Avx2(a: __m256i) -> __m256i {
             Self::shr(a, BY)
         }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::shr_const::<BY>, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_const::<BY>(a)
+        }
         for Sse41(a: __m128i) -> __m128i {
             Self::shr(a, BY)
         }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_const::<BY>(a)
+        }
         for Sse2(a: __m128i) -> __m128i {
             Self::shr(a, BY)
         }
@@ -486,18 +759,298 @@ impl_imm8_op! {
     }
 }
 
+impl_op! {
+    fn shl_var<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Shift the even and odd bytes separately inside 16 bit lanes.
+            let low_bytes = _mm512_set1_epi16(0x00FF);
+            let even = _mm512_sllv_epi16(a, _mm512_and_si512(b, low_bytes));
+            let odd = _mm512_sllv_epi16(_mm512_andnot_si512(low_bytes, a), _mm512_srli_epi16::<8>(b));
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            shift_by_count_bits!(Avx2, i8, shl_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::shl_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            shift_by_count_bits!(Sse2, i8, shl_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vshlq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            shift_by_count_bits!(Wasm, i8, shl_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Shift the even and odd bytes separately inside 16 bit lanes.
+            let low_bytes = _mm512_set1_epi16(0x00FF);
+            let even = _mm512_srav_epi16(
+                _mm512_srai_epi16::<8>(_mm512_slli_epi16::<8>(a)),
+                _mm512_and_si512(b, low_bytes),
+            );
+            let odd = _mm512_srav_epi16(a, _mm512_srli_epi16::<8>(b));
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = _mm256_cmpgt_epi8(_mm256_setzero_si256(), a);
+            _mm256_xor_si256(Self::shr_logical(_mm256_xor_si256(a, sign), b), sign)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::shr_var, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = _mm_cmplt_epi8(a, _mm_setzero_si128());
+            _mm_xor_si128(Self::shr_logical(_mm_xor_si128(a, sign), b), sign)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vshlq_s8(a, vnegq_s8(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            // Shift the complement of negative lanes logically and flip them back, which shifts in
+            // copies of the sign bit.
+            let sign = i8x16_shr(a, 7);
+            v128_xor(Self::shr_logical(v128_xor(a, sign), b), sign)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Shift the even and odd bytes separately inside 16 bit lanes.
+            let low_bytes = _mm512_set1_epi16(0x00FF);
+            let even = _mm512_srlv_epi16(_mm512_and_si512(a, low_bytes), _mm512_and_si512(b, low_bytes));
+            let odd = _mm512_srlv_epi16(a, _mm512_srli_epi16::<8>(b));
+            _mm512_mask_blend_epi8(0xAAAA_AAAA_AAAA_AAAA, even, odd)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            shift_by_count_bits!(Avx2, i8, shr_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::shr_logical, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            shift_by_count_bits!(Sse2, i8, shr_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            (a as u8).wrapping_shr(b as u32) as i8
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vreinterpretq_s8_u8(vshlq_u8(vreinterpretq_u8_s8(a), vnegq_s8(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            shift_by_count_bits!(Wasm, i8, shr_const, a, b, (1, 7), (2, 6), (4, 5))
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Look up the number of ones in the low and high nibble of each byte.
+            let nibble_counts = _mm512_broadcast_i32x4(m128i_nibble_counts!());
+            let low_nibbles = _mm512_set1_epi8(0x0F);
+            let low = _mm512_shuffle_epi8(nibble_counts, _mm512_and_si512(a, low_nibbles));
+            let high = _mm512_shuffle_epi8(
+                nibble_counts,
+                _mm512_and_si512(_mm512_srli_epi16::<4>(a), low_nibbles),
+            );
+            _mm512_add_epi8(low, high)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let nibble_counts = _mm256_broadcastsi128_si256(m128i_nibble_counts!());
+            let low_nibbles = _mm256_set1_epi8(0x0F);
+            let low = _mm256_shuffle_epi8(nibble_counts, _mm256_and_si256(a, low_nibbles));
+            let high = _mm256_shuffle_epi8(
+                nibble_counts,
+                _mm256_and_si256(_mm256_srli_epi16::<4>(a), low_nibbles),
+            );
+            _mm256_add_epi8(low, high)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::count_ones, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let nibble_counts = m128i_nibble_counts!();
+            let low_nibbles = _mm_set1_epi8(0x0F);
+            let low = _mm_shuffle_epi8(nibble_counts, _mm_and_si128(a, low_nibbles));
+            let high = _mm_shuffle_epi8(
+                nibble_counts,
+                _mm_and_si128(_mm_srli_epi16::<4>(a), low_nibbles),
+            );
+            _mm_add_epi8(low, high)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            // Add up neighbouring bits, then pairs, then nibbles, masking off the bits that the
+            // 16 bit shifts move over from the next byte.
+            let pairs = _mm_sub_epi8(a, _mm_and_si128(_mm_srli_epi16::<1>(a), _mm_set1_epi8(0x55)));
+            let mask = _mm_set1_epi8(0x33);
+            let nibbles = _mm_add_epi8(
+                _mm_and_si128(pairs, mask),
+                _mm_and_si128(_mm_srli_epi16::<2>(pairs), mask),
+            );
+            _mm_and_si128(_mm_add_epi8(nibbles, _mm_srli_epi16::<4>(nibbles)), _mm_set1_epi8(0x0F))
+        }
+        for Scalar(a: i8) -> i8 {
+            a.count_ones() as i8
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vcntq_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            i8x16_popcnt(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            // Smear the highest set bit into the bits below it, then count the bits left above it.
+            let a = _mm512_or_si512(a, Ops::<Avx512, i8>::shr_const::<1>(a));
+            let a = _mm512_or_si512(a, Ops::<Avx512, i8>::shr_const::<2>(a));
+            let a = _mm512_or_si512(a, Ops::<Avx512, i8>::shr_const::<4>(a));
+            Self::count_ones(_mm512_xor_si512(a, _mm512_set1_epi8(-1)))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let a = _mm256_or_si256(a, Ops::<Avx2, i8>::shr_const::<1>(a));
+            let a = _mm256_or_si256(a, Ops::<Avx2, i8>::shr_const::<2>(a));
+            let a = _mm256_or_si256(a, Ops::<Avx2, i8>::shr_const::<4>(a));
+            Self::count_ones(_mm256_xor_si256(a, _mm256_set1_epi8(-1)))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::leading_zeros, a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, Ops::<Ssse3, i8>::shr_const::<1>(a));
+            let a = _mm_or_si128(a, Ops::<Ssse3, i8>::shr_const::<2>(a));
+            let a = _mm_or_si128(a, Ops::<Ssse3, i8>::shr_const::<4>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_or_si128(a, Ops::<Sse2, i8>::shr_const::<1>(a));
+            let a = _mm_or_si128(a, Ops::<Sse2, i8>::shr_const::<2>(a));
+            let a = _mm_or_si128(a, Ops::<Sse2, i8>::shr_const::<4>(a));
+            Self::count_ones(_mm_xor_si128(a, _mm_set1_epi8(-1)))
+        }
+        for Scalar(a: i8) -> i8 {
+            a.leading_zeros() as i8
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            vclzq_s8(a)
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = v128_or(a, u8x16_shr(a, 1));
+            let a = v128_or(a, u8x16_shr(a, 2));
+            let a = v128_or(a, u8x16_shr(a, 4));
+            Self::count_ones(v128_not(a))
+        }
+    }
+}
+
 impl_op! {
     fn extend_i16<i8> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepi8_epi16(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepi8_epi16(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i8>::extend_i16(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i8>::extend_i16(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i8>::extend_i16(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i8>::extend_i16(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             let a = _mm_cvtepi8_epi16(val);
             let b = _mm_cvtepi8_epi16(_mm_shuffle_epi32(val, 0b_01_00_11_10));
             (a, b)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i8>::extend_i16(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i8; 16]>(val);
             let a = [
@@ -540,16 +1093,36 @@ impl_op! {
 
 impl_op! {
     fn unsigned_extend_i16<i8> {
+        for Avx512(val: __m512i) -> (__m512i, __m512i) {
+            let a = _mm512_cvtepu8_epi16(_mm512_extracti64x4_epi64(val, 0));
+            let b = _mm512_cvtepu8_epi16(_mm512_extracti64x4_epi64(val, 1));
+            (a, b)
+        }
+        for Avx2Fma(val: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i8>::unsigned_extend_i16(val)
+        }
         for Avx2(val: __m256i) -> (__m256i, __m256i) {
             let a = _mm256_cvtepu8_epi16(_mm256_extracti128_si256(val, 0));
             let b = _mm256_cvtepu8_epi16(_mm256_extracti128_si256(val, 1));
             (a, b)
         }
+        for Avx(val: __m256i) -> (__m256i, __m256i) {
+            let (lo, hi) = split_m256i!(val);
+            let (lo_a, lo_b) = Ops::<Sse41, i8>::unsigned_extend_i16(lo);
+            let (hi_a, hi_b) = Ops::<Sse41, i8>::unsigned_extend_i16(hi);
+            (join_m128i!(lo_a, lo_b), join_m128i!(hi_a, hi_b))
+        }
+        for Sse42(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i8>::unsigned_extend_i16(val)
+        }
         for Sse41(val: __m128i) -> (__m128i, __m128i) {
             let a = _mm_cvtepu8_epi16(val);
             let b = _mm_cvtepu8_epi16(_mm_shuffle_epi32(val, 0b_01_00_11_10));
             (a, b)
         }
+        for Ssse3(val: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i8>::unsigned_extend_i16(val)
+        }
         for Sse2(val: __m128i) -> (__m128i, __m128i) {
             let arr = core::mem::transmute::<__m128i, [i8; 16]>(val);
             let a = [
@@ -592,19 +1165,35 @@ impl_op! {
 
 impl_op! {
     fn get_mask<i8> {
-        for Avx2(val: __m256i) -> u32 {
-            _mm256_movemask_epi8(val) as u32
+        for Avx512(val: __m512i) -> u64 {
+            _mm512_movepi8_mask(val)
+        }
+        for Avx2Fma(val: __m256i) -> u64 {
+            Ops::<Avx2, i8>::get_mask(val)
+        }
+        for Avx2(val: __m256i) -> u64 {
+            _mm256_movemask_epi8(val) as u32 as u64
+        }
+        for Avx(val: __m256i) -> u64 {
+            let (lo, hi) = split_m256i!(val);
+            Ops::<Sse41, i8>::get_mask(lo) | (Ops::<Sse41, i8>::get_mask(hi) << 16)
+        }
+        for Sse42(val: __m128i) -> u64 {
+            Ops::<Sse41, i8>::get_mask(val)
         }
-        for Sse41(val: __m128i) -> u32 {
-            _mm_movemask_epi8(val) as u32
+        for Sse41(val: __m128i) -> u64 {
+            Ops::<Sse2, i8>::get_mask(val)
         }
-        for Sse2(val: __m128i) -> u32 {
-            _mm_movemask_epi8(val) as u32
+        for Ssse3(val: __m128i) -> u64 {
+            Ops::<Sse2, i8>::get_mask(val)
         }
-        for Scalar(val: i8) -> u32 {
-            ((val as u8) & 0x1) as u32
+        for Sse2(val: __m128i) -> u64 {
+            _mm_movemask_epi8(val) as u64
         }
-        for Neon(val: int8x16_t) -> u32 {
+        for Scalar(val: i8) -> u64 {
+            ((val as u8) >> 7) as u64
+        }
+        for Neon(val: int8x16_t) -> u64 {
             let val = vreinterpretq_u8_s8(val);
 
             // Algorithm copied from here: https://stackoverflow.com/a/68694558
@@ -618,22 +1207,37 @@ impl_op! {
             out |= vaddv_u8(vget_low_u8(vmask)) as u16;
             out |= (vaddv_u8(vget_high_u8(vmask)) as u16) << 8;
 
-            out as u32
+            out as u64
         }
-        for Wasm(val: v128) -> u32 {
-            i8x16_bitmask(val) as u32
+        for Wasm(val: v128) -> u64 {
+            i8x16_bitmask(val) as u64
         }
     }
 }
 
 impl_op! {
     fn zeroes<i8> {
+        for Avx512() -> __m512i {
+            _mm512_setzero_si512()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, i8>::zeroes()
+        }
         for Avx2() -> __m256i {
             _mm256_setzero_si256()
         }
+        for Avx() -> __m256i {
+            _mm256_setzero_si256()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, i8>::zeroes()
+        }
         for Sse41() -> __m128i {
             _mm_setzero_si128()
         }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, i8>::zeroes()
+        }
         for Sse2() -> __m128i {
             _mm_setzero_si128()
         }
@@ -651,12 +1255,27 @@ impl_op! {
 
 impl_op! {
     fn set1<i8> {
+        for Avx512(val: i8) -> __m512i {
+            _mm512_set1_epi8(val)
+        }
+        for Avx2Fma(val: i8) -> __m256i {
+            Ops::<Avx2, i8>::set1(val)
+        }
         for Avx2(val: i8) -> __m256i {
             _mm256_set1_epi8(val)
         }
+        for Avx(val: i8) -> __m256i {
+            _mm256_set1_epi8(val)
+        }
+        for Sse42(val: i8) -> __m128i {
+            Ops::<Sse41, i8>::set1(val)
+        }
         for Sse41(val: i8) -> __m128i {
             _mm_set1_epi8(val)
         }
+        for Ssse3(val: i8) -> __m128i {
+            Ops::<Sse2, i8>::set1(val)
+        }
         for Sse2(val: i8) -> __m128i {
             _mm_set1_epi8(val)
         }
@@ -674,12 +1293,27 @@ impl_op! {
 
 impl_op! {
     fn load_unaligned<i8> {
+        for Avx512(ptr: *const i8) -> __m512i {
+            _mm512_loadu_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i8) -> __m256i {
+            Ops::<Avx2, i8>::load_unaligned(ptr)
+        }
         for Avx2(ptr: *const i8) -> __m256i {
             _mm256_loadu_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i8) -> __m256i {
+            _mm256_loadu_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i8) -> __m128i {
+            Ops::<Sse41, i8>::load_unaligned(ptr)
+        }
         for Sse41(ptr: *const i8) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i8) -> __m128i {
+            Ops::<Sse2, i8>::load_unaligned(ptr)
+        }
         for Sse2(ptr: *const i8) -> __m128i {
             _mm_loadu_si128(ptr as *const __m128i)
         }
@@ -697,12 +1331,27 @@ impl_op! {
 
 impl_op! {
     fn load_aligned<i8> {
+        for Avx512(ptr: *const i8) -> __m512i {
+            _mm512_load_si512(ptr as *const __m512i)
+        }
+        for Avx2Fma(ptr: *const i8) -> __m256i {
+            Ops::<Avx2, i8>::load_aligned(ptr)
+        }
         for Avx2(ptr: *const i8) -> __m256i {
             _mm256_load_si256(ptr as *const __m256i)
         }
+        for Avx(ptr: *const i8) -> __m256i {
+            _mm256_load_si256(ptr as *const __m256i)
+        }
+        for Sse42(ptr: *const i8) -> __m128i {
+            Ops::<Sse41, i8>::load_aligned(ptr)
+        }
         for Sse41(ptr: *const i8) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
+        for Ssse3(ptr: *const i8) -> __m128i {
+            Ops::<Sse2, i8>::load_aligned(ptr)
+        }
         for Sse2(ptr: *const i8) -> __m128i {
             _mm_load_si128(ptr as *const __m128i)
         }
@@ -720,12 +1369,27 @@ impl_op! {
 
 impl_op! {
     fn store_unaligned<i8> {
+        for Avx512(ptr: *mut i8, a: __m512i) {
+            _mm512_storeu_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i8, a: __m256i) {
+            Ops::<Avx2, i8>::store_unaligned(ptr, a)
+        }
         for Avx2(ptr: *mut i8, a: __m256i) {
             _mm256_storeu_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i8, a: __m256i) {
+            _mm256_storeu_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i8, a: __m128i) {
+            Ops::<Sse41, i8>::store_unaligned(ptr, a)
+        }
         for Sse41(ptr: *mut i8, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i8, a: __m128i) {
+            Ops::<Sse2, i8>::store_unaligned(ptr, a)
+        }
         for Sse2(ptr: *mut i8, a: __m128i) {
             _mm_storeu_si128(ptr as *mut __m128i, a)
         }
@@ -743,12 +1407,27 @@ impl_op! {
 
 impl_op! {
     fn store_aligned<i8> {
+        for Avx512(ptr: *mut i8, a: __m512i) {
+            _mm512_store_si512(ptr as *mut __m512i, a)
+        }
+        for Avx2Fma(ptr: *mut i8, a: __m256i) {
+            Ops::<Avx2, i8>::store_aligned(ptr, a)
+        }
         for Avx2(ptr: *mut i8, a: __m256i) {
             _mm256_store_si256(ptr as *mut __m256i, a)
         }
+        for Avx(ptr: *mut i8, a: __m256i) {
+            _mm256_store_si256(ptr as *mut __m256i, a)
+        }
+        for Sse42(ptr: *mut i8, a: __m128i) {
+            Ops::<Sse41, i8>::store_aligned(ptr, a)
+        }
         for Sse41(ptr: *mut i8, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
+        for Ssse3(ptr: *mut i8, a: __m128i) {
+            Ops::<Sse2, i8>::store_aligned(ptr, a)
+        }
         for Sse2(ptr: *mut i8, a: __m128i) {
             _mm_store_si128(ptr as *mut __m128i, a)
         }
@@ -763,3 +1442,949 @@ impl_op! {
         }
     }
 }
+
+impl_op! {
+    fn cmp_eq_any<i8> {
+        for Avx512(a: __m512i, set: &[i8]) -> __m512i {
+            let mut mask = 0;
+            for &value in set {
+                mask |= _mm512_cmpeq_epi8_mask(a, _mm512_set1_epi8(value));
+            }
+            _mm512_movm_epi8(mask)
+        }
+        for Avx2Fma(a: __m256i, set: &[i8]) -> __m256i {
+            Ops::<Avx2, i8>::cmp_eq_any(a, set)
+        }
+        for Avx2(a: __m256i, set: &[i8]) -> __m256i {
+            let mut result = _mm256_setzero_si256();
+            for &value in set {
+                result = _mm256_or_si256(result, _mm256_cmpeq_epi8(a, _mm256_set1_epi8(value)));
+            }
+            result
+        }
+        for Avx(a: __m256i, set: &[i8]) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            join_m128i!(
+                Ops::<Sse42, i8>::cmp_eq_any(lo, set),
+                Ops::<Sse42, i8>::cmp_eq_any(hi, set)
+            )
+        }
+        for Sse42(a: __m128i, set: &[i8]) -> __m128i {
+            let mut needle = [0i8; 16];
+            needle[..set.len()].copy_from_slice(set);
+            let needle = _mm_loadu_si128(needle.as_ptr() as *const __m128i);
+            _mm_cmpestrm(
+                needle,
+                set.len() as i32,
+                a,
+                16,
+                _SIDD_SBYTE_OPS | _SIDD_CMP_EQUAL_ANY | _SIDD_UNIT_MASK
+            )
+        }
+        for Sse41(a: __m128i, set: &[i8]) -> __m128i {
+            Ops::<Sse2, i8>::cmp_eq_any(a, set)
+        }
+        for Ssse3(a: __m128i, set: &[i8]) -> __m128i {
+            Ops::<Sse2, i8>::cmp_eq_any(a, set)
+        }
+        for Sse2(a: __m128i, set: &[i8]) -> __m128i {
+            let mut result = _mm_setzero_si128();
+            for &value in set {
+                result = _mm_or_si128(result, _mm_cmpeq_epi8(a, _mm_set1_epi8(value)));
+            }
+            result
+        }
+        for Scalar(a: i8, set: &[i8]) -> i8 {
+            if set.contains(&a) {
+                u32::MAX as i8
+            } else {
+                0
+            }
+        }
+        for Neon(a: int8x16_t, set: &[i8]) -> int8x16_t {
+            let mut result = vdupq_n_u8(0);
+            for &value in set {
+                result = vorrq_u8(result, vceqq_s8(a, vdupq_n_s8(value)));
+            }
+            vreinterpretq_s8_u8(result)
+        }
+        for Wasm(a: v128, set: &[i8]) -> v128 {
+            let mut result = i8x16_splat(0);
+            for &value in set {
+                result = v128_or(result, i8x16_eq(a, i8x16_splat(value)));
+            }
+            result
+        }
+    }
+}
+
+impl_op! {
+    fn cmp_in_ranges<i8> {
+        for Avx512(a: __m512i, ranges: &[(i8, i8)]) -> __m512i {
+            let mut mask = 0;
+            for &(lo, hi) in ranges {
+                let gte = _mm512_cmpge_epi8_mask(a, _mm512_set1_epi8(lo));
+                let lte = _mm512_cmple_epi8_mask(a, _mm512_set1_epi8(hi));
+                mask |= gte & lte;
+            }
+            _mm512_movm_epi8(mask)
+        }
+        for Avx2Fma(a: __m256i, ranges: &[(i8, i8)]) -> __m256i {
+            Ops::<Avx2, i8>::cmp_in_ranges(a, ranges)
+        }
+        for Avx2(a: __m256i, ranges: &[(i8, i8)]) -> __m256i {
+            let mut result = _mm256_setzero_si256();
+            for &(lo, hi) in ranges {
+                let gte = Ops::<Avx2, i8>::gte(a, _mm256_set1_epi8(lo));
+                let lte = Ops::<Avx2, i8>::lte(a, _mm256_set1_epi8(hi));
+                result = _mm256_or_si256(result, _mm256_and_si256(gte, lte));
+            }
+            result
+        }
+        for Avx(a: __m256i, ranges: &[(i8, i8)]) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            join_m128i!(
+                Ops::<Sse42, i8>::cmp_in_ranges(lo, ranges),
+                Ops::<Sse42, i8>::cmp_in_ranges(hi, ranges)
+            )
+        }
+        for Sse42(a: __m128i, ranges: &[(i8, i8)]) -> __m128i {
+            let mut needle = [0i8; 16];
+            for (i, &(lo, hi)) in ranges.iter().enumerate() {
+                needle[i * 2] = lo;
+                needle[i * 2 + 1] = hi;
+            }
+            let needle = _mm_loadu_si128(needle.as_ptr() as *const __m128i);
+            _mm_cmpestrm(
+                needle,
+                ranges.len() as i32 * 2,
+                a,
+                16,
+                _SIDD_SBYTE_OPS | _SIDD_CMP_RANGES | _SIDD_UNIT_MASK
+            )
+        }
+        for Sse41(a: __m128i, ranges: &[(i8, i8)]) -> __m128i {
+            Ops::<Sse2, i8>::cmp_in_ranges(a, ranges)
+        }
+        for Ssse3(a: __m128i, ranges: &[(i8, i8)]) -> __m128i {
+            Ops::<Sse2, i8>::cmp_in_ranges(a, ranges)
+        }
+        for Sse2(a: __m128i, ranges: &[(i8, i8)]) -> __m128i {
+            let mut result = _mm_setzero_si128();
+            for &(lo, hi) in ranges {
+                let gte = Ops::<Sse2, i8>::gte(a, _mm_set1_epi8(lo));
+                let lte = Ops::<Sse2, i8>::lte(a, _mm_set1_epi8(hi));
+                result = _mm_or_si128(result, _mm_and_si128(gte, lte));
+            }
+            result
+        }
+        for Scalar(a: i8, ranges: &[(i8, i8)]) -> i8 {
+            if ranges.iter().any(|&(lo, hi)| lo <= a && a <= hi) {
+                u32::MAX as i8
+            } else {
+                0
+            }
+        }
+        for Neon(a: int8x16_t, ranges: &[(i8, i8)]) -> int8x16_t {
+            let mut result = vdupq_n_u8(0);
+            for &(lo, hi) in ranges {
+                let gte = vcgeq_s8(a, vdupq_n_s8(lo));
+                let lte = vcleq_s8(a, vdupq_n_s8(hi));
+                result = vorrq_u8(result, vandq_u8(gte, lte));
+            }
+            vreinterpretq_s8_u8(result)
+        }
+        for Wasm(a: v128, ranges: &[(i8, i8)]) -> v128 {
+            let mut result = i8x16_splat(0);
+            for &(lo, hi) in ranges {
+                let gte = i8x16_ge(a, i8x16_splat(lo));
+                let lte = i8x16_le(a, i8x16_splat(hi));
+                result = v128_or(result, v128_and(gte, lte));
+            }
+            result
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn align_right<i8, const N: i32> {
+        for Avx512(a: __m512i, low: __m512i) -> __m512i {
+            // The 128 bit lanes of `low` followed by `a`, each window starting one lane later. The
+            // last window only pads out N == 64.
+            let windows = [
+                low,
+                _mm512_alignr_epi64(a, low, 2),
+                _mm512_alignr_epi64(a, low, 4),
+                _mm512_alignr_epi64(a, low, 6),
+                a,
+                a,
+            ];
+            constify_imm!(N, 0..=64, |BY| {
+                let lanes = (BY / 16) as usize;
+                _mm512_alignr_epi8(windows[lanes + 1], windows[lanes], BY % 16)
+            })
+        }
+        for Avx2Fma(a: __m256i, low: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::align_right::<N>(a, low)
+        }
+        for Avx2(a: __m256i, low: __m256i) -> __m256i {
+            // Line up the 128 bit lanes that follow each lane of `low`, then shift within lanes.
+            let next = _mm256_permute2x128_si256(low, a, 0x21);
+            let windows = [low, next, a, a];
+            constify_imm!(N, 0..=32, |BY| {
+                let lanes = (BY / 16) as usize;
+                _mm256_alignr_epi8(windows[lanes + 1], windows[lanes], BY % 16)
+            })
+        }
+        for Avx(a: __m256i, low: __m256i) -> __m256i {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (low_lo, low_hi) = split_m256i!(low);
+            let halves = [low_lo, low_hi, a_lo, a_hi, a_hi];
+            constify_imm!(N, 0..=32, |BY| {
+                let lanes = (BY / 16) as usize;
+                join_m128i!(
+                    Ops::<Ssse3, i8>::align_right::<{ BY % 16 }>(halves[lanes + 1], halves[lanes]),
+                    Ops::<Ssse3, i8>::align_right::<{ BY % 16 }>(
+                        halves[lanes + 2],
+                        halves[lanes + 1]
+                    )
+                )
+            })
+        }
+        for Sse42(a: __m128i, low: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::align_right::<N>(a, low)
+        }
+        for Sse41(a: __m128i, low: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::align_right::<N>(a, low)
+        }
+        for Ssse3(a: __m128i, low: __m128i) -> __m128i {
+            _mm_alignr_epi8(a, low, N)
+        }
+        for Sse2(a: __m128i, low: __m128i) -> __m128i {
+            constify_imm!(N, 0..=16, |BY| {
+                _mm_or_si128(_mm_srli_si128(low, BY), _mm_slli_si128(a, 16 - BY))
+            })
+        }
+        for Scalar(a: i8, low: i8) -> i8 {
+            if N == 0 {
+                low
+            } else {
+                a
+            }
+        }
+        for Neon(a: int8x16_t, low: int8x16_t) -> int8x16_t {
+            let iota: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+            let indices = vaddq_u8(vld1q_u8(iota.as_ptr()), vdupq_n_u8(N as u8));
+            vqtbl2q_s8(int8x16x2_t(low, a), indices)
+        }
+        for Wasm(a: v128, low: v128) -> v128 {
+            let indices = i8x16_add(
+                i8x16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+                i8x16_splat(N as i8),
+            );
+            let from_a = i8x16_sub(indices, i8x16_splat(16));
+            v128_or(i8x16_swizzle(low, indices), i8x16_swizzle(a, from_a))
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<i8> {
+        for Avx512(ptr: *const i8, mask: __mmask64) -> __m512i {
+            _mm512_maskz_loadu_epi8(mask, ptr)
+        }
+        for Avx2Fma(ptr: *const i8, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const i8, mask: __m256i) -> __m256i {
+            load_masked_emulated!(ptr, Ops::<Avx2, i8>::mask_to_bitmask(mask), [i8; 32])
+        }
+        for Avx(ptr: *const i8, mask: __m256i) -> __m256i {
+            load_masked_emulated!(ptr, Ops::<Avx, i8>::mask_to_bitmask(mask), [i8; 32])
+        }
+        for Sse42(ptr: *const i8, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const i8, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::load_masked(ptr, mask)
+        }
+        for Ssse3(ptr: *const i8, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const i8, mask: __m128i) -> __m128i {
+            load_masked_emulated!(ptr, Ops::<Sse2, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+        for Scalar(ptr: *const i8, mask: i8) -> i8 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const i8, mask: int8x16_t) -> int8x16_t {
+            load_masked_emulated!(ptr, Ops::<Neon, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+        for Wasm(ptr: *const i8, mask: v128) -> v128 {
+            load_masked_emulated!(ptr, Ops::<Wasm, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<i8> {
+        for Avx512(ptr: *mut i8, a: __m512i, mask: __mmask64) {
+            _mm512_mask_storeu_epi8(ptr, mask, a)
+        }
+        for Avx2Fma(ptr: *mut i8, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i8>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut i8, a: __m256i, mask: __m256i) {
+            store_masked_emulated!(ptr, a, Ops::<Avx2, i8>::mask_to_bitmask(mask), [i8; 32])
+        }
+        for Avx(ptr: *mut i8, a: __m256i, mask: __m256i) {
+            store_masked_emulated!(ptr, a, Ops::<Avx, i8>::mask_to_bitmask(mask), [i8; 32])
+        }
+        for Sse42(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i8>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i8>::store_masked(ptr, a, mask)
+        }
+        for Ssse3(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i8>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut i8, a: __m128i, mask: __m128i) {
+            store_masked_emulated!(ptr, a, Ops::<Sse2, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+        for Scalar(ptr: *mut i8, a: i8, mask: i8) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut i8, a: int8x16_t, mask: int8x16_t) {
+            store_masked_emulated!(ptr, a, Ops::<Neon, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+        for Wasm(ptr: *mut i8, a: v128, mask: v128) {
+            store_masked_emulated!(ptr, a, Ops::<Wasm, i8>::mask_to_bitmask(mask), [i8; 16])
+        }
+    }
+}
+
+impl_op! {
+    fn permute<i8> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            let indices = _mm512_and_si512(indices, _mm512_set1_epi8(63));
+            // pshufb only looks within each 128 bit quarter, so look up every quarter and pick the
+            // result from the quarter each index points into.
+            let q0 = _mm512_shuffle_epi8(_mm512_shuffle_i32x4::<0x00>(a, a), indices);
+            let q1 = _mm512_shuffle_epi8(_mm512_shuffle_i32x4::<0x55>(a, a), indices);
+            let q2 = _mm512_shuffle_epi8(_mm512_shuffle_i32x4::<0xAA>(a, a), indices);
+            let q3 = _mm512_shuffle_epi8(_mm512_shuffle_i32x4::<0xFF>(a, a), indices);
+            let odd = _mm512_test_epi8_mask(indices, _mm512_set1_epi8(16));
+            let high = _mm512_test_epi8_mask(indices, _mm512_set1_epi8(32));
+            let low_half = _mm512_mask_blend_epi8(odd, q0, q1);
+            let high_half = _mm512_mask_blend_epi8(odd, q2, q3);
+            _mm512_mask_blend_epi8(high, low_half, high_half)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = _mm256_and_si256(indices, _mm256_set1_epi8(31));
+            // pshufb only looks within each 128 bit half, so look up both halves and pick the
+            // result from the half each index points into.
+            let same = _mm256_shuffle_epi8(a, indices);
+            let other = _mm256_shuffle_epi8(_mm256_permute2x128_si256::<0x01>(a, a), indices);
+            let half = _mm256_setr_epi64x(0, 0, 0x1010101010101010, 0x1010101010101010);
+            let in_same = _mm256_cmpeq_epi8(_mm256_and_si256(indices, _mm256_set1_epi8(16)), half);
+            _mm256_blendv_epi8(other, same, in_same)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i8; 32]>(indices);
+            let values = core::mem::transmute::<__m256i, [i8; 32]>(a);
+            core::mem::transmute::<[i8; 32], __m256i>(indices.map(|i| values[i as usize & 31]))
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            _mm_shuffle_epi8(a, _mm_and_si128(indices, _mm_set1_epi8(15)))
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            _mm_shuffle_epi8(a, _mm_and_si128(indices, _mm_set1_epi8(15)))
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i8; 16]>(indices);
+            let values = core::mem::transmute::<__m128i, [i8; 16]>(a);
+            core::mem::transmute::<[i8; 16], __m128i>(indices.map(|i| values[i as usize & 15]))
+        }
+        for Scalar(a: i8, indices: i8) -> i8 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int8x16_t, indices: int8x16_t) -> int8x16_t {
+            vqtbl1q_s8(a, vandq_u8(vreinterpretq_u8_s8(indices), vdupq_n_u8(15)))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            i8x16_swizzle(a, v128_and(indices, i8x16_splat(15)))
+        }
+    }
+}
+
+impl_op! {
+    fn shuffle<i8> {
+        for Avx512(a: __m512i, indices: [i8; 64]) -> __m512i {
+            Ops::<Avx512, i8>::permute(a, core::mem::transmute::<[i8; 64], __m512i>(indices))
+        }
+        for Avx2Fma(a: __m256i, indices: [i8; 32]) -> __m256i {
+            Ops::<Avx2, i8>::shuffle(a, indices)
+        }
+        for Avx2(a: __m256i, indices: [i8; 32]) -> __m256i {
+            Ops::<Avx2, i8>::permute(a, core::mem::transmute::<[i8; 32], __m256i>(indices))
+        }
+        for Avx(a: __m256i, indices: [i8; 32]) -> __m256i {
+            // pshufb writes zero for indices with the top bit set, so look up each half with only
+            // the indices into it and combine the two.
+            let from_lo: [i8; 32] =
+                core::array::from_fn(|i| if indices[i] < 16 { indices[i] } else { -1 });
+            let from_hi: [i8; 32] =
+                core::array::from_fn(|i| if indices[i] < 16 { -1 } else { indices[i] - 16 });
+            let [lo_from_lo, hi_from_lo] = core::mem::transmute::<[i8; 32], [__m128i; 2]>(from_lo);
+            let [lo_from_hi, hi_from_hi] = core::mem::transmute::<[i8; 32], [__m128i; 2]>(from_hi);
+            let (lo, hi) = split_m256i!(a);
+            join_m128i!(
+                _mm_or_si128(_mm_shuffle_epi8(lo, lo_from_lo), _mm_shuffle_epi8(hi, lo_from_hi)),
+                _mm_or_si128(_mm_shuffle_epi8(lo, hi_from_lo), _mm_shuffle_epi8(hi, hi_from_hi))
+            )
+        }
+        for Sse42(a: __m128i, indices: [i8; 16]) -> __m128i {
+            Ops::<Sse41, i8>::shuffle(a, indices)
+        }
+        for Sse41(a: __m128i, indices: [i8; 16]) -> __m128i {
+            Ops::<Ssse3, i8>::shuffle(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: [i8; 16]) -> __m128i {
+            _mm_shuffle_epi8(a, core::mem::transmute::<[i8; 16], __m128i>(indices))
+        }
+        for Sse2(a: __m128i, indices: [i8; 16]) -> __m128i {
+            // Sse2 has no byte shuffle, so this compiles to the word shuffles, unpacks and masks
+            // that build the same lanes.
+            pick_lanes!(a: __m128i, indices, [i8; 16])
+        }
+        for Scalar(a: i8, indices: [i8; 1]) -> i8 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: int8x16_t, indices: [i8; 16]) -> int8x16_t {
+            vqtbl1q_s8(a, core::mem::transmute::<[i8; 16], uint8x16_t>(indices))
+        }
+        for Wasm(a: v128, indices: [i8; 16]) -> v128 {
+            // Compiles to i8x16.shuffle.
+            pick_lanes!(a: v128, indices, [i8; 16])
+        }
+    }
+}
+
+impl_op! {
+    fn lookup16<i8> {
+        for Avx512(table: &[i8; 16], indices: __m512i) -> __m512i {
+            let table = _mm512_broadcast_i32x4(_mm_loadu_si128(table.as_ptr() as *const __m128i));
+            let in_range = _mm512_testn_epi8_mask(indices, _mm512_set1_epi8(-16));
+            _mm512_maskz_shuffle_epi8(in_range, table, indices)
+        }
+        for Avx2Fma(table: &[i8; 16], indices: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::lookup16(table, indices)
+        }
+        for Avx2(table: &[i8; 16], indices: __m256i) -> __m256i {
+            let table = _mm256_broadcastsi128_si256(_mm_loadu_si128(table.as_ptr() as *const __m128i));
+            // pshufb zeroes lanes with the high bit set, so set it for indices past the table too.
+            let past_end = _mm256_cmpgt_epi8(indices, _mm256_set1_epi8(15));
+            _mm256_shuffle_epi8(table, _mm256_or_si256(indices, past_end))
+        }
+        for Avx(table: &[i8; 16], indices: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(indices);
+            join_m128i!(
+                Ops::<Sse41, i8>::lookup16(table, lo),
+                Ops::<Sse41, i8>::lookup16(table, hi)
+            )
+        }
+        for Sse42(table: &[i8; 16], indices: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::lookup16(table, indices)
+        }
+        for Sse41(table: &[i8; 16], indices: __m128i) -> __m128i {
+            let table = _mm_loadu_si128(table.as_ptr() as *const __m128i);
+            // pshufb zeroes lanes with the high bit set, so set it for indices past the table too.
+            let past_end = _mm_cmpgt_epi8(indices, _mm_set1_epi8(15));
+            _mm_shuffle_epi8(table, _mm_or_si128(indices, past_end))
+        }
+        for Ssse3(table: &[i8; 16], indices: __m128i) -> __m128i {
+            let table = _mm_loadu_si128(table.as_ptr() as *const __m128i);
+            let past_end = _mm_cmpgt_epi8(indices, _mm_set1_epi8(15));
+            _mm_shuffle_epi8(table, _mm_or_si128(indices, past_end))
+        }
+        for Sse2(table: &[i8; 16], indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i8; 16]>(indices);
+            let result = indices.map(|i| table.get(i as u8 as usize).copied().unwrap_or(0));
+            core::mem::transmute::<[i8; 16], __m128i>(result)
+        }
+        for Scalar(table: &[i8; 16], indices: i8) -> i8 {
+            table.get(indices as u8 as usize).copied().unwrap_or(0)
+        }
+        for Neon(table: &[i8; 16], indices: int8x16_t) -> int8x16_t {
+            vqtbl1q_s8(vld1q_s8(table.as_ptr()), vreinterpretq_u8_s8(indices))
+        }
+        for Wasm(table: &[i8; 16], indices: v128) -> v128 {
+            i8x16_swizzle(v128_load(table.as_ptr() as *const v128), indices)
+        }
+    }
+}
+
+impl_op! {
+    fn shuffle_bytes<i8> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            let in_range = _mm512_testn_epi8_mask(indices, _mm512_set1_epi8(-64));
+            _mm512_maskz_mov_epi8(in_range, Ops::<Avx512, i8>::permute(a, indices))
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shuffle_bytes(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            let high_bits = _mm256_and_si256(indices, _mm256_set1_epi8(-32));
+            let in_range = _mm256_cmpeq_epi8(high_bits, _mm256_setzero_si256());
+            _mm256_and_si256(in_range, Ops::<Avx2, i8>::permute(a, indices))
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            let indices = core::mem::transmute::<__m256i, [i8; 32]>(indices);
+            let values = core::mem::transmute::<__m256i, [i8; 32]>(a);
+            let result = indices.map(|i| values.get(i as u8 as usize).copied().unwrap_or(0));
+            core::mem::transmute::<[i8; 32], __m256i>(result)
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shuffle_bytes(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            // pshufb zeroes lanes with the high bit set, so set it for indices past the end too.
+            let past_end = _mm_cmpgt_epi8(indices, _mm_set1_epi8(15));
+            _mm_shuffle_epi8(a, _mm_or_si128(indices, past_end))
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            let past_end = _mm_cmpgt_epi8(indices, _mm_set1_epi8(15));
+            _mm_shuffle_epi8(a, _mm_or_si128(indices, past_end))
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            let indices = core::mem::transmute::<__m128i, [i8; 16]>(indices);
+            let values = core::mem::transmute::<__m128i, [i8; 16]>(a);
+            let result = indices.map(|i| values.get(i as u8 as usize).copied().unwrap_or(0));
+            core::mem::transmute::<[i8; 16], __m128i>(result)
+        }
+        for Scalar(a: i8, indices: i8) -> i8 {
+            if indices == 0 {
+                a
+            } else {
+                0
+            }
+        }
+        for Neon(a: int8x16_t, indices: int8x16_t) -> int8x16_t {
+            vqtbl1q_s8(a, vreinterpretq_u8_s8(indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            i8x16_swizzle(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Zip each 128 bit quarter, then put the quarters back in order.
+            let lo = _mm512_unpacklo_epi8(a, b);
+            let hi = _mm512_unpackhi_epi8(a, b);
+            _mm512_permutex2var_epi64(lo, _mm512_setr_epi64(0, 1, 8, 9, 2, 3, 10, 11), hi)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi8(a, b);
+            let hi = _mm256_unpackhi_epi8(a, b);
+            _mm256_permute2x128_si256::<0x20>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (a_lo, _) = split_m256i!(a);
+            let (b_lo, _) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i8>::zip_lo(a_lo, b_lo),
+                Ops::<Sse41, i8>::zip_hi(a_lo, b_lo)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpacklo_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            let _ = b;
+            a
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vzip1q_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_shuffle::<0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            // Zip each 128 bit quarter, then put the quarters back in order.
+            let lo = _mm512_unpacklo_epi8(a, b);
+            let hi = _mm512_unpackhi_epi8(a, b);
+            _mm512_permutex2var_epi64(lo, _mm512_setr_epi64(4, 5, 12, 13, 6, 7, 14, 15), hi)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            let lo = _mm256_unpacklo_epi8(a, b);
+            let hi = _mm256_unpackhi_epi8(a, b);
+            _mm256_permute2x128_si256::<0x31>(lo, hi)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            let (_, a_hi) = split_m256i!(a);
+            let (_, b_hi) = split_m256i!(b);
+            join_m128i!(
+                Ops::<Sse41, i8>::zip_lo(a_hi, b_hi),
+                Ops::<Sse41, i8>::zip_hi(a_hi, b_hi)
+            )
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_unpackhi_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            let _ = a;
+            b
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vzip2q_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_shuffle::<8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31>(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            // Unzip each 128 bit quarter, then gather the quarters coming from the same input.
+            let low = _mm512_set1_epi16(0xFF);
+            let even = _mm512_packus_epi16(_mm512_and_si512(a, low), _mm512_and_si512(b, low));
+            let odd = _mm512_packus_epi16(_mm512_srli_epi16::<8>(a), _mm512_srli_epi16::<8>(b));
+            let order = _mm512_setr_epi64(0, 2, 4, 6, 1, 3, 5, 7);
+            (_mm512_permutexvar_epi64(order, even), _mm512_permutexvar_epi64(order, odd))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, i8>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            // Unzip each 128 bit half, then gather the halves coming from the same input.
+            let low = _mm256_set1_epi16(0xFF);
+            let even = _mm256_packus_epi16(_mm256_and_si256(a, low), _mm256_and_si256(b, low));
+            let odd = _mm256_packus_epi16(_mm256_srli_epi16::<8>(a), _mm256_srli_epi16::<8>(b));
+            (_mm256_permute4x64_epi64::<0b11_01_10_00>(even), _mm256_permute4x64_epi64::<0b11_01_10_00>(odd))
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (a_lo, a_hi) = split_m256i!(a);
+            let (b_lo, b_hi) = split_m256i!(b);
+            let (a_even, a_odd) = Ops::<Sse41, i8>::unzip(a_lo, a_hi);
+            let (b_even, b_odd) = Ops::<Sse41, i8>::unzip(b_lo, b_hi);
+            (join_m128i!(a_even, b_even), join_m128i!(a_odd, b_odd))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, i8>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i8>::unzip(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, i8>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let low = _mm_set1_epi16(0xFF);
+            let even = _mm_packus_epi16(_mm_and_si128(a, low), _mm_and_si128(b, low));
+            let odd = _mm_packus_epi16(_mm_srli_epi16::<8>(a), _mm_srli_epi16::<8>(b));
+            (even, odd)
+        }
+        for Scalar(a: i8, b: i8) -> (i8, i8) {
+            (a, b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> (int8x16_t, int8x16_t) {
+            (vuzp1q_s8(a, b), vuzp2q_s8(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let even = i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(a, b);
+            let odd = i8x16_shuffle::<1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31>(a, b);
+            (even, odd)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t) {
+            let loaded = vld2q_s8(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t) {
+            vst2q_s8(ptr, int8x16x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t, int8x16_t) {
+            let loaded = vld3q_s8(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t, c: int8x16_t) {
+            vst3q_s8(ptr, int8x16x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<i8> {
+        for Neon(ptr: *const i8) -> (int8x16_t, int8x16_t, int8x16_t, int8x16_t) {
+            let loaded = vld4q_s8(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<i8> {
+        for Neon(ptr: *mut i8, a: int8x16_t, b: int8x16_t, c: int8x16_t, d: int8x16_t) {
+            vst4q_s8(ptr, int8x16x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            let carry = _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512());
+            let a = _mm512_add_epi8(a, _mm512_alignr_epi8::<15>(a, carry));
+            let carry = _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512());
+            let a = _mm512_add_epi8(a, _mm512_alignr_epi8::<14>(a, carry));
+            let a = _mm512_add_epi8(a, _mm512_alignr_epi32::<15>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi8(a, _mm512_alignr_epi32::<14>(a, _mm512_setzero_si512()));
+            let a = _mm512_add_epi8(a, _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512()));
+            _mm512_add_epi8(a, _mm512_alignr_epi32::<8>(a, _mm512_setzero_si512()))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi8(a, _mm256_alignr_epi8::<15>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi8(a, _mm256_alignr_epi8::<14>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi8(a, _mm256_alignr_epi8::<12>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            let a = _mm256_add_epi8(a, _mm256_alignr_epi8::<8>(a, carry));
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            _mm256_add_epi8(a, carry)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let lo = Ops::<Sse41, i8>::prefix_sum(lo);
+            let hi = Ops::<Sse41, i8>::prefix_sum(hi);
+            // Add the total of the lower half, held in its last lane, to every lane of the upper half.
+            join_m128i!(lo, _mm_add_epi8(hi, _mm_shuffle_epi8(lo, _mm_set1_epi8(15))))
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            let a = _mm_add_epi8(a, _mm_slli_si128::<1>(a));
+            let a = _mm_add_epi8(a, _mm_slli_si128::<2>(a));
+            let a = _mm_add_epi8(a, _mm_slli_si128::<4>(a));
+            _mm_add_epi8(a, _mm_slli_si128::<8>(a))
+        }
+        for Scalar(a: i8) -> i8 {
+            a
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            let a = vaddq_s8(a, vextq_s8::<15>(vdupq_n_s8(0), a));
+            let a = vaddq_s8(a, vextq_s8::<14>(vdupq_n_s8(0), a));
+            let a = vaddq_s8(a, vextq_s8::<12>(vdupq_n_s8(0), a));
+            vaddq_s8(a, vextq_s8::<8>(vdupq_n_s8(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            let shifted = i8x16_shuffle::<16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14>(a, i8x16_splat(0));
+            let a = i8x16_add(a, shifted);
+            let shifted = i8x16_shuffle::<16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13>(a, i8x16_splat(0));
+            let a = i8x16_add(a, shifted);
+            let shifted = i8x16_shuffle::<16, 16, 16, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11>(a, i8x16_splat(0));
+            let a = i8x16_add(a, shifted);
+            let shifted = i8x16_shuffle::<16, 16, 16, 16, 16, 16, 16, 16, 0, 1, 2, 3, 4, 5, 6, 7>(a, i8x16_splat(0));
+            i8x16_add(a, shifted)
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<i8> {
+        for Avx512(a: __m512i) -> __m512i {
+            let carry = _mm512_alignr_epi32::<12>(a, _mm512_setzero_si512());
+            Ops::<Avx512, i8>::prefix_sum(_mm512_alignr_epi8::<15>(a, carry))
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            let carry = _mm256_permute2x128_si256::<0x08>(a, a);
+            Ops::<Avx2, i8>::prefix_sum(_mm256_alignr_epi8::<15>(a, carry))
+        }
+        for Avx(a: __m256i) -> __m256i {
+            let (lo, hi) = split_m256i!(a);
+            let a = join_m128i!(_mm_slli_si128::<1>(lo), _mm_alignr_epi8::<15>(hi, lo));
+            Ops::<Avx, i8>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::prefix_sum(_mm_slli_si128::<1>(a))
+        }
+        for Scalar(a: i8) -> i8 {
+            let _ = a;
+            0
+        }
+        for Neon(a: int8x16_t) -> int8x16_t {
+            Ops::<Neon, i8>::prefix_sum(vextq_s8::<15>(vdupq_n_s8(0), a))
+        }
+        for Wasm(a: v128) -> v128 {
+            let a = i8x16_shuffle::<16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14>(a, i8x16_splat(0));
+            Ops::<Wasm, i8>::prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::saturating_add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epi8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::saturating_add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::saturating_add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::saturating_add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.saturating_add(b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vqaddq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<i8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epi8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::saturating_sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epi8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, i8>::saturating_sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::saturating_sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::saturating_sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epi8(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vqsubq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            i8x16_sub_sat(a, b)
+        }
+    }
+}
@@ -0,0 +1,1345 @@
+use super::*;
+
+/// 64 bytes with every bit set followed by 64 cleared bytes. A vector loaded `n` bytes before
+/// the middle of the table has exactly its first `n` bytes set.
+static FIRST_LANES: [i8; 128] = {
+    let mut table = [0; 128];
+    let mut i = 0;
+    while i < 64 {
+        table[i] = -1;
+        i += 1;
+    }
+    table
+};
+
+impl_op! {
+    fn mask_from_vector<i8> {
+        for Avx512(val: __m512i) -> __mmask64 {
+            _mm512_movepi8_mask(val)
+        }
+        for Avx2Fma(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx2(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx(val: __m256i) -> __m256i {
+            val
+        }
+        for Sse42(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse41(val: __m128i) -> __m128i {
+            val
+        }
+        for Ssse3(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse2(val: __m128i) -> __m128i {
+            val
+        }
+        for Scalar(val: i8) -> i8 {
+            val
+        }
+        for Neon(val: int8x16_t) -> int8x16_t {
+            val
+        }
+        for Wasm(val: v128) -> v128 {
+            val
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i8> {
+        for Avx512(mask: __mmask64) -> __m512i {
+            _mm512_movm_epi8(mask)
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Scalar(mask: i8) -> i8 {
+            mask
+        }
+        for Neon(mask: int8x16_t) -> int8x16_t {
+            mask
+        }
+        for Wasm(mask: v128) -> v128 {
+            mask
+        }
+    }
+}
+
+impl_op! {
+    fn mask_and<i8> {
+        for Avx512(a: __mmask64, b: __mmask64) -> __mmask64 {
+            a & b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mask_and(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_and_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_and_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mask_and(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mask_and(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a & b
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vandq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_and(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_or<i8> {
+        for Avx512(a: __mmask64, b: __mmask64) -> __mmask64 {
+            a | b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mask_or(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_or_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_or_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mask_or(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mask_or(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a | b
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            vorrq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_or(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_xor<i8> {
+        for Avx512(a: __mmask64, b: __mmask64) -> __mmask64 {
+            a ^ b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mask_xor(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_xor_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mask_xor(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mask_xor(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Scalar(a: i8, b: i8) -> i8 {
+            a ^ b
+        }
+        for Neon(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+            veorq_s8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_xor(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_not<i8> {
+        for Avx512(mask: __mmask64) -> __mmask64 {
+            !mask
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mask_not(mask)
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            _mm256_xor_si256(mask, _mm256_set1_epi32(-1))
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(mask),
+                _mm256_castsi256_ps(_mm256_set1_epi32(-1)),
+            ))
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mask_not(mask)
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mask_not(mask)
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Scalar(mask: i8) -> i8 {
+            !mask
+        }
+        for Neon(mask: int8x16_t) -> int8x16_t {
+            vmvnq_s8(mask)
+        }
+        for Wasm(mask: v128) -> v128 {
+            v128_not(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_bitmask<i8> {
+        for Avx512(mask: __mmask64) -> u64 {
+            mask as u64
+        }
+        for Avx2Fma(mask: __m256i) -> u64 {
+            Ops::<Avx2, i8>::mask_to_bitmask(mask)
+        }
+        for Avx2(mask: __m256i) -> u64 {
+            Ops::<Avx2, i8>::get_mask(mask)
+        }
+        for Avx(mask: __m256i) -> u64 {
+            Ops::<Avx, i8>::get_mask(mask)
+        }
+        for Sse42(mask: __m128i) -> u64 {
+            Ops::<Sse41, i8>::mask_to_bitmask(mask)
+        }
+        for Sse41(mask: __m128i) -> u64 {
+            Ops::<Sse41, i8>::get_mask(mask)
+        }
+        for Ssse3(mask: __m128i) -> u64 {
+            Ops::<Sse2, i8>::mask_to_bitmask(mask)
+        }
+        for Sse2(mask: __m128i) -> u64 {
+            Ops::<Sse2, i8>::get_mask(mask)
+        }
+        for Scalar(mask: i8) -> u64 {
+            Ops::<Scalar, i8>::get_mask(mask)
+        }
+        for Neon(mask: int8x16_t) -> u64 {
+            Ops::<Neon, i8>::get_mask(mask)
+        }
+        for Wasm(mask: v128) -> u64 {
+            Ops::<Wasm, i8>::get_mask(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_from_vector<i16> {
+        for Avx512(val: __m512i) -> __mmask32 {
+            _mm512_movepi16_mask(val)
+        }
+        for Avx2Fma(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx2(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx(val: __m256i) -> __m256i {
+            val
+        }
+        for Sse42(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse41(val: __m128i) -> __m128i {
+            val
+        }
+        for Ssse3(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse2(val: __m128i) -> __m128i {
+            val
+        }
+        for Scalar(val: i16) -> i16 {
+            val
+        }
+        for Neon(val: int16x8_t) -> int16x8_t {
+            val
+        }
+        for Wasm(val: v128) -> v128 {
+            val
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i16> {
+        for Avx512(mask: __mmask32) -> __m512i {
+            _mm512_movm_epi16(mask)
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Scalar(mask: i16) -> i16 {
+            mask
+        }
+        for Neon(mask: int16x8_t) -> int16x8_t {
+            mask
+        }
+        for Wasm(mask: v128) -> v128 {
+            mask
+        }
+    }
+}
+
+impl_op! {
+    fn mask_and<i16> {
+        for Avx512(a: __mmask32, b: __mmask32) -> __mmask32 {
+            a & b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mask_and(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_and_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_and_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mask_and(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mask_and(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a & b
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vandq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_and(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_or<i16> {
+        for Avx512(a: __mmask32, b: __mmask32) -> __mmask32 {
+            a | b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mask_or(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_or_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_or_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mask_or(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mask_or(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a | b
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            vorrq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_or(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_xor<i16> {
+        for Avx512(a: __mmask32, b: __mmask32) -> __mmask32 {
+            a ^ b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mask_xor(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_xor_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mask_xor(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mask_xor(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Scalar(a: i16, b: i16) -> i16 {
+            a ^ b
+        }
+        for Neon(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+            veorq_s16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_xor(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_not<i16> {
+        for Avx512(mask: __mmask32) -> __mmask32 {
+            !mask
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mask_not(mask)
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            _mm256_xor_si256(mask, _mm256_set1_epi32(-1))
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(mask),
+                _mm256_castsi256_ps(_mm256_set1_epi32(-1)),
+            ))
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mask_not(mask)
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mask_not(mask)
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Scalar(mask: i16) -> i16 {
+            !mask
+        }
+        for Neon(mask: int16x8_t) -> int16x8_t {
+            vmvnq_s16(mask)
+        }
+        for Wasm(mask: v128) -> v128 {
+            v128_not(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_bitmask<i16> {
+        for Avx512(mask: __mmask32) -> u64 {
+            mask as u64
+        }
+        for Avx2Fma(mask: __m256i) -> u64 {
+            Ops::<Avx2, i16>::mask_to_bitmask(mask)
+        }
+        for Avx2(mask: __m256i) -> u64 {
+            Ops::<Avx2, i16>::get_mask(mask)
+        }
+        for Avx(mask: __m256i) -> u64 {
+            Ops::<Avx, i16>::get_mask(mask)
+        }
+        for Sse42(mask: __m128i) -> u64 {
+            Ops::<Sse41, i16>::mask_to_bitmask(mask)
+        }
+        for Sse41(mask: __m128i) -> u64 {
+            Ops::<Sse41, i16>::get_mask(mask)
+        }
+        for Ssse3(mask: __m128i) -> u64 {
+            Ops::<Sse2, i16>::mask_to_bitmask(mask)
+        }
+        for Sse2(mask: __m128i) -> u64 {
+            Ops::<Sse2, i16>::get_mask(mask)
+        }
+        for Scalar(mask: i16) -> u64 {
+            Ops::<Scalar, i16>::get_mask(mask)
+        }
+        for Neon(mask: int16x8_t) -> u64 {
+            Ops::<Neon, i16>::get_mask(mask)
+        }
+        for Wasm(mask: v128) -> u64 {
+            Ops::<Wasm, i16>::get_mask(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_from_vector<i32> {
+        for Avx512(val: __m512i) -> __mmask16 {
+            _mm512_movepi32_mask(val)
+        }
+        for Avx2Fma(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx2(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx(val: __m256i) -> __m256i {
+            val
+        }
+        for Sse42(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse41(val: __m128i) -> __m128i {
+            val
+        }
+        for Ssse3(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse2(val: __m128i) -> __m128i {
+            val
+        }
+        for Scalar(val: i32) -> i32 {
+            val
+        }
+        for Neon(val: int32x4_t) -> int32x4_t {
+            val
+        }
+        for Wasm(val: v128) -> v128 {
+            val
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i32> {
+        for Avx512(mask: __mmask16) -> __m512i {
+            _mm512_movm_epi32(mask)
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Scalar(mask: i32) -> i32 {
+            mask
+        }
+        for Neon(mask: int32x4_t) -> int32x4_t {
+            mask
+        }
+        for Wasm(mask: v128) -> v128 {
+            mask
+        }
+    }
+}
+
+impl_op! {
+    fn mask_and<i32> {
+        for Avx512(a: __mmask16, b: __mmask16) -> __mmask16 {
+            a & b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mask_and(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_and_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_and_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mask_and(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mask_and(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a & b
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vandq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_and(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_or<i32> {
+        for Avx512(a: __mmask16, b: __mmask16) -> __mmask16 {
+            a | b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mask_or(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_or_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_or_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mask_or(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mask_or(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a | b
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            vorrq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_or(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_xor<i32> {
+        for Avx512(a: __mmask16, b: __mmask16) -> __mmask16 {
+            a ^ b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mask_xor(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_xor_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mask_xor(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mask_xor(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Scalar(a: i32, b: i32) -> i32 {
+            a ^ b
+        }
+        for Neon(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+            veorq_s32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_xor(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_not<i32> {
+        for Avx512(mask: __mmask16) -> __mmask16 {
+            !mask
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mask_not(mask)
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            _mm256_xor_si256(mask, _mm256_set1_epi32(-1))
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(mask),
+                _mm256_castsi256_ps(_mm256_set1_epi32(-1)),
+            ))
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mask_not(mask)
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mask_not(mask)
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Scalar(mask: i32) -> i32 {
+            !mask
+        }
+        for Neon(mask: int32x4_t) -> int32x4_t {
+            vmvnq_s32(mask)
+        }
+        for Wasm(mask: v128) -> v128 {
+            v128_not(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_bitmask<i32> {
+        for Avx512(mask: __mmask16) -> u64 {
+            mask as u64
+        }
+        for Avx2Fma(mask: __m256i) -> u64 {
+            Ops::<Avx2, i32>::mask_to_bitmask(mask)
+        }
+        for Avx2(mask: __m256i) -> u64 {
+            Ops::<Avx2, i32>::get_mask(mask)
+        }
+        for Avx(mask: __m256i) -> u64 {
+            Ops::<Avx, i32>::get_mask(mask)
+        }
+        for Sse42(mask: __m128i) -> u64 {
+            Ops::<Sse41, i32>::mask_to_bitmask(mask)
+        }
+        for Sse41(mask: __m128i) -> u64 {
+            Ops::<Sse41, i32>::get_mask(mask)
+        }
+        for Ssse3(mask: __m128i) -> u64 {
+            Ops::<Sse2, i32>::mask_to_bitmask(mask)
+        }
+        for Sse2(mask: __m128i) -> u64 {
+            Ops::<Sse2, i32>::get_mask(mask)
+        }
+        for Scalar(mask: i32) -> u64 {
+            Ops::<Scalar, i32>::get_mask(mask)
+        }
+        for Neon(mask: int32x4_t) -> u64 {
+            Ops::<Neon, i32>::get_mask(mask)
+        }
+        for Wasm(mask: v128) -> u64 {
+            Ops::<Wasm, i32>::get_mask(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_from_vector<i64> {
+        for Avx512(val: __m512i) -> __mmask8 {
+            _mm512_movepi64_mask(val)
+        }
+        for Avx2Fma(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx2(val: __m256i) -> __m256i {
+            val
+        }
+        for Avx(val: __m256i) -> __m256i {
+            val
+        }
+        for Sse42(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse41(val: __m128i) -> __m128i {
+            val
+        }
+        for Ssse3(val: __m128i) -> __m128i {
+            val
+        }
+        for Sse2(val: __m128i) -> __m128i {
+            val
+        }
+        for Scalar(val: i64) -> i64 {
+            val
+        }
+        for Neon(val: int64x2_t) -> int64x2_t {
+            val
+        }
+        for Wasm(val: v128) -> v128 {
+            val
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_vector<i64> {
+        for Avx512(mask: __mmask8) -> __m512i {
+            _mm512_movm_epi64(mask)
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            mask
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            mask
+        }
+        for Scalar(mask: i64) -> i64 {
+            mask
+        }
+        for Neon(mask: int64x2_t) -> int64x2_t {
+            mask
+        }
+        for Wasm(mask: v128) -> v128 {
+            mask
+        }
+    }
+}
+
+impl_op! {
+    fn mask_and<i64> {
+        for Avx512(a: __mmask8, b: __mmask8) -> __mmask8 {
+            a & b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mask_and(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_and_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_and_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mask_and(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mask_and(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_and_si128(a, b)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a & b
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vandq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_and(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_or<i64> {
+        for Avx512(a: __mmask8, b: __mmask8) -> __mmask8 {
+            a | b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mask_or(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_or_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_or_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mask_or(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mask_or(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_or_si128(a, b)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a | b
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            vorrq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_or(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_xor<i64> {
+        for Avx512(a: __mmask8, b: __mmask8) -> __mmask8 {
+            a ^ b
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mask_xor(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_xor_si256(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(a),
+                _mm256_castsi256_ps(b),
+            ))
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mask_xor(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mask_xor(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_xor_si128(a, b)
+        }
+        for Scalar(a: i64, b: i64) -> i64 {
+            a ^ b
+        }
+        for Neon(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+            veorq_s64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_xor(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_not<i64> {
+        for Avx512(mask: __mmask8) -> __mmask8 {
+            !mask
+        }
+        for Avx2Fma(mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mask_not(mask)
+        }
+        for Avx2(mask: __m256i) -> __m256i {
+            _mm256_xor_si256(mask, _mm256_set1_epi32(-1))
+        }
+        for Avx(mask: __m256i) -> __m256i {
+            _mm256_castps_si256(_mm256_xor_ps(
+                _mm256_castsi256_ps(mask),
+                _mm256_castsi256_ps(_mm256_set1_epi32(-1)),
+            ))
+        }
+        for Sse42(mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mask_not(mask)
+        }
+        for Sse41(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Ssse3(mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mask_not(mask)
+        }
+        for Sse2(mask: __m128i) -> __m128i {
+            _mm_xor_si128(mask, _mm_set1_epi32(-1))
+        }
+        for Scalar(mask: i64) -> i64 {
+            !mask
+        }
+        for Neon(mask: int64x2_t) -> int64x2_t {
+            vreinterpretq_s64_s32(vmvnq_s32(vreinterpretq_s32_s64(mask)))
+        }
+        for Wasm(mask: v128) -> v128 {
+            v128_not(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_to_bitmask<i64> {
+        for Avx512(mask: __mmask8) -> u64 {
+            mask as u64
+        }
+        for Avx2Fma(mask: __m256i) -> u64 {
+            Ops::<Avx2, i64>::mask_to_bitmask(mask)
+        }
+        for Avx2(mask: __m256i) -> u64 {
+            Ops::<Avx2, i64>::get_mask(mask)
+        }
+        for Avx(mask: __m256i) -> u64 {
+            Ops::<Avx, i64>::get_mask(mask)
+        }
+        for Sse42(mask: __m128i) -> u64 {
+            Ops::<Sse41, i64>::mask_to_bitmask(mask)
+        }
+        for Sse41(mask: __m128i) -> u64 {
+            Ops::<Sse41, i64>::get_mask(mask)
+        }
+        for Ssse3(mask: __m128i) -> u64 {
+            Ops::<Sse2, i64>::mask_to_bitmask(mask)
+        }
+        for Sse2(mask: __m128i) -> u64 {
+            Ops::<Sse2, i64>::get_mask(mask)
+        }
+        for Scalar(mask: i64) -> u64 {
+            Ops::<Scalar, i64>::get_mask(mask)
+        }
+        for Neon(mask: int64x2_t) -> u64 {
+            Ops::<Neon, i64>::get_mask(mask)
+        }
+        for Wasm(mask: v128) -> u64 {
+            Ops::<Wasm, i64>::get_mask(mask)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_first_lanes<i8> {
+        for Avx512(len: usize) -> __mmask64 {
+            if len >= 64 {
+                u64::MAX
+            } else {
+                (1 << len) - 1
+            }
+        }
+        for Avx2Fma(len: usize) -> __m256i {
+            let offset = 64 - len.min(32);
+            Ops::<Avx2Fma, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Avx2(len: usize) -> __m256i {
+            let offset = 64 - len.min(32);
+            Ops::<Avx2, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Avx(len: usize) -> __m256i {
+            let offset = 64 - len.min(32);
+            Ops::<Avx, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Sse42(len: usize) -> __m128i {
+            let offset = 64 - len.min(16);
+            Ops::<Sse42, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Sse41(len: usize) -> __m128i {
+            let offset = 64 - len.min(16);
+            Ops::<Sse41, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Ssse3(len: usize) -> __m128i {
+            let offset = 64 - len.min(16);
+            Ops::<Ssse3, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Sse2(len: usize) -> __m128i {
+            let offset = 64 - len.min(16);
+            Ops::<Sse2, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Scalar(len: usize) -> i8 {
+            if len > 0 {
+                -1
+            } else {
+                0
+            }
+        }
+        for Neon(len: usize) -> int8x16_t {
+            let offset = 64 - len.min(16);
+            Ops::<Neon, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+        for Wasm(len: usize) -> v128 {
+            let offset = 64 - len.min(16);
+            Ops::<Wasm, i8>::load_unaligned(FIRST_LANES.as_ptr().add(offset))
+        }
+    }
+}
+
+impl_op! {
+    fn mask_first_lanes<i16> {
+        for Avx512(len: usize) -> __mmask32 {
+            if len >= 32 {
+                __mmask32::MAX
+            } else {
+                ((1u64 << len) - 1) as __mmask32
+            }
+        }
+        for Avx2Fma(len: usize) -> __m256i {
+            let offset = 64 - len.min(16) * 2;
+            Ops::<Avx2Fma, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Avx2(len: usize) -> __m256i {
+            let offset = 64 - len.min(16) * 2;
+            Ops::<Avx2, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Avx(len: usize) -> __m256i {
+            let offset = 64 - len.min(16) * 2;
+            Ops::<Avx, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Sse42(len: usize) -> __m128i {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Sse42, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Sse41(len: usize) -> __m128i {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Sse41, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Ssse3(len: usize) -> __m128i {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Ssse3, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Sse2(len: usize) -> __m128i {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Sse2, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Scalar(len: usize) -> i16 {
+            if len > 0 {
+                -1
+            } else {
+                0
+            }
+        }
+        for Neon(len: usize) -> int16x8_t {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Neon, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+        for Wasm(len: usize) -> v128 {
+            let offset = 64 - len.min(8) * 2;
+            Ops::<Wasm, i16>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i16)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_first_lanes<i32> {
+        for Avx512(len: usize) -> __mmask16 {
+            if len >= 16 {
+                __mmask16::MAX
+            } else {
+                ((1u64 << len) - 1) as __mmask16
+            }
+        }
+        for Avx2Fma(len: usize) -> __m256i {
+            let offset = 64 - len.min(8) * 4;
+            Ops::<Avx2Fma, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Avx2(len: usize) -> __m256i {
+            let offset = 64 - len.min(8) * 4;
+            Ops::<Avx2, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Avx(len: usize) -> __m256i {
+            let offset = 64 - len.min(8) * 4;
+            Ops::<Avx, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Sse42(len: usize) -> __m128i {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Sse42, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Sse41(len: usize) -> __m128i {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Sse41, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Ssse3(len: usize) -> __m128i {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Ssse3, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Sse2(len: usize) -> __m128i {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Sse2, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Scalar(len: usize) -> i32 {
+            if len > 0 {
+                -1
+            } else {
+                0
+            }
+        }
+        for Neon(len: usize) -> int32x4_t {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Neon, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+        for Wasm(len: usize) -> v128 {
+            let offset = 64 - len.min(4) * 4;
+            Ops::<Wasm, i32>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i32)
+        }
+    }
+}
+
+impl_op! {
+    fn mask_first_lanes<i64> {
+        for Avx512(len: usize) -> __mmask8 {
+            if len >= 8 {
+                __mmask8::MAX
+            } else {
+                ((1u64 << len) - 1) as __mmask8
+            }
+        }
+        for Avx2Fma(len: usize) -> __m256i {
+            let offset = 64 - len.min(4) * 8;
+            Ops::<Avx2Fma, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Avx2(len: usize) -> __m256i {
+            let offset = 64 - len.min(4) * 8;
+            Ops::<Avx2, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Avx(len: usize) -> __m256i {
+            let offset = 64 - len.min(4) * 8;
+            Ops::<Avx, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Sse42(len: usize) -> __m128i {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Sse42, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Sse41(len: usize) -> __m128i {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Sse41, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Ssse3(len: usize) -> __m128i {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Ssse3, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Sse2(len: usize) -> __m128i {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Sse2, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Scalar(len: usize) -> i64 {
+            if len > 0 {
+                -1
+            } else {
+                0
+            }
+        }
+        for Neon(len: usize) -> int64x2_t {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Neon, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+        for Wasm(len: usize) -> v128 {
+            let offset = 64 - len.min(2) * 8;
+            Ops::<Wasm, i64>::load_unaligned(FIRST_LANES.as_ptr().add(offset) as *const i64)
+        }
+    }
+}
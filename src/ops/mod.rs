@@ -6,7 +6,10 @@ use crate::engines::scalar::Scalar;
 #[cfg(target_arch = "wasm32")]
 use crate::engines::wasm32::Wasm;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-use crate::engines::{avx2::Avx2, sse2::Sse2, sse41::Sse41};
+use crate::engines::{
+    avx::Avx, avx2::Avx2, avx2fma::Avx2Fma, avx512::Avx512, sse2::Sse2, sse41::Sse41, sse42::Sse42,
+    ssse3::Ssse3,
+};
 
 use crate::libm_ext::FloatExt;
 use core::marker::PhantomData;
@@ -28,6 +31,14 @@ mod i32;
 
 mod i64;
 
+mod u8;
+
+mod u16;
+
+mod u32;
+
+mod u64;
+
 mod f32;
 
 mod f64;
@@ -36,27 +47,54 @@ mod bit;
 
 mod casts;
 
+mod mask;
+
 #[allow(non_camel_case_types)]
 pub struct binary;
 
 pub struct Ops<T, T2>(PhantomData<(T, T2)>);
 
 macro_rules! with_feature_flag {
+    (Avx512, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "avx512f,avx512bw,avx512dq")]
+        $($r)+
+    };
+    (Avx2Fma, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "avx2,fma")]
+        $($r)+
+    };
     (Avx2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         #[target_feature(enable = "avx2")]
         $($r)+
     };
+    (Avx, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "avx")]
+        $($r)+
+    };
     (Sse2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         #[target_feature(enable = "sse2")]
         $($r)+
     };
+    (Ssse3, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "ssse3")]
+        $($r)+
+    };
     (Sse41, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         #[target_feature(enable = "sse4.1")]
         $($r)+
     };
+    (Sse42, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[target_feature(enable = "sse4.2")]
+        $($r)+
+    };
     (Neon, $($r:tt)+) => {
         #[cfg(target_arch = "aarch64")]
         #[target_feature(enable = "neon")]
@@ -74,18 +112,38 @@ macro_rules! with_feature_flag {
 use with_feature_flag;
 
 macro_rules! with_cfg_flag {
+    (Avx512, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
+    (Avx2Fma, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
     (Avx2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         $($r)+
     };
+    (Avx, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
     (Sse2, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         $($r)+
     };
+    (Ssse3, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
     (Sse41, $($r:tt)+) => {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
         $($r)+
     };
+    (Sse42, $($r:tt)+) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        $($r)+
+    };
     (Neon, $($r:tt)+) => {
         #[cfg(target_arch = "aarch64")]
         $($r)+
@@ -151,3 +209,188 @@ macro_rules! impl_imm8_op {
     }
 }
 use impl_imm8_op;
+
+/// Splits a 256 bit integer vector into its low and high 128 bit halves. Only requires AVX.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! split_m256i {
+    ($a:expr) => {{
+        let a = $a;
+        (_mm256_castsi256_si128(a), _mm256_extractf128_si256(a, 1))
+    }};
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use split_m256i;
+
+/// Joins a low and a high 128 bit integer vector into a 256 bit one. Only requires AVX.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! join_m128i {
+    ($lo:expr, $hi:expr) => {
+        _mm256_set_m128i($hi, $lo)
+    };
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use join_m128i;
+
+/// Runs a 128 bit integer op on both halves of 256 bit integer vectors, for engines that have
+/// 256 bit registers but no 256 bit integer instructions (e.g. Avx).
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! m256i_run_on_halves {
+    ($op:path, $a:expr) => {{
+        let (a_lo, a_hi) = split_m256i!($a);
+        join_m128i!($op(a_lo), $op(a_hi))
+    }};
+    ($op:path, $a:expr, $b:expr) => {{
+        let (a_lo, a_hi) = split_m256i!($a);
+        let (b_lo, b_hi) = split_m256i!($b);
+        join_m128i!($op(a_lo, b_lo), $op(a_hi, b_hi))
+    }};
+    ($op:path, $a:expr, $b:expr, $c:expr) => {{
+        let (a_lo, a_hi) = split_m256i!($a);
+        let (b_lo, b_hi) = split_m256i!($b);
+        let (c_lo, c_hi) = split_m256i!($c);
+        join_m128i!($op(a_lo, b_lo, c_lo), $op(a_hi, b_hi, c_hi))
+    }};
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use m256i_run_on_halves;
+
+/// Shifts each 32 bit lane of a 128 bit vector by the count in the same lane of `counts`, for
+/// engines whose shifts take a single count for the whole vector.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! m128i_shift_epi32_by_lane {
+    ($shift:ident, $a:expr, $counts:expr) => {{
+        let (a, counts, zero) = ($a, $counts, _mm_setzero_si128());
+        let shifted0 = $shift(a, _mm_unpacklo_epi32(counts, zero));
+        let shifted1 = $shift(a, _mm_srli_epi64::<32>(counts));
+        let shifted2 = $shift(a, _mm_unpackhi_epi32(counts, zero));
+        let shifted3 = $shift(a, _mm_srli_si128::<12>(counts));
+        let low = _mm_castsi128_ps(_mm_unpacklo_epi32(shifted0, shifted1));
+        let high = _mm_castsi128_ps(_mm_unpackhi_epi32(shifted2, shifted3));
+        _mm_castps_si128(_mm_shuffle_ps::<0b11_00_11_00>(low, high))
+    }};
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use m128i_shift_epi32_by_lane;
+
+/// Loads the lanes set in `bits` one at a time and zeroes the others, for engines without a masked
+/// load of the lane width. The lanes that are not set are never read.
+macro_rules! load_masked_emulated {
+    ($ptr:expr, $bits:expr, [$ty:ty; $lanes:literal]) => {{
+        let (ptr, bits) = ($ptr, $bits);
+        let mut result = [0 as $ty; $lanes];
+        for (i, lane) in result.iter_mut().enumerate() {
+            if (bits >> i) & 1 == 1 {
+                *lane = *ptr.add(i);
+            }
+        }
+        core::mem::transmute_copy(&result)
+    }};
+}
+use load_masked_emulated;
+
+/// Stores the lanes set in `bits` one at a time, for engines without a masked store of the lane
+/// width. The lanes that are not set are never written.
+macro_rules! store_masked_emulated {
+    ($ptr:expr, $a:expr, $bits:expr, [$ty:ty; $lanes:literal]) => {{
+        let (ptr, bits) = ($ptr, $bits);
+        let values = core::mem::transmute_copy::<_, [$ty; $lanes]>(&$a);
+        for (i, value) in values.into_iter().enumerate() {
+            if (bits >> i) & 1 == 1 {
+                *ptr.add(i) = value;
+            }
+        }
+    }};
+}
+use store_masked_emulated;
+
+/// Splits each of the lane indices in `$indices` into the indices of the `$parts` narrower lanes it
+/// is made of, for shuffling wide lanes with a narrower shuffle.
+macro_rules! split_lane_indices {
+    ($indices:ident, $parts:literal, $ty:ty) => {
+        core::array::from_fn(|i| ($indices[i / $parts] as usize * $parts + i % $parts) as $ty)
+    };
+}
+use split_lane_indices;
+
+/// Builds a vector out of the lanes of `$a`, with lane `i` taking lane `$indices[i]`. The shuffles
+/// pass constant indices, so this folds into the fixed shuffle instructions, which take the lanes
+/// as immediates and can't be reached from the indices otherwise.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "wasm32"))]
+macro_rules! pick_lanes {
+    ($a:ident: $vector:ty, $indices:ident, [$ty:ty; $n:literal]) => {{
+        let lanes = core::mem::transmute::<$vector, [$ty; $n]>($a);
+        core::mem::transmute::<[$ty; $n], $vector>($indices.map(|i| lanes[i as usize]))
+    }};
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "wasm32"))]
+use pick_lanes;
+
+/// Expands `$body` once for every value in the range with `$by` bound to it as a constant, and
+/// picks the one equal to `$n`. Immediates can't be computed from const generics or from values,
+/// but they can be computed from `$by`, and the match folds away when `$n` is known.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! constify_imm {
+    ($n:expr, 0..=16, |$by:ident| $body:expr) => {
+        constify_imm!(@match $n, $by, $body, 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16)
+    };
+    ($n:expr, 0..=32, |$by:ident| $body:expr) => {
+        constify_imm!(
+            @match $n, $by, $body,
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+        )
+    };
+    ($n:expr, 0..=64, |$by:ident| $body:expr) => {
+        constify_imm!(
+            @match $n, $by, $body,
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+            33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61
+            62 63 64
+        )
+    };
+    (@match $n:expr, $by:ident, $body:expr, $($value:literal)*) => {
+        match $n {
+            $($value => {
+                const $by: i32 = $value;
+                $body
+            })*
+            _ => unreachable!(),
+        }
+    };
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use constify_imm;
+
+/// The number of ones in each value of a nibble, as a table for `_mm_shuffle_epi8` lookups.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+macro_rules! m128i_nibble_counts {
+    () => {
+        _mm_setr_epi8(0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4)
+    };
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use m128i_nibble_counts;
+
+/// Shifts each lane by the count in the same lane of `counts` one bit of the counts at a time, for
+/// engines without per-lane shifts. Every `(weight, to_sign)` step moves the bit worth `weight` up
+/// to the sign bit and shifts the lanes that have it set by `weight`.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "wasm32"))]
+macro_rules! shift_by_count_bits {
+    ($engine:ident, $ty:ident, $shift_const:ident, $a:expr, $counts:expr, $(($weight:literal, $to_sign:literal)),+) => {{
+        let counts = $counts;
+        let shifted = $a;
+        $(
+            let selected = Ops::<$engine, $ty>::lt(
+                Ops::<$engine, $ty>::shl_const::<$to_sign>(counts),
+                Ops::<$engine, $ty>::zeroes(),
+            );
+            let shifted = Ops::<$engine, $ty>::blendv(
+                shifted,
+                Ops::<$engine, $ty>::$shift_const::<$weight>(shifted),
+                selected,
+            );
+        )+
+        shifted
+    }};
+}
+#[cfg(any(target_arch = "x86_64", target_arch = "x86", target_arch = "wasm32"))]
+use shift_by_count_bits;
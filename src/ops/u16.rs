@@ -0,0 +1,1562 @@
+use super::*;
+
+impl_op! {
+    fn add<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::add(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::add(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::add(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::add(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::add(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vaddq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::sub(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::sub(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::sub(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::sub(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::sub(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vsubq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::mul(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::mul(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::mul(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::mul(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::mul(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmulq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::min(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::min(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::min(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_sub_epi16(a, _mm_subs_epu16(a, b))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.min(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vminq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::max(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::max(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::max(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_add_epi16(b, _mm_subs_epu16(a, b))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.max(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmaxq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u16) -> u16 {
+            a
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::eq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::eq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::eq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::eq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::eq(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a == b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vceqq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::neq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::neq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::neq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::neq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::neq(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a != b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vmvnq_u16(vceqq_u16(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmplt_epu16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::lt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi16(i16::MIN);
+            Ops::<Avx2, i16>::lt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::lt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse41, i16>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::lt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse2, i16>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a < b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcltq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmple_epu16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::lte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi16(i16::MIN);
+            Ops::<Avx2, i16>::lte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::lte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse41, i16>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::lte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse2, i16>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a <= b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcleq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpgt_epu16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::gt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi16(i16::MIN);
+            Ops::<Avx2, i16>::gt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::gt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse41, i16>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::gt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse2, i16>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a > b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcgtq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi16(_mm512_cmpge_epu16_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::gte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi16(i16::MIN);
+            Ops::<Avx2, i16>::gte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::gte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse41, i16>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::gte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi16(i16::MIN);
+            Ops::<Sse2, i16>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            if a >= b {
+                u16::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vcgeq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u16> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::blendv(a, b, mask)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::blendv(a, b, mask)
+        }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx, i16>::blendv(a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::blendv(a, b, mask)
+        }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::blendv(a, b, mask)
+        }
+        for Scalar(a: u16, b: u16, mask: u16) -> u16 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t, mask: uint16x8_t) -> uint16x8_t {
+            vbslq_u16(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i16>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::shl(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u16>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shl(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i16>::shl(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u16>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shl(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u16>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shl(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a << rhs
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vshlq_u16(a, vdupq_n_s16(rhs as i16))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u16> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i16>::shr(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u16>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i16>::shr(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i16>::shr(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u16>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i16>::shr(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u16>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i16>::shr(a, rhs)
+        }
+        for Scalar(a: u16, rhs: i32) -> u16 {
+            a >> rhs
+        }
+        for Neon(a: uint16x8_t, rhs: i32) -> uint16x8_t {
+            vshlq_u16(a, vdupq_n_s16(-rhs as i16))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i16>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shl_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::shl_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a << BY
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vshlq_n_u16(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u16, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shr_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::shr_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a >> BY
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vshrq_n_u16(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_var<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shl_var(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shl_var(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::shl_var(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shl_var(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vshlq_u16(a, vreinterpretq_s16_u16(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::shl_var(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            Self::shr_logical(a, b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            Self::shr_logical(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Self::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::shr_logical(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vshlq_u16(a, vnegq_s16(vreinterpretq_s16_u16(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::count_ones(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::count_ones(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::count_ones(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i16>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::count_ones(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.count_ones() as u16
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::count_ones(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::leading_zeros(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::leading_zeros(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::leading_zeros(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i16>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::leading_zeros(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a.leading_zeros() as u16
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::leading_zeros(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u16> {
+        for Avx512() -> __m512i {
+            Ops::<Avx512, i16>::zeroes()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, u16>::zeroes()
+        }
+        for Avx2() -> __m256i {
+            Ops::<Avx2, i16>::zeroes()
+        }
+        for Avx() -> __m256i {
+            Ops::<Avx, i16>::zeroes()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, u16>::zeroes()
+        }
+        for Sse41() -> __m128i {
+            Ops::<Sse41, i16>::zeroes()
+        }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, u16>::zeroes()
+        }
+        for Sse2() -> __m128i {
+            Ops::<Sse2, i16>::zeroes()
+        }
+        for Scalar() -> u16 {
+            0
+        }
+        for Neon() -> uint16x8_t {
+            vdupq_n_u16(0)
+        }
+        for Wasm() -> v128 {
+            Ops::<Wasm, i16>::zeroes()
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u16> {
+        for Avx512(val: u16) -> __m512i {
+            Ops::<Avx512, i16>::set1(val as i16)
+        }
+        for Avx2Fma(val: u16) -> __m256i {
+            Ops::<Avx2, u16>::set1(val)
+        }
+        for Avx2(val: u16) -> __m256i {
+            Ops::<Avx2, i16>::set1(val as i16)
+        }
+        for Avx(val: u16) -> __m256i {
+            Ops::<Avx, i16>::set1(val as i16)
+        }
+        for Sse42(val: u16) -> __m128i {
+            Ops::<Sse41, u16>::set1(val)
+        }
+        for Sse41(val: u16) -> __m128i {
+            Ops::<Sse41, i16>::set1(val as i16)
+        }
+        for Ssse3(val: u16) -> __m128i {
+            Ops::<Sse2, u16>::set1(val)
+        }
+        for Sse2(val: u16) -> __m128i {
+            Ops::<Sse2, i16>::set1(val as i16)
+        }
+        for Scalar(val: u16) -> u16 {
+            val
+        }
+        for Neon(val: u16) -> uint16x8_t {
+            vdupq_n_u16(val)
+        }
+        for Wasm(val: u16) -> v128 {
+            u16x8_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u16> {
+        for Avx512(ptr: *const u16) -> __m512i {
+            Ops::<Avx512, i16>::load_unaligned(ptr as *const i16)
+        }
+        for Avx2Fma(ptr: *const u16) -> __m256i {
+            Ops::<Avx2, u16>::load_unaligned(ptr)
+        }
+        for Avx2(ptr: *const u16) -> __m256i {
+            Ops::<Avx2, i16>::load_unaligned(ptr as *const i16)
+        }
+        for Avx(ptr: *const u16) -> __m256i {
+            Ops::<Avx, i16>::load_unaligned(ptr as *const i16)
+        }
+        for Sse42(ptr: *const u16) -> __m128i {
+            Ops::<Sse41, u16>::load_unaligned(ptr)
+        }
+        for Sse41(ptr: *const u16) -> __m128i {
+            Ops::<Sse41, i16>::load_unaligned(ptr as *const i16)
+        }
+        for Ssse3(ptr: *const u16) -> __m128i {
+            Ops::<Sse2, u16>::load_unaligned(ptr)
+        }
+        for Sse2(ptr: *const u16) -> __m128i {
+            Ops::<Sse2, i16>::load_unaligned(ptr as *const i16)
+        }
+        for Scalar(ptr: *const u16) -> u16 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u16) -> uint16x8_t {
+            vld1q_u16(ptr)
+        }
+        for Wasm(ptr: *const u16) -> v128 {
+            Ops::<Wasm, i16>::load_unaligned(ptr as *const i16)
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u16> {
+        for Avx512(ptr: *const u16) -> __m512i {
+            Ops::<Avx512, i16>::load_aligned(ptr as *const i16)
+        }
+        for Avx2Fma(ptr: *const u16) -> __m256i {
+            Ops::<Avx2, u16>::load_aligned(ptr)
+        }
+        for Avx2(ptr: *const u16) -> __m256i {
+            Ops::<Avx2, i16>::load_aligned(ptr as *const i16)
+        }
+        for Avx(ptr: *const u16) -> __m256i {
+            Ops::<Avx, i16>::load_aligned(ptr as *const i16)
+        }
+        for Sse42(ptr: *const u16) -> __m128i {
+            Ops::<Sse41, u16>::load_aligned(ptr)
+        }
+        for Sse41(ptr: *const u16) -> __m128i {
+            Ops::<Sse41, i16>::load_aligned(ptr as *const i16)
+        }
+        for Ssse3(ptr: *const u16) -> __m128i {
+            Ops::<Sse2, u16>::load_aligned(ptr)
+        }
+        for Sse2(ptr: *const u16) -> __m128i {
+            Ops::<Sse2, i16>::load_aligned(ptr as *const i16)
+        }
+        for Scalar(ptr: *const u16) -> u16 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u16) -> uint16x8_t {
+            vld1q_u16(ptr)
+        }
+        for Wasm(ptr: *const u16) -> v128 {
+            Ops::<Wasm, i16>::load_aligned(ptr as *const i16)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u16> {
+        for Avx512(ptr: *mut u16, a: __m512i) {
+            Ops::<Avx512, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+        for Avx2Fma(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx2, u16>::store_unaligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx2, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+        for Avx(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+        for Sse42(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse41, u16>::store_unaligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse41, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+        for Ssse3(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse2, u16>::store_unaligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse2, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+        for Scalar(ptr: *mut u16, a: u16) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u16, a: uint16x8_t) {
+            vst1q_u16(ptr, a)
+        }
+        for Wasm(ptr: *mut u16, a: v128) {
+            Ops::<Wasm, i16>::store_unaligned(ptr as *mut i16, a)
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u16> {
+        for Avx512(ptr: *mut u16, a: __m512i) {
+            Ops::<Avx512, i16>::store_aligned(ptr as *mut i16, a)
+        }
+        for Avx2Fma(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx2, u16>::store_aligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx2, i16>::store_aligned(ptr as *mut i16, a)
+        }
+        for Avx(ptr: *mut u16, a: __m256i) {
+            Ops::<Avx, i16>::store_aligned(ptr as *mut i16, a)
+        }
+        for Sse42(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse41, u16>::store_aligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse41, i16>::store_aligned(ptr as *mut i16, a)
+        }
+        for Ssse3(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse2, u16>::store_aligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u16, a: __m128i) {
+            Ops::<Sse2, i16>::store_aligned(ptr as *mut i16, a)
+        }
+        for Scalar(ptr: *mut u16, a: u16) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u16, a: uint16x8_t) {
+            vst1q_u16(ptr, a)
+        }
+        for Wasm(ptr: *mut u16, a: v128) {
+            Ops::<Wasm, i16>::store_aligned(ptr as *mut i16, a)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<u16> {
+        for Avx512(ptr: *const u16, mask: __mmask32) -> __m512i {
+            Ops::<Avx512, i16>::load_masked(ptr as *const i16, mask)
+        }
+        for Avx2Fma(ptr: *const u16, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const u16, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::load_masked(ptr as *const i16, mask)
+        }
+        for Avx(ptr: *const u16, mask: __m256i) -> __m256i {
+            Ops::<Avx, i16>::load_masked(ptr as *const i16, mask)
+        }
+        for Sse42(ptr: *const u16, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const u16, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::load_masked(ptr as *const i16, mask)
+        }
+        for Ssse3(ptr: *const u16, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const u16, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::load_masked(ptr as *const i16, mask)
+        }
+        for Scalar(ptr: *const u16, mask: i16) -> u16 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const u16, mask: int16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::load_masked(ptr as *const i16, mask))
+        }
+        for Wasm(ptr: *const u16, mask: v128) -> v128 {
+            Ops::<Wasm, i16>::load_masked(ptr as *const i16, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<u16> {
+        for Avx512(ptr: *mut u16, a: __m512i, mask: __mmask32) {
+            Ops::<Avx512, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+        for Avx2Fma(ptr: *mut u16, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, u16>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut u16, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+        for Avx(ptr: *mut u16, a: __m256i, mask: __m256i) {
+            Ops::<Avx, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+        for Sse42(ptr: *mut u16, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, u16>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut u16, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+        for Ssse3(ptr: *mut u16, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, u16>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut u16, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+        for Scalar(ptr: *mut u16, a: u16, mask: i16) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut u16, a: uint16x8_t, mask: int16x8_t) {
+            Ops::<Neon, i16>::store_masked(ptr as *mut i16, vreinterpretq_s16_u16(a), mask)
+        }
+        for Wasm(ptr: *mut u16, a: v128, mask: v128) {
+            Ops::<Wasm, i16>::store_masked(ptr as *mut i16, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<u16> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::permute(a, indices)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::permute(a, indices)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx, i16>::permute(a, indices)
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::permute(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Ssse3, i16>::permute(a, indices)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::permute(a, indices)
+        }
+        for Scalar(a: u16, indices: i16) -> u16 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: uint16x8_t, indices: int16x8_t) -> uint16x8_t {
+            let a = vreinterpretq_s16_u16(a);
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i16>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::zip_lo(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::zip_lo(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::zip_lo(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_lo(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            let _ = b;
+            a
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::zip_lo(vreinterpretq_s16_u16(a), vreinterpretq_s16_u16(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::zip_hi(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::zip_hi(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i16>::zip_hi(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::zip_hi(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            let _ = a;
+            b
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::zip_hi(vreinterpretq_s16_u16(a), vreinterpretq_s16_u16(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i16>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let (x, y) = Ops::<Avx512, i16>::unzip(a, b);
+            (x, y)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, u16>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx2, i16>::unzip(a, b);
+            (x, y)
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx, i16>::unzip(a, b);
+            (x, y)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, u16>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse41, i16>::unzip(a, b);
+            (x, y)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, u16>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse2, i16>::unzip(a, b);
+            (x, y)
+        }
+        for Scalar(a: u16, b: u16) -> (u16, u16) {
+            (a, b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> (uint16x8_t, uint16x8_t) {
+            let (x, y) = Ops::<Neon, i16>::unzip(vreinterpretq_s16_u16(a), vreinterpretq_s16_u16(b));
+            (vreinterpretq_u16_s16(x), vreinterpretq_u16_s16(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i16>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t) {
+            let loaded = vld2q_u16(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t) {
+            vst2q_u16(ptr, uint16x8x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t, uint16x8_t) {
+            let loaded = vld3q_u16(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) {
+            vst3q_u16(ptr, uint16x8x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<u16> {
+        for Neon(ptr: *const u16) -> (uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t) {
+            let loaded = vld4q_u16(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u16> {
+        for Neon(ptr: *mut u16, a: uint16x8_t, b: uint16x8_t, c: uint16x8_t, d: uint16x8_t) {
+            vst4q_u16(ptr, uint16x8x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::prefix_sum(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            a
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::prefix_sum(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<u16> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i16>::exclusive_prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i16>::exclusive_prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i16>::exclusive_prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i16>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i16>::exclusive_prefix_sum(a)
+        }
+        for Scalar(a: u16) -> u16 {
+            let _ = a;
+            0
+        }
+        for Neon(a: uint16x8_t) -> uint16x8_t {
+            vreinterpretq_u16_s16(Ops::<Neon, i16>::exclusive_prefix_sum(vreinterpretq_s16_u16(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i16>::exclusive_prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epu16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::saturating_add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epu16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::saturating_add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::saturating_add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::saturating_add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vqaddq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u16> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epu16(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u16>::saturating_sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epu16(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u16>::saturating_sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u16>::saturating_sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu16(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u16>::saturating_sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu16(a, b)
+        }
+        for Scalar(a: u16, b: u16) -> u16 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+            vqsubq_u16(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u16x8_sub_sat(a, b)
+        }
+    }
+}
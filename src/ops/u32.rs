@@ -0,0 +1,1540 @@
+use super::*;
+
+impl_op! {
+    fn add<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::add(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::add(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::add(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::add(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::add(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vaddq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::sub(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::sub(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::sub(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::sub(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::sub(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vsubq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::mul(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::mul(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::mul(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::mul(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::mul(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmulq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::min(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu32(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::min(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu32(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::min(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::blendv(b, a, Self::lt(a, b))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.min(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vminq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu32(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::max(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu32(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::max(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu32(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::max(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::blendv(b, a, Self::gt(a, b))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.max(b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmaxq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u32) -> u32 {
+            a
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::eq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::eq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::eq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::eq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::eq(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a == b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vceqq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::neq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::neq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::neq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::neq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::neq(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a != b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vmvnq_u32(vceqq_u32(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmplt_epu32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::lt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi32(i32::MIN);
+            Ops::<Avx2, i32>::lt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::lt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse41, i32>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::lt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse2, i32>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a < b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcltq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmple_epu32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::lte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi32(i32::MIN);
+            Ops::<Avx2, i32>::lte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::lte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse41, i32>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::lte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse2, i32>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a <= b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcleq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpgt_epu32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::gt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi32(i32::MIN);
+            Ops::<Avx2, i32>::gt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::gt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse41, i32>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::gt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse2, i32>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a > b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcgtq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi32(_mm512_cmpge_epu32_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::gte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi32(i32::MIN);
+            Ops::<Avx2, i32>::gte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u32>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::gte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse41, i32>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::gte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi32(i32::MIN);
+            Ops::<Sse2, i32>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            if a >= b {
+                u32::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vcgeq_u32(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u32x4_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u32> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::blendv(a, b, mask)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::blendv(a, b, mask)
+        }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx, i32>::blendv(a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::blendv(a, b, mask)
+        }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::blendv(a, b, mask)
+        }
+        for Scalar(a: u32, b: u32, mask: u32) -> u32 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t, mask: uint32x4_t) -> uint32x4_t {
+            vbslq_u32(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i32>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::shl(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u32>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shl(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i32>::shl(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u32>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shl(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u32>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shl(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a << rhs
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vshlq_u32(a, vdupq_n_s32(rhs as i32))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u32> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i32>::shr(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u32>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i32>::shr(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i32>::shr(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u32>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i32>::shr(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u32>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i32>::shr(a, rhs)
+        }
+        for Scalar(a: u32, rhs: i32) -> u32 {
+            a >> rhs
+        }
+        for Neon(a: uint32x4_t, rhs: i32) -> uint32x4_t {
+            vshlq_u32(a, vdupq_n_s32(-rhs))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i32>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shl_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::shl_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a << BY
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vshlq_n_u32(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u32, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shr_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::shr_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a >> BY
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vshrq_n_u32(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_var<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shl_var(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shl_var(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::shl_var(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shl_var(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vshlq_u32(a, vreinterpretq_s32_u32(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::shl_var(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            Self::shr_logical(a, b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            Self::shr_logical(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Self::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::shr_logical(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vshlq_u32(a, vnegq_s32(vreinterpretq_s32_u32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::count_ones(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::count_ones(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::count_ones(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i32>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::count_ones(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.count_ones()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::count_ones(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::leading_zeros(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::leading_zeros(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::leading_zeros(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i32>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::leading_zeros(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a.leading_zeros()
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::leading_zeros(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u32> {
+        for Avx512() -> __m512i {
+            Ops::<Avx512, i32>::zeroes()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, u32>::zeroes()
+        }
+        for Avx2() -> __m256i {
+            Ops::<Avx2, i32>::zeroes()
+        }
+        for Avx() -> __m256i {
+            Ops::<Avx, i32>::zeroes()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, u32>::zeroes()
+        }
+        for Sse41() -> __m128i {
+            Ops::<Sse41, i32>::zeroes()
+        }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, u32>::zeroes()
+        }
+        for Sse2() -> __m128i {
+            Ops::<Sse2, i32>::zeroes()
+        }
+        for Scalar() -> u32 {
+            0
+        }
+        for Neon() -> uint32x4_t {
+            vdupq_n_u32(0)
+        }
+        for Wasm() -> v128 {
+            Ops::<Wasm, i32>::zeroes()
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u32> {
+        for Avx512(val: u32) -> __m512i {
+            Ops::<Avx512, i32>::set1(val as i32)
+        }
+        for Avx2Fma(val: u32) -> __m256i {
+            Ops::<Avx2, u32>::set1(val)
+        }
+        for Avx2(val: u32) -> __m256i {
+            Ops::<Avx2, i32>::set1(val as i32)
+        }
+        for Avx(val: u32) -> __m256i {
+            Ops::<Avx, i32>::set1(val as i32)
+        }
+        for Sse42(val: u32) -> __m128i {
+            Ops::<Sse41, u32>::set1(val)
+        }
+        for Sse41(val: u32) -> __m128i {
+            Ops::<Sse41, i32>::set1(val as i32)
+        }
+        for Ssse3(val: u32) -> __m128i {
+            Ops::<Sse2, u32>::set1(val)
+        }
+        for Sse2(val: u32) -> __m128i {
+            Ops::<Sse2, i32>::set1(val as i32)
+        }
+        for Scalar(val: u32) -> u32 {
+            val
+        }
+        for Neon(val: u32) -> uint32x4_t {
+            vdupq_n_u32(val)
+        }
+        for Wasm(val: u32) -> v128 {
+            u32x4_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u32> {
+        for Avx512(ptr: *const u32) -> __m512i {
+            Ops::<Avx512, i32>::load_unaligned(ptr as *const i32)
+        }
+        for Avx2Fma(ptr: *const u32) -> __m256i {
+            Ops::<Avx2, u32>::load_unaligned(ptr)
+        }
+        for Avx2(ptr: *const u32) -> __m256i {
+            Ops::<Avx2, i32>::load_unaligned(ptr as *const i32)
+        }
+        for Avx(ptr: *const u32) -> __m256i {
+            Ops::<Avx, i32>::load_unaligned(ptr as *const i32)
+        }
+        for Sse42(ptr: *const u32) -> __m128i {
+            Ops::<Sse41, u32>::load_unaligned(ptr)
+        }
+        for Sse41(ptr: *const u32) -> __m128i {
+            Ops::<Sse41, i32>::load_unaligned(ptr as *const i32)
+        }
+        for Ssse3(ptr: *const u32) -> __m128i {
+            Ops::<Sse2, u32>::load_unaligned(ptr)
+        }
+        for Sse2(ptr: *const u32) -> __m128i {
+            Ops::<Sse2, i32>::load_unaligned(ptr as *const i32)
+        }
+        for Scalar(ptr: *const u32) -> u32 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u32) -> uint32x4_t {
+            vld1q_u32(ptr)
+        }
+        for Wasm(ptr: *const u32) -> v128 {
+            Ops::<Wasm, i32>::load_unaligned(ptr as *const i32)
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u32> {
+        for Avx512(ptr: *const u32) -> __m512i {
+            Ops::<Avx512, i32>::load_aligned(ptr as *const i32)
+        }
+        for Avx2Fma(ptr: *const u32) -> __m256i {
+            Ops::<Avx2, u32>::load_aligned(ptr)
+        }
+        for Avx2(ptr: *const u32) -> __m256i {
+            Ops::<Avx2, i32>::load_aligned(ptr as *const i32)
+        }
+        for Avx(ptr: *const u32) -> __m256i {
+            Ops::<Avx, i32>::load_aligned(ptr as *const i32)
+        }
+        for Sse42(ptr: *const u32) -> __m128i {
+            Ops::<Sse41, u32>::load_aligned(ptr)
+        }
+        for Sse41(ptr: *const u32) -> __m128i {
+            Ops::<Sse41, i32>::load_aligned(ptr as *const i32)
+        }
+        for Ssse3(ptr: *const u32) -> __m128i {
+            Ops::<Sse2, u32>::load_aligned(ptr)
+        }
+        for Sse2(ptr: *const u32) -> __m128i {
+            Ops::<Sse2, i32>::load_aligned(ptr as *const i32)
+        }
+        for Scalar(ptr: *const u32) -> u32 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u32) -> uint32x4_t {
+            vld1q_u32(ptr)
+        }
+        for Wasm(ptr: *const u32) -> v128 {
+            Ops::<Wasm, i32>::load_aligned(ptr as *const i32)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u32> {
+        for Avx512(ptr: *mut u32, a: __m512i) {
+            Ops::<Avx512, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+        for Avx2Fma(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx2, u32>::store_unaligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx2, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+        for Avx(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+        for Sse42(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse41, u32>::store_unaligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse41, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+        for Ssse3(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse2, u32>::store_unaligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse2, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+        for Scalar(ptr: *mut u32, a: u32) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u32, a: uint32x4_t) {
+            vst1q_u32(ptr, a)
+        }
+        for Wasm(ptr: *mut u32, a: v128) {
+            Ops::<Wasm, i32>::store_unaligned(ptr as *mut i32, a)
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u32> {
+        for Avx512(ptr: *mut u32, a: __m512i) {
+            Ops::<Avx512, i32>::store_aligned(ptr as *mut i32, a)
+        }
+        for Avx2Fma(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx2, u32>::store_aligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx2, i32>::store_aligned(ptr as *mut i32, a)
+        }
+        for Avx(ptr: *mut u32, a: __m256i) {
+            Ops::<Avx, i32>::store_aligned(ptr as *mut i32, a)
+        }
+        for Sse42(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse41, u32>::store_aligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse41, i32>::store_aligned(ptr as *mut i32, a)
+        }
+        for Ssse3(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse2, u32>::store_aligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u32, a: __m128i) {
+            Ops::<Sse2, i32>::store_aligned(ptr as *mut i32, a)
+        }
+        for Scalar(ptr: *mut u32, a: u32) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u32, a: uint32x4_t) {
+            vst1q_u32(ptr, a)
+        }
+        for Wasm(ptr: *mut u32, a: v128) {
+            Ops::<Wasm, i32>::store_aligned(ptr as *mut i32, a)
+        }
+    }
+}
+
+impl_op! {
+    fn gather_unchecked<u32> {
+        for Avx512(base: *const u32, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::gather_unchecked(base as *const i32, indices)
+        }
+        for Avx2Fma(base: *const u32, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const u32, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::gather_unchecked(base as *const i32, indices)
+        }
+        for Avx(base: *const u32, indices: __m256i) -> __m256i {
+            Ops::<Avx, i32>::gather_unchecked(base as *const i32, indices)
+        }
+        for Sse42(base: *const u32, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const u32, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::gather_unchecked(base as *const i32, indices)
+        }
+        for Ssse3(base: *const u32, indices: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const u32, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::gather_unchecked(base as *const i32, indices)
+        }
+        for Scalar(base: *const u32, indices: i32) -> u32 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const u32, indices: int32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::gather_unchecked(base as *const i32, indices))
+        }
+        for Wasm(base: *const u32, indices: v128) -> v128 {
+            Ops::<Wasm, i32>::gather_unchecked(base as *const i32, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<u32> {
+        for Avx512(base: *mut u32, indices: __m512i, val: __m512i) {
+            Ops::<Avx512, i32>::scatter(base as *mut i32, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<u32> {
+        for Avx512(base: *mut u32, indices: __m512i, val: __m512i, mask: __mmask16) {
+            Ops::<Avx512, i32>::scatter_masked(base as *mut i32, indices, val, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<u32> {
+        for Avx512(ptr: *const u32, mask: __mmask16) -> __m512i {
+            Ops::<Avx512, i32>::load_masked(ptr as *const i32, mask)
+        }
+        for Avx2Fma(ptr: *const u32, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const u32, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::load_masked(ptr as *const i32, mask)
+        }
+        for Avx(ptr: *const u32, mask: __m256i) -> __m256i {
+            Ops::<Avx, i32>::load_masked(ptr as *const i32, mask)
+        }
+        for Sse42(ptr: *const u32, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const u32, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::load_masked(ptr as *const i32, mask)
+        }
+        for Ssse3(ptr: *const u32, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const u32, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::load_masked(ptr as *const i32, mask)
+        }
+        for Scalar(ptr: *const u32, mask: i32) -> u32 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const u32, mask: int32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::load_masked(ptr as *const i32, mask))
+        }
+        for Wasm(ptr: *const u32, mask: v128) -> v128 {
+            Ops::<Wasm, i32>::load_masked(ptr as *const i32, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<u32> {
+        for Avx512(ptr: *mut u32, a: __m512i, mask: __mmask16) {
+            Ops::<Avx512, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+        for Avx2Fma(ptr: *mut u32, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, u32>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut u32, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+        for Avx(ptr: *mut u32, a: __m256i, mask: __m256i) {
+            Ops::<Avx, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+        for Sse42(ptr: *mut u32, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, u32>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut u32, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+        for Ssse3(ptr: *mut u32, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, u32>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut u32, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+        for Scalar(ptr: *mut u32, a: u32, mask: i32) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut u32, a: uint32x4_t, mask: int32x4_t) {
+            Ops::<Neon, i32>::store_masked(ptr as *mut i32, vreinterpretq_s32_u32(a), mask)
+        }
+        for Wasm(ptr: *mut u32, a: v128, mask: v128) {
+            Ops::<Wasm, i32>::store_masked(ptr as *mut i32, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<u32> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::permute(a, indices)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::permute(a, indices)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx, i32>::permute(a, indices)
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::permute(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Ssse3, i32>::permute(a, indices)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::permute(a, indices)
+        }
+        for Scalar(a: u32, indices: i32) -> u32 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: uint32x4_t, indices: int32x4_t) -> uint32x4_t {
+            let a = vreinterpretq_s32_u32(a);
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i32>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::zip_lo(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::zip_lo(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::zip_lo(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_lo(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            let _ = b;
+            a
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::zip_lo(vreinterpretq_s32_u32(a), vreinterpretq_s32_u32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::zip_hi(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::zip_hi(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i32>::zip_hi(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::zip_hi(a, b)
+        }
+        for Scalar(a: u32, b: u32) -> u32 {
+            let _ = a;
+            b
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::zip_hi(vreinterpretq_s32_u32(a), vreinterpretq_s32_u32(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i32>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<u32> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let (x, y) = Ops::<Avx512, i32>::unzip(a, b);
+            (x, y)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, u32>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx2, i32>::unzip(a, b);
+            (x, y)
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx, i32>::unzip(a, b);
+            (x, y)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, u32>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse41, i32>::unzip(a, b);
+            (x, y)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, u32>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse2, i32>::unzip(a, b);
+            (x, y)
+        }
+        for Scalar(a: u32, b: u32) -> (u32, u32) {
+            (a, b)
+        }
+        for Neon(a: uint32x4_t, b: uint32x4_t) -> (uint32x4_t, uint32x4_t) {
+            let (x, y) = Ops::<Neon, i32>::unzip(vreinterpretq_s32_u32(a), vreinterpretq_s32_u32(b));
+            (vreinterpretq_u32_s32(x), vreinterpretq_u32_s32(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i32>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t) {
+            let loaded = vld2q_u32(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t) {
+            vst2q_u32(ptr, uint32x4x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t, uint32x4_t) {
+            let loaded = vld3q_u32(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) {
+            vst3q_u32(ptr, uint32x4x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<u32> {
+        for Neon(ptr: *const u32) -> (uint32x4_t, uint32x4_t, uint32x4_t, uint32x4_t) {
+            let loaded = vld4q_u32(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u32> {
+        for Neon(ptr: *mut u32, a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, d: uint32x4_t) {
+            vst4q_u32(ptr, uint32x4x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::prefix_sum(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            a
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::prefix_sum(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<u32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i32>::exclusive_prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u32>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i32>::exclusive_prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i32>::exclusive_prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u32>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i32>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u32>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i32>::exclusive_prefix_sum(a)
+        }
+        for Scalar(a: u32) -> u32 {
+            let _ = a;
+            0
+        }
+        for Neon(a: uint32x4_t) -> uint32x4_t {
+            vreinterpretq_u32_s32(Ops::<Neon, i32>::exclusive_prefix_sum(vreinterpretq_s32_u32(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i32>::exclusive_prefix_sum(a)
+        }
+    }
+}
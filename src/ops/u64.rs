@@ -0,0 +1,1550 @@
+use super::*;
+
+impl_op! {
+    fn add<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::add(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::add(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::add(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::add(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::add(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vaddq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::sub(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::sub(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::sub(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::sub(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::sub(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vsubq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::mul(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::mul(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::mul(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::mul(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::mul(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            let a = vreinterpretq_s64_u64(a);
+            let b = vreinterpretq_s64_u64(b);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::mul(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::min(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::blendv(b, a, Self::lt(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse42, i64>::blendv(b, a, Self::lt(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::blendv(b, a, Self::lt(a, b))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::min(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::blendv(b, a, Self::lt(a, b))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.min(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(vcltq_u64(a, b), a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_bitselect(a, b, Self::lt(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn max<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu64(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::max(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::blendv(b, a, Self::gt(a, b))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse42, i64>::blendv(b, a, Self::gt(a, b))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::blendv(b, a, Self::gt(a, b))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::max(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::blendv(b, a, Self::gt(a, b))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.max(b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(vcgtq_u64(a, b), a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            v128_bitselect(a, b, Self::gt(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u64) -> u64 {
+            a
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::eq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::eq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::eq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::eq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::eq(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a == b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vceqq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::neq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::neq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::neq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::neq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::neq(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a != b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(vceqq_u64(a, b))))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmplt_epu64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::lt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi64x(i64::MIN);
+            Ops::<Avx2, i64>::lt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse42, i64>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse41, i64>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::lt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse2, i64>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a < b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcltq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i64x2_splat(i64::MIN);
+            i64x2_lt(v128_xor(a, bias), v128_xor(b, bias))
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmple_epu64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::lte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi64x(i64::MIN);
+            Ops::<Avx2, i64>::lte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse42, i64>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse41, i64>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::lte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse2, i64>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a <= b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcleq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i64x2_splat(i64::MIN);
+            i64x2_le(v128_xor(a, bias), v128_xor(b, bias))
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpgt_epu64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::gt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi64x(i64::MIN);
+            Ops::<Avx2, i64>::gt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse42, i64>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse41, i64>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::gt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse2, i64>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a > b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcgtq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i64x2_splat(i64::MIN);
+            i64x2_gt(v128_xor(a, bias), v128_xor(b, bias))
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi64(_mm512_cmpge_epu64_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::gte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi64x(i64::MIN);
+            Ops::<Avx2, i64>::gte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse42, u64>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse42, i64>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse41, i64>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::gte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi64x(i64::MIN);
+            Ops::<Sse2, i64>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            if a >= b {
+                u64::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vcgeq_u64(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            let bias = i64x2_splat(i64::MIN);
+            i64x2_ge(v128_xor(a, bias), v128_xor(b, bias))
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u64> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::blendv(a, b, mask)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::blendv(a, b, mask)
+        }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx, i64>::blendv(a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::blendv(a, b, mask)
+        }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::blendv(a, b, mask)
+        }
+        for Scalar(a: u64, b: u64, mask: u64) -> u64 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t, mask: uint64x2_t) -> uint64x2_t {
+            vbslq_u64(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i64>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::shl(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u64>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shl(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i64>::shl(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u64>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shl(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u64>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shl(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a << rhs
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vshlq_u64(a, vdupq_n_s64(rhs as i64))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u64> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i64>::shr(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u64>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i64>::shr(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i64>::shr(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u64>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i64>::shr(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u64>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i64>::shr(a, rhs)
+        }
+        for Scalar(a: u64, rhs: i32) -> u64 {
+            a >> rhs
+        }
+        for Neon(a: uint64x2_t, rhs: i32) -> uint64x2_t {
+            vshlq_u64(a, vdupq_n_s64(-rhs as i64))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i64>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shl_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::shl_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a << BY
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vshlq_n_u64(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u64, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shr_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::shr_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a >> BY
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vshrq_n_u64(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_var<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shl_var(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shl_var(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::shl_var(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shl_var(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vshlq_u64(a, vreinterpretq_s64_u64(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::shl_var(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            Self::shr_logical(a, b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            Self::shr_logical(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Self::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::shr_logical(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vshlq_u64(a, vnegq_s64(vreinterpretq_s64_u64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::count_ones(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::count_ones(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::count_ones(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i64>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::count_ones(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.count_ones() as u64
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::count_ones(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::leading_zeros(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::leading_zeros(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::leading_zeros(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i64>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::leading_zeros(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a.leading_zeros() as u64
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::leading_zeros(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u64> {
+        for Avx512() -> __m512i {
+            Ops::<Avx512, i64>::zeroes()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, u64>::zeroes()
+        }
+        for Avx2() -> __m256i {
+            Ops::<Avx2, i64>::zeroes()
+        }
+        for Avx() -> __m256i {
+            Ops::<Avx, i64>::zeroes()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, u64>::zeroes()
+        }
+        for Sse41() -> __m128i {
+            Ops::<Sse41, i64>::zeroes()
+        }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, u64>::zeroes()
+        }
+        for Sse2() -> __m128i {
+            Ops::<Sse2, i64>::zeroes()
+        }
+        for Scalar() -> u64 {
+            0
+        }
+        for Neon() -> uint64x2_t {
+            vdupq_n_u64(0)
+        }
+        for Wasm() -> v128 {
+            Ops::<Wasm, i64>::zeroes()
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u64> {
+        for Avx512(val: u64) -> __m512i {
+            Ops::<Avx512, i64>::set1(val as i64)
+        }
+        for Avx2Fma(val: u64) -> __m256i {
+            Ops::<Avx2, u64>::set1(val)
+        }
+        for Avx2(val: u64) -> __m256i {
+            Ops::<Avx2, i64>::set1(val as i64)
+        }
+        for Avx(val: u64) -> __m256i {
+            Ops::<Avx, i64>::set1(val as i64)
+        }
+        for Sse42(val: u64) -> __m128i {
+            Ops::<Sse41, u64>::set1(val)
+        }
+        for Sse41(val: u64) -> __m128i {
+            Ops::<Sse41, i64>::set1(val as i64)
+        }
+        for Ssse3(val: u64) -> __m128i {
+            Ops::<Sse2, u64>::set1(val)
+        }
+        for Sse2(val: u64) -> __m128i {
+            Ops::<Sse2, i64>::set1(val as i64)
+        }
+        for Scalar(val: u64) -> u64 {
+            val
+        }
+        for Neon(val: u64) -> uint64x2_t {
+            vdupq_n_u64(val)
+        }
+        for Wasm(val: u64) -> v128 {
+            u64x2_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u64> {
+        for Avx512(ptr: *const u64) -> __m512i {
+            Ops::<Avx512, i64>::load_unaligned(ptr as *const i64)
+        }
+        for Avx2Fma(ptr: *const u64) -> __m256i {
+            Ops::<Avx2, u64>::load_unaligned(ptr)
+        }
+        for Avx2(ptr: *const u64) -> __m256i {
+            Ops::<Avx2, i64>::load_unaligned(ptr as *const i64)
+        }
+        for Avx(ptr: *const u64) -> __m256i {
+            Ops::<Avx, i64>::load_unaligned(ptr as *const i64)
+        }
+        for Sse42(ptr: *const u64) -> __m128i {
+            Ops::<Sse41, u64>::load_unaligned(ptr)
+        }
+        for Sse41(ptr: *const u64) -> __m128i {
+            Ops::<Sse41, i64>::load_unaligned(ptr as *const i64)
+        }
+        for Ssse3(ptr: *const u64) -> __m128i {
+            Ops::<Sse2, u64>::load_unaligned(ptr)
+        }
+        for Sse2(ptr: *const u64) -> __m128i {
+            Ops::<Sse2, i64>::load_unaligned(ptr as *const i64)
+        }
+        for Scalar(ptr: *const u64) -> u64 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u64) -> uint64x2_t {
+            vld1q_u64(ptr)
+        }
+        for Wasm(ptr: *const u64) -> v128 {
+            Ops::<Wasm, i64>::load_unaligned(ptr as *const i64)
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u64> {
+        for Avx512(ptr: *const u64) -> __m512i {
+            Ops::<Avx512, i64>::load_aligned(ptr as *const i64)
+        }
+        for Avx2Fma(ptr: *const u64) -> __m256i {
+            Ops::<Avx2, u64>::load_aligned(ptr)
+        }
+        for Avx2(ptr: *const u64) -> __m256i {
+            Ops::<Avx2, i64>::load_aligned(ptr as *const i64)
+        }
+        for Avx(ptr: *const u64) -> __m256i {
+            Ops::<Avx, i64>::load_aligned(ptr as *const i64)
+        }
+        for Sse42(ptr: *const u64) -> __m128i {
+            Ops::<Sse41, u64>::load_aligned(ptr)
+        }
+        for Sse41(ptr: *const u64) -> __m128i {
+            Ops::<Sse41, i64>::load_aligned(ptr as *const i64)
+        }
+        for Ssse3(ptr: *const u64) -> __m128i {
+            Ops::<Sse2, u64>::load_aligned(ptr)
+        }
+        for Sse2(ptr: *const u64) -> __m128i {
+            Ops::<Sse2, i64>::load_aligned(ptr as *const i64)
+        }
+        for Scalar(ptr: *const u64) -> u64 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u64) -> uint64x2_t {
+            vld1q_u64(ptr)
+        }
+        for Wasm(ptr: *const u64) -> v128 {
+            Ops::<Wasm, i64>::load_aligned(ptr as *const i64)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u64> {
+        for Avx512(ptr: *mut u64, a: __m512i) {
+            Ops::<Avx512, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+        for Avx2Fma(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx2, u64>::store_unaligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx2, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+        for Avx(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+        for Sse42(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse41, u64>::store_unaligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse41, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+        for Ssse3(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse2, u64>::store_unaligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse2, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+        for Scalar(ptr: *mut u64, a: u64) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u64, a: uint64x2_t) {
+            vst1q_u64(ptr, a)
+        }
+        for Wasm(ptr: *mut u64, a: v128) {
+            Ops::<Wasm, i64>::store_unaligned(ptr as *mut i64, a)
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u64> {
+        for Avx512(ptr: *mut u64, a: __m512i) {
+            Ops::<Avx512, i64>::store_aligned(ptr as *mut i64, a)
+        }
+        for Avx2Fma(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx2, u64>::store_aligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx2, i64>::store_aligned(ptr as *mut i64, a)
+        }
+        for Avx(ptr: *mut u64, a: __m256i) {
+            Ops::<Avx, i64>::store_aligned(ptr as *mut i64, a)
+        }
+        for Sse42(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse41, u64>::store_aligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse41, i64>::store_aligned(ptr as *mut i64, a)
+        }
+        for Ssse3(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse2, u64>::store_aligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u64, a: __m128i) {
+            Ops::<Sse2, i64>::store_aligned(ptr as *mut i64, a)
+        }
+        for Scalar(ptr: *mut u64, a: u64) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u64, a: uint64x2_t) {
+            vst1q_u64(ptr, a)
+        }
+        for Wasm(ptr: *mut u64, a: v128) {
+            Ops::<Wasm, i64>::store_aligned(ptr as *mut i64, a)
+        }
+    }
+}
+
+impl_op! {
+    fn gather_unchecked<u64> {
+        for Avx512(base: *const u64, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::gather_unchecked(base as *const i64, indices)
+        }
+        for Avx2Fma(base: *const u64, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::gather_unchecked(base, indices)
+        }
+        for Avx2(base: *const u64, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::gather_unchecked(base as *const i64, indices)
+        }
+        for Avx(base: *const u64, indices: __m256i) -> __m256i {
+            Ops::<Avx, i64>::gather_unchecked(base as *const i64, indices)
+        }
+        for Sse42(base: *const u64, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::gather_unchecked(base, indices)
+        }
+        for Sse41(base: *const u64, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::gather_unchecked(base as *const i64, indices)
+        }
+        for Ssse3(base: *const u64, indices: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::gather_unchecked(base, indices)
+        }
+        for Sse2(base: *const u64, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::gather_unchecked(base as *const i64, indices)
+        }
+        for Scalar(base: *const u64, indices: i64) -> u64 {
+            *base.add(indices as usize)
+        }
+        for Neon(base: *const u64, indices: int64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::gather_unchecked(base as *const i64, indices))
+        }
+        for Wasm(base: *const u64, indices: v128) -> v128 {
+            Ops::<Wasm, i64>::gather_unchecked(base as *const i64, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter<u64> {
+        for Avx512(base: *mut u64, indices: __m512i, val: __m512i) {
+            Ops::<Avx512, i64>::scatter(base as *mut i64, indices, val)
+        }
+    }
+}
+
+impl_op! {
+    fn scatter_masked<u64> {
+        for Avx512(base: *mut u64, indices: __m512i, val: __m512i, mask: __mmask8) {
+            Ops::<Avx512, i64>::scatter_masked(base as *mut i64, indices, val, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<u64> {
+        for Avx512(ptr: *const u64, mask: __mmask8) -> __m512i {
+            Ops::<Avx512, i64>::load_masked(ptr as *const i64, mask)
+        }
+        for Avx2Fma(ptr: *const u64, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const u64, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::load_masked(ptr as *const i64, mask)
+        }
+        for Avx(ptr: *const u64, mask: __m256i) -> __m256i {
+            Ops::<Avx, i64>::load_masked(ptr as *const i64, mask)
+        }
+        for Sse42(ptr: *const u64, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const u64, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::load_masked(ptr as *const i64, mask)
+        }
+        for Ssse3(ptr: *const u64, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const u64, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::load_masked(ptr as *const i64, mask)
+        }
+        for Scalar(ptr: *const u64, mask: i64) -> u64 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const u64, mask: int64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::load_masked(ptr as *const i64, mask))
+        }
+        for Wasm(ptr: *const u64, mask: v128) -> v128 {
+            Ops::<Wasm, i64>::load_masked(ptr as *const i64, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<u64> {
+        for Avx512(ptr: *mut u64, a: __m512i, mask: __mmask8) {
+            Ops::<Avx512, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+        for Avx2Fma(ptr: *mut u64, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, u64>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut u64, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+        for Avx(ptr: *mut u64, a: __m256i, mask: __m256i) {
+            Ops::<Avx, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+        for Sse42(ptr: *mut u64, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, u64>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut u64, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+        for Ssse3(ptr: *mut u64, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, u64>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut u64, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+        for Scalar(ptr: *mut u64, a: u64, mask: i64) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut u64, a: uint64x2_t, mask: int64x2_t) {
+            Ops::<Neon, i64>::store_masked(ptr as *mut i64, vreinterpretq_s64_u64(a), mask)
+        }
+        for Wasm(ptr: *mut u64, a: v128, mask: v128) {
+            Ops::<Wasm, i64>::store_masked(ptr as *mut i64, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<u64> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::permute(a, indices)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::permute(a, indices)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx, i64>::permute(a, indices)
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::permute(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Ssse3, i64>::permute(a, indices)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::permute(a, indices)
+        }
+        for Scalar(a: u64, indices: i64) -> u64 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: uint64x2_t, indices: int64x2_t) -> uint64x2_t {
+            let a = vreinterpretq_s64_u64(a);
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i64>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::zip_lo(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::zip_lo(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::zip_lo(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_lo(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            let _ = b;
+            a
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::zip_lo(vreinterpretq_s64_u64(a), vreinterpretq_s64_u64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::zip_hi(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::zip_hi(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i64>::zip_hi(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::zip_hi(a, b)
+        }
+        for Scalar(a: u64, b: u64) -> u64 {
+            let _ = a;
+            b
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::zip_hi(vreinterpretq_s64_u64(a), vreinterpretq_s64_u64(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i64>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<u64> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let (x, y) = Ops::<Avx512, i64>::unzip(a, b);
+            (x, y)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, u64>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx2, i64>::unzip(a, b);
+            (x, y)
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx, i64>::unzip(a, b);
+            (x, y)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, u64>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse41, i64>::unzip(a, b);
+            (x, y)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, u64>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse2, i64>::unzip(a, b);
+            (x, y)
+        }
+        for Scalar(a: u64, b: u64) -> (u64, u64) {
+            (a, b)
+        }
+        for Neon(a: uint64x2_t, b: uint64x2_t) -> (uint64x2_t, uint64x2_t) {
+            let (x, y) = Ops::<Neon, i64>::unzip(vreinterpretq_s64_u64(a), vreinterpretq_s64_u64(b));
+            (vreinterpretq_u64_s64(x), vreinterpretq_u64_s64(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i64>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t) {
+            let loaded = vld2q_u64(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t) {
+            vst2q_u64(ptr, uint64x2x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t, uint64x2_t) {
+            let loaded = vld3q_u64(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) {
+            vst3q_u64(ptr, uint64x2x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<u64> {
+        for Neon(ptr: *const u64) -> (uint64x2_t, uint64x2_t, uint64x2_t, uint64x2_t) {
+            let loaded = vld4q_u64(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u64> {
+        for Neon(ptr: *mut u64, a: uint64x2_t, b: uint64x2_t, c: uint64x2_t, d: uint64x2_t) {
+            vst4q_u64(ptr, uint64x2x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::prefix_sum(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            a
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::prefix_sum(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<u64> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i64>::exclusive_prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u64>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i64>::exclusive_prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i64>::exclusive_prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u64>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i64>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u64>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i64>::exclusive_prefix_sum(a)
+        }
+        for Scalar(a: u64) -> u64 {
+            let _ = a;
+            0
+        }
+        for Neon(a: uint64x2_t) -> uint64x2_t {
+            vreinterpretq_u64_s64(Ops::<Neon, i64>::exclusive_prefix_sum(vreinterpretq_s64_u64(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i64>::exclusive_prefix_sum(a)
+        }
+    }
+}
@@ -0,0 +1,1562 @@
+use super::*;
+
+impl_op! {
+    fn add<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::add(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::add(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::add(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::add(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::add(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_add(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vaddq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::add(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn sub<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::sub(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::sub(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::sub(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::sub(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::sub(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_sub(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vsubq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::sub(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn mul<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::mul(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::mul(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::mul(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::mul(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::mul(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::mul(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::mul(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::mul(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_mul(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmulq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::mul(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn min<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_min_epu8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::min(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_min_epu8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::min, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::min(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::min(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_min_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.min(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vminq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_min(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn max<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_max_epu8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::max(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_max_epu8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::max, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::max(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::max(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_max_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.max(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmaxq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_max(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn abs<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            a
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            a
+        }
+        for Avx(a: __m256i) -> __m256i {
+            a
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            a
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            a
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            a
+        }
+        for Scalar(a: u8) -> u8 {
+            a
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            a
+        }
+        for Wasm(a: v128) -> v128 {
+            a
+        }
+    }
+}
+
+impl_op! {
+    fn eq<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::eq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::eq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::eq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::eq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::eq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::eq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::eq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::eq(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a == b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vceqq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::eq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn neq<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::neq(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::neq(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::neq(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::neq(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::neq(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::neq(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::neq(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::neq(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a != b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vmvnq_u8(vceqq_u8(a, b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::neq(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lt<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmplt_epu8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::lt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi8(i8::MIN);
+            Ops::<Avx2, i8>::lt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::lt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::lt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse41, i8>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::lt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse2, i8>::lt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a < b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcltq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_lt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn lte<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmple_epu8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::lte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi8(i8::MIN);
+            Ops::<Avx2, i8>::lte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::lte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::lte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse41, i8>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::lte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse2, i8>::lte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a <= b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcleq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_le(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gt<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpgt_epu8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::gt(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi8(i8::MIN);
+            Ops::<Avx2, i8>::gt(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::gt, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::gt(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse41, i8>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::gt(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse2, i8>::gt(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a > b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcgtq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_gt(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn gte<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_movm_epi8(_mm512_cmpge_epu8_mask(a, b))
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::gte(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm256_set1_epi8(i8::MIN);
+            Ops::<Avx2, i8>::gte(_mm256_xor_si256(a, bias), _mm256_xor_si256(b, bias))
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::gte, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::gte(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse41, i8>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::gte(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            // Flip the sign bits so the signed comparison orders the values as unsigned.
+            let bias = _mm_set1_epi8(i8::MIN);
+            Ops::<Sse2, i8>::gte(_mm_xor_si128(a, bias), _mm_xor_si128(b, bias))
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            if a >= b {
+                u8::MAX
+            } else {
+                0
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vcgeq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_ge(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn blendv<u8> {
+        for Avx512(a: __m512i, b: __m512i, mask: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::blendv(a, b, mask)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::blendv(a, b, mask)
+        }
+        for Avx2(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::blendv(a, b, mask)
+        }
+        for Avx(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+            Ops::<Avx, i8>::blendv(a, b, mask)
+        }
+        for Sse42(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::blendv(a, b, mask)
+        }
+        for Sse41(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::blendv(a, b, mask)
+        }
+        for Ssse3(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::blendv(a, b, mask)
+        }
+        for Sse2(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::blendv(a, b, mask)
+        }
+        for Scalar(a: u8, b: u8, mask: u8) -> u8 {
+            if mask == 0 {
+                a
+            } else {
+                b
+            }
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t, mask: uint8x16_t) -> uint8x16_t {
+            vbslq_u8(mask, b, a)
+        }
+        for Wasm(a: v128, b: v128, mask: v128) -> v128 {
+            Ops::<Wasm, i8>::blendv(a, b, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn shl<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::shl(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u8>::shl(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shl(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i8>::shl(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u8>::shl(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shl(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u8>::shl(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shl(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a << rhs
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vshlq_u8(a, vdupq_n_s8(rhs as i8))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::shl(a, rhs)
+        }
+    }
+}
+
+impl_op! {
+    fn shr<u8> {
+        for Avx512(a: __m512i, rhs: i32) -> __m512i {
+            Ops::<Avx512, i8>::shr(a, rhs)
+        }
+        for Avx2Fma(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, u8>::shr(a, rhs)
+        }
+        for Avx2(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx2, i8>::shr(a, rhs)
+        }
+        for Avx(a: __m256i, rhs: i32) -> __m256i {
+            Ops::<Avx, i8>::shr(a, rhs)
+        }
+        for Sse42(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, u8>::shr(a, rhs)
+        }
+        for Sse41(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse41, i8>::shr(a, rhs)
+        }
+        for Ssse3(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, u8>::shr(a, rhs)
+        }
+        for Sse2(a: __m128i, rhs: i32) -> __m128i {
+            Ops::<Sse2, i8>::shr(a, rhs)
+        }
+        for Scalar(a: u8, rhs: i32) -> u8 {
+            a >> rhs
+        }
+        for Neon(a: uint8x16_t, rhs: i32) -> uint8x16_t {
+            vshlq_u8(a, vdupq_n_s8(-rhs as i8))
+        }
+        for Wasm(a: v128, rhs: i32) -> v128 {
+            Ops::<Wasm, i8>::shr(a, rhs)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shl_const<u8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shl_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::shl_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::shl_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::shl_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::shl_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_const::<BY>(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a << BY
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vshlq_n_u8(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::shl_const::<BY>(a)
+        }
+    }
+}
+
+impl_imm8_op! {
+    fn shr_const<u8, const BY: i32> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shr_const::<BY>(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::shr_const::<BY>(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_const::<BY>(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::shr_const::<BY>(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::shr_const::<BY>(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_const::<BY>(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::shr_const::<BY>(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_const::<BY>(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a >> BY
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vshrq_n_u8(a, BY)
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::shr_const::<BY>(a)
+        }
+    }
+}
+
+impl_op! {
+    fn shl_var<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shl_var(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::shl_var(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shl_var(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::shl_var(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::shl_var(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shl_var(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::shl_var(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shl_var(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_shl(b as u32)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vshlq_u8(a, vreinterpretq_s8_u8(b))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::shl_var(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_var<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Self::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Self::shr_logical(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            Self::shr_logical(a, b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            Self::shr_logical(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Self::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn shr_logical<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::shr_logical(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::shr_logical(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::shr_logical(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::shr_logical(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::shr_logical(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::shr_logical(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::shr_logical(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::shr_logical(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.wrapping_shr(b as u32)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vshlq_u8(a, vnegq_s8(vreinterpretq_s8_u8(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::shr_logical(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn count_ones<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::count_ones(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::count_ones(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::count_ones(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::count_ones(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::count_ones(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::count_ones(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::count_ones(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::count_ones(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.count_ones() as u8
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::count_ones(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::count_ones(a)
+        }
+    }
+}
+
+impl_op! {
+    fn leading_zeros<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::leading_zeros(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::leading_zeros(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::leading_zeros(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::leading_zeros(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::leading_zeros(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::leading_zeros(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::leading_zeros(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::leading_zeros(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a.leading_zeros() as u8
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::leading_zeros(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::leading_zeros(a)
+        }
+    }
+}
+
+impl_op! {
+    fn zeroes<u8> {
+        for Avx512() -> __m512i {
+            Ops::<Avx512, i8>::zeroes()
+        }
+        for Avx2Fma() -> __m256i {
+            Ops::<Avx2, u8>::zeroes()
+        }
+        for Avx2() -> __m256i {
+            Ops::<Avx2, i8>::zeroes()
+        }
+        for Avx() -> __m256i {
+            Ops::<Avx, i8>::zeroes()
+        }
+        for Sse42() -> __m128i {
+            Ops::<Sse41, u8>::zeroes()
+        }
+        for Sse41() -> __m128i {
+            Ops::<Sse41, i8>::zeroes()
+        }
+        for Ssse3() -> __m128i {
+            Ops::<Sse2, u8>::zeroes()
+        }
+        for Sse2() -> __m128i {
+            Ops::<Sse2, i8>::zeroes()
+        }
+        for Scalar() -> u8 {
+            0
+        }
+        for Neon() -> uint8x16_t {
+            vdupq_n_u8(0)
+        }
+        for Wasm() -> v128 {
+            Ops::<Wasm, i8>::zeroes()
+        }
+    }
+}
+
+impl_op! {
+    fn set1<u8> {
+        for Avx512(val: u8) -> __m512i {
+            Ops::<Avx512, i8>::set1(val as i8)
+        }
+        for Avx2Fma(val: u8) -> __m256i {
+            Ops::<Avx2, u8>::set1(val)
+        }
+        for Avx2(val: u8) -> __m256i {
+            Ops::<Avx2, i8>::set1(val as i8)
+        }
+        for Avx(val: u8) -> __m256i {
+            Ops::<Avx, i8>::set1(val as i8)
+        }
+        for Sse42(val: u8) -> __m128i {
+            Ops::<Sse41, u8>::set1(val)
+        }
+        for Sse41(val: u8) -> __m128i {
+            Ops::<Sse41, i8>::set1(val as i8)
+        }
+        for Ssse3(val: u8) -> __m128i {
+            Ops::<Sse2, u8>::set1(val)
+        }
+        for Sse2(val: u8) -> __m128i {
+            Ops::<Sse2, i8>::set1(val as i8)
+        }
+        for Scalar(val: u8) -> u8 {
+            val
+        }
+        for Neon(val: u8) -> uint8x16_t {
+            vdupq_n_u8(val)
+        }
+        for Wasm(val: u8) -> v128 {
+            u8x16_splat(val)
+        }
+    }
+}
+
+impl_op! {
+    fn load_unaligned<u8> {
+        for Avx512(ptr: *const u8) -> __m512i {
+            Ops::<Avx512, i8>::load_unaligned(ptr as *const i8)
+        }
+        for Avx2Fma(ptr: *const u8) -> __m256i {
+            Ops::<Avx2, u8>::load_unaligned(ptr)
+        }
+        for Avx2(ptr: *const u8) -> __m256i {
+            Ops::<Avx2, i8>::load_unaligned(ptr as *const i8)
+        }
+        for Avx(ptr: *const u8) -> __m256i {
+            Ops::<Avx, i8>::load_unaligned(ptr as *const i8)
+        }
+        for Sse42(ptr: *const u8) -> __m128i {
+            Ops::<Sse41, u8>::load_unaligned(ptr)
+        }
+        for Sse41(ptr: *const u8) -> __m128i {
+            Ops::<Sse41, i8>::load_unaligned(ptr as *const i8)
+        }
+        for Ssse3(ptr: *const u8) -> __m128i {
+            Ops::<Sse2, u8>::load_unaligned(ptr)
+        }
+        for Sse2(ptr: *const u8) -> __m128i {
+            Ops::<Sse2, i8>::load_unaligned(ptr as *const i8)
+        }
+        for Scalar(ptr: *const u8) -> u8 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u8) -> uint8x16_t {
+            vld1q_u8(ptr)
+        }
+        for Wasm(ptr: *const u8) -> v128 {
+            Ops::<Wasm, i8>::load_unaligned(ptr as *const i8)
+        }
+    }
+}
+
+impl_op! {
+    fn load_aligned<u8> {
+        for Avx512(ptr: *const u8) -> __m512i {
+            Ops::<Avx512, i8>::load_aligned(ptr as *const i8)
+        }
+        for Avx2Fma(ptr: *const u8) -> __m256i {
+            Ops::<Avx2, u8>::load_aligned(ptr)
+        }
+        for Avx2(ptr: *const u8) -> __m256i {
+            Ops::<Avx2, i8>::load_aligned(ptr as *const i8)
+        }
+        for Avx(ptr: *const u8) -> __m256i {
+            Ops::<Avx, i8>::load_aligned(ptr as *const i8)
+        }
+        for Sse42(ptr: *const u8) -> __m128i {
+            Ops::<Sse41, u8>::load_aligned(ptr)
+        }
+        for Sse41(ptr: *const u8) -> __m128i {
+            Ops::<Sse41, i8>::load_aligned(ptr as *const i8)
+        }
+        for Ssse3(ptr: *const u8) -> __m128i {
+            Ops::<Sse2, u8>::load_aligned(ptr)
+        }
+        for Sse2(ptr: *const u8) -> __m128i {
+            Ops::<Sse2, i8>::load_aligned(ptr as *const i8)
+        }
+        for Scalar(ptr: *const u8) -> u8 {
+            unsafe { *ptr }
+        }
+        for Neon(ptr: *const u8) -> uint8x16_t {
+            vld1q_u8(ptr)
+        }
+        for Wasm(ptr: *const u8) -> v128 {
+            Ops::<Wasm, i8>::load_aligned(ptr as *const i8)
+        }
+    }
+}
+
+impl_op! {
+    fn store_unaligned<u8> {
+        for Avx512(ptr: *mut u8, a: __m512i) {
+            Ops::<Avx512, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+        for Avx2Fma(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx2, u8>::store_unaligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx2, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+        for Avx(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+        for Sse42(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse41, u8>::store_unaligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse41, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+        for Ssse3(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse2, u8>::store_unaligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse2, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+        for Scalar(ptr: *mut u8, a: u8) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u8, a: uint8x16_t) {
+            vst1q_u8(ptr, a)
+        }
+        for Wasm(ptr: *mut u8, a: v128) {
+            Ops::<Wasm, i8>::store_unaligned(ptr as *mut i8, a)
+        }
+    }
+}
+
+impl_op! {
+    fn store_aligned<u8> {
+        for Avx512(ptr: *mut u8, a: __m512i) {
+            Ops::<Avx512, i8>::store_aligned(ptr as *mut i8, a)
+        }
+        for Avx2Fma(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx2, u8>::store_aligned(ptr, a)
+        }
+        for Avx2(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx2, i8>::store_aligned(ptr as *mut i8, a)
+        }
+        for Avx(ptr: *mut u8, a: __m256i) {
+            Ops::<Avx, i8>::store_aligned(ptr as *mut i8, a)
+        }
+        for Sse42(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse41, u8>::store_aligned(ptr, a)
+        }
+        for Sse41(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse41, i8>::store_aligned(ptr as *mut i8, a)
+        }
+        for Ssse3(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse2, u8>::store_aligned(ptr, a)
+        }
+        for Sse2(ptr: *mut u8, a: __m128i) {
+            Ops::<Sse2, i8>::store_aligned(ptr as *mut i8, a)
+        }
+        for Scalar(ptr: *mut u8, a: u8) {
+            unsafe { *ptr = a }
+        }
+        for Neon(ptr: *mut u8, a: uint8x16_t) {
+            vst1q_u8(ptr, a)
+        }
+        for Wasm(ptr: *mut u8, a: v128) {
+            Ops::<Wasm, i8>::store_aligned(ptr as *mut i8, a)
+        }
+    }
+}
+
+impl_op! {
+    fn load_masked<u8> {
+        for Avx512(ptr: *const u8, mask: __mmask64) -> __m512i {
+            Ops::<Avx512, i8>::load_masked(ptr as *const i8, mask)
+        }
+        for Avx2Fma(ptr: *const u8, mask: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::load_masked(ptr, mask)
+        }
+        for Avx2(ptr: *const u8, mask: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::load_masked(ptr as *const i8, mask)
+        }
+        for Avx(ptr: *const u8, mask: __m256i) -> __m256i {
+            Ops::<Avx, i8>::load_masked(ptr as *const i8, mask)
+        }
+        for Sse42(ptr: *const u8, mask: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::load_masked(ptr, mask)
+        }
+        for Sse41(ptr: *const u8, mask: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::load_masked(ptr as *const i8, mask)
+        }
+        for Ssse3(ptr: *const u8, mask: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::load_masked(ptr, mask)
+        }
+        for Sse2(ptr: *const u8, mask: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::load_masked(ptr as *const i8, mask)
+        }
+        for Scalar(ptr: *const u8, mask: i8) -> u8 {
+            if mask != 0 {
+                *ptr
+            } else {
+                0
+            }
+        }
+        for Neon(ptr: *const u8, mask: int8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::load_masked(ptr as *const i8, mask))
+        }
+        for Wasm(ptr: *const u8, mask: v128) -> v128 {
+            Ops::<Wasm, i8>::load_masked(ptr as *const i8, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn store_masked<u8> {
+        for Avx512(ptr: *mut u8, a: __m512i, mask: __mmask64) {
+            Ops::<Avx512, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+        for Avx2Fma(ptr: *mut u8, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, u8>::store_masked(ptr, a, mask)
+        }
+        for Avx2(ptr: *mut u8, a: __m256i, mask: __m256i) {
+            Ops::<Avx2, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+        for Avx(ptr: *mut u8, a: __m256i, mask: __m256i) {
+            Ops::<Avx, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+        for Sse42(ptr: *mut u8, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, u8>::store_masked(ptr, a, mask)
+        }
+        for Sse41(ptr: *mut u8, a: __m128i, mask: __m128i) {
+            Ops::<Sse41, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+        for Ssse3(ptr: *mut u8, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, u8>::store_masked(ptr, a, mask)
+        }
+        for Sse2(ptr: *mut u8, a: __m128i, mask: __m128i) {
+            Ops::<Sse2, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+        for Scalar(ptr: *mut u8, a: u8, mask: i8) {
+            if mask != 0 {
+                *ptr = a;
+            }
+        }
+        for Neon(ptr: *mut u8, a: uint8x16_t, mask: int8x16_t) {
+            Ops::<Neon, i8>::store_masked(ptr as *mut i8, vreinterpretq_s8_u8(a), mask)
+        }
+        for Wasm(ptr: *mut u8, a: v128, mask: v128) {
+            Ops::<Wasm, i8>::store_masked(ptr as *mut i8, a, mask)
+        }
+    }
+}
+
+impl_op! {
+    fn permute<u8> {
+        for Avx512(a: __m512i, indices: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::permute(a, indices)
+        }
+        for Avx2Fma(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::permute(a, indices)
+        }
+        for Avx2(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::permute(a, indices)
+        }
+        for Avx(a: __m256i, indices: __m256i) -> __m256i {
+            Ops::<Avx, i8>::permute(a, indices)
+        }
+        for Sse42(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::permute(a, indices)
+        }
+        for Sse41(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::permute(a, indices)
+        }
+        for Ssse3(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Ssse3, i8>::permute(a, indices)
+        }
+        for Sse2(a: __m128i, indices: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::permute(a, indices)
+        }
+        for Scalar(a: u8, indices: i8) -> u8 {
+            let _ = indices;
+            a
+        }
+        for Neon(a: uint8x16_t, indices: int8x16_t) -> uint8x16_t {
+            let a = vreinterpretq_s8_u8(a);
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::permute(a, indices))
+        }
+        for Wasm(a: v128, indices: v128) -> v128 {
+            Ops::<Wasm, i8>::permute(a, indices)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_lo<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::zip_lo(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::zip_lo(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::zip_lo(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::zip_lo(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::zip_lo(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::zip_lo(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::zip_lo(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_lo(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            let _ = b;
+            a
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::zip_lo(vreinterpretq_s8_u8(a), vreinterpretq_s8_u8(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::zip_lo(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn zip_hi<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::zip_hi(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::zip_hi(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::zip_hi(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx, i8>::zip_hi(a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::zip_hi(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::zip_hi(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::zip_hi(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::zip_hi(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            let _ = a;
+            b
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::zip_hi(vreinterpretq_s8_u8(a), vreinterpretq_s8_u8(b)))
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            Ops::<Wasm, i8>::zip_hi(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn unzip<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+            let (x, y) = Ops::<Avx512, i8>::unzip(a, b);
+            (x, y)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            Ops::<Avx2, u8>::unzip(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx2, i8>::unzip(a, b);
+            (x, y)
+        }
+        for Avx(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+            let (x, y) = Ops::<Avx, i8>::unzip(a, b);
+            (x, y)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse41, u8>::unzip(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse41, i8>::unzip(a, b);
+            (x, y)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            Ops::<Sse2, u8>::unzip(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+            let (x, y) = Ops::<Sse2, i8>::unzip(a, b);
+            (x, y)
+        }
+        for Scalar(a: u8, b: u8) -> (u8, u8) {
+            (a, b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> (uint8x16_t, uint8x16_t) {
+            let (x, y) = Ops::<Neon, i8>::unzip(vreinterpretq_s8_u8(a), vreinterpretq_s8_u8(b));
+            (vreinterpretq_u8_s8(x), vreinterpretq_u8_s8(y))
+        }
+        for Wasm(a: v128, b: v128) -> (v128, v128) {
+            let (x, y) = Ops::<Wasm, i8>::unzip(a, b);
+            (x, y)
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved2<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t) {
+            let loaded = vld2q_u8(ptr);
+            (loaded.0, loaded.1)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved2<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t) {
+            vst2q_u8(ptr, uint8x16x2_t(a, b))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved3<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t, uint8x16_t) {
+            let loaded = vld3q_u8(ptr);
+            (loaded.0, loaded.1, loaded.2)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved3<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) {
+            vst3q_u8(ptr, uint8x16x3_t(a, b, c))
+        }
+    }
+}
+
+impl_op! {
+    fn load_deinterleaved4<u8> {
+        for Neon(ptr: *const u8) -> (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t) {
+            let loaded = vld4q_u8(ptr);
+            (loaded.0, loaded.1, loaded.2, loaded.3)
+        }
+    }
+}
+
+impl_op! {
+    fn store_interleaved4<u8> {
+        for Neon(ptr: *mut u8, a: uint8x16_t, b: uint8x16_t, c: uint8x16_t, d: uint8x16_t) {
+            vst4q_u8(ptr, uint8x16x4_t(a, b, c, d))
+        }
+    }
+}
+
+impl_op! {
+    fn prefix_sum<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::prefix_sum(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            a
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::prefix_sum(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn exclusive_prefix_sum<u8> {
+        for Avx512(a: __m512i) -> __m512i {
+            Ops::<Avx512, i8>::exclusive_prefix_sum(a)
+        }
+        for Avx2Fma(a: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::exclusive_prefix_sum(a)
+        }
+        for Avx2(a: __m256i) -> __m256i {
+            Ops::<Avx2, i8>::exclusive_prefix_sum(a)
+        }
+        for Avx(a: __m256i) -> __m256i {
+            Ops::<Avx, i8>::exclusive_prefix_sum(a)
+        }
+        for Sse42(a: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::exclusive_prefix_sum(a)
+        }
+        for Sse41(a: __m128i) -> __m128i {
+            Ops::<Sse41, i8>::exclusive_prefix_sum(a)
+        }
+        for Ssse3(a: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::exclusive_prefix_sum(a)
+        }
+        for Sse2(a: __m128i) -> __m128i {
+            Ops::<Sse2, i8>::exclusive_prefix_sum(a)
+        }
+        for Scalar(a: u8) -> u8 {
+            let _ = a;
+            0
+        }
+        for Neon(a: uint8x16_t) -> uint8x16_t {
+            vreinterpretq_u8_s8(Ops::<Neon, i8>::exclusive_prefix_sum(vreinterpretq_s8_u8(a)))
+        }
+        for Wasm(a: v128) -> v128 {
+            Ops::<Wasm, i8>::exclusive_prefix_sum(a)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_add<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_adds_epu8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::saturating_add(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_adds_epu8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::saturating_add, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::saturating_add(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::saturating_add(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_adds_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.saturating_add(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vqaddq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_add_sat(a, b)
+        }
+    }
+}
+
+impl_op! {
+    fn saturating_sub<u8> {
+        for Avx512(a: __m512i, b: __m512i) -> __m512i {
+            _mm512_subs_epu8(a, b)
+        }
+        for Avx2Fma(a: __m256i, b: __m256i) -> __m256i {
+            Ops::<Avx2, u8>::saturating_sub(a, b)
+        }
+        for Avx2(a: __m256i, b: __m256i) -> __m256i {
+            _mm256_subs_epu8(a, b)
+        }
+        for Avx(a: __m256i, b: __m256i) -> __m256i {
+            m256i_run_on_halves!(Ops::<Sse41, u8>::saturating_sub, a, b)
+        }
+        for Sse42(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse41, u8>::saturating_sub(a, b)
+        }
+        for Sse41(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu8(a, b)
+        }
+        for Ssse3(a: __m128i, b: __m128i) -> __m128i {
+            Ops::<Sse2, u8>::saturating_sub(a, b)
+        }
+        for Sse2(a: __m128i, b: __m128i) -> __m128i {
+            _mm_subs_epu8(a, b)
+        }
+        for Scalar(a: u8, b: u8) -> u8 {
+            a.saturating_sub(b)
+        }
+        for Neon(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+            vqsubq_u8(a, b)
+        }
+        for Wasm(a: v128, b: v128) -> v128 {
+            u8x16_sub_sat(a, b)
+        }
+    }
+}
@@ -351,6 +351,18 @@ macro_rules! horizontal_add_scalar {
     (i64) => {
         i64
     };
+    (u8) => {
+        u64
+    };
+    (u16) => {
+        u64
+    };
+    (u32) => {
+        u64
+    };
+    (u64) => {
+        u64
+    };
     (f32) => {
         f32
     };
@@ -359,6 +371,265 @@ macro_rules! horizontal_add_scalar {
     };
 }
 
+macro_rules! gather_unchecked {
+    ($engine:ident, i8, $slice:ident, $indices:ident) => {
+        gather_emulated($slice, $indices)
+    };
+    ($engine:ident, i16, $slice:ident, $indices:ident) => {
+        gather_emulated($slice, $indices)
+    };
+    ($engine:ident, u8, $slice:ident, $indices:ident) => {
+        gather_emulated($slice, $indices)
+    };
+    ($engine:ident, u16, $slice:ident, $indices:ident) => {
+        gather_emulated($slice, $indices)
+    };
+    ($engine:ident, $scalar_ty:ident, $slice:ident, $indices:ident) => {
+        Self(Ops::<$engine, $scalar_ty>::gather_unchecked(
+            $slice.as_ptr(),
+            $indices.0,
+        ))
+    };
+}
+
+/// Only Avx512 has scatter instructions, and only for 32 and 64 bit lanes, so every other
+/// combination stores one lane at a time.
+macro_rules! scatter_unchecked {
+    (Avx512, i8, $self:ident, $slice:ident, $indices:ident) => {
+        scatter_emulated($self, $slice, $indices, u64::MAX)
+    };
+    (Avx512, i16, $self:ident, $slice:ident, $indices:ident) => {
+        scatter_emulated($self, $slice, $indices, u64::MAX)
+    };
+    (Avx512, u8, $self:ident, $slice:ident, $indices:ident) => {
+        scatter_emulated($self, $slice, $indices, u64::MAX)
+    };
+    (Avx512, u16, $self:ident, $slice:ident, $indices:ident) => {
+        scatter_emulated($self, $slice, $indices, u64::MAX)
+    };
+    (Avx512, $scalar_ty:ident, $self:ident, $slice:ident, $indices:ident) => {
+        Ops::<Avx512, $scalar_ty>::scatter($slice.as_mut_ptr(), $indices.0, $self.0)
+    };
+    ($engine:ident, $scalar_ty:ident, $self:ident, $slice:ident, $indices:ident) => {
+        scatter_emulated($self, $slice, $indices, u64::MAX)
+    };
+}
+
+macro_rules! scatter_masked_unchecked {
+    (Avx512, i8, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        scatter_emulated($self, $slice, $indices, $mask.to_bitmask())
+    };
+    (Avx512, i16, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        scatter_emulated($self, $slice, $indices, $mask.to_bitmask())
+    };
+    (Avx512, u8, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        scatter_emulated($self, $slice, $indices, $mask.to_bitmask())
+    };
+    (Avx512, u16, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        scatter_emulated($self, $slice, $indices, $mask.to_bitmask())
+    };
+    (Avx512, $scalar_ty:ident, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        Ops::<Avx512, $scalar_ty>::scatter_masked($slice.as_mut_ptr(), $indices.0, $self.0, $mask.0)
+    };
+    ($engine:ident, $scalar_ty:ident, $self:ident, $slice:ident, $indices:ident, $mask:ident) => {
+        scatter_emulated($self, $slice, $indices, $mask.to_bitmask())
+    };
+}
+
+/// Helpers for three channel interleaving, built out of shuffles. Neon has dedicated
+/// instructions for it, so it doesn't need them.
+macro_rules! impl_interleave3 {
+    (Neon, $ty:ident, $mask_ty:ident) => {};
+    ($engine:ident, $ty:ident, $mask_ty:ident) => {
+        impl $ty {
+            /// A mask with the lanes where `f` returns true set.
+            #[inline(always)]
+            fn lanes_where(f: impl Fn(usize) -> bool) -> $mask_ty {
+                type Lanes = <$ty as SimdBaseIo>::GatherIndices;
+                let mut lanes = Lanes::zeroes();
+                for i in 0..Self::WIDTH {
+                    if f(i) {
+                        lanes[i] = -1;
+                    }
+                }
+                lanes.cmp_lt(Lanes::zeroes())
+            }
+
+            /// Splits three vectors of values interleaved as `[x, y, z, x, y, z, ...]` into one
+            /// vector per channel.
+            #[inline(always)]
+            fn deinterleave3(vectors: [Self; 3]) -> [Self; 3] {
+                [
+                    Self::channel3::<0>(vectors),
+                    Self::channel3::<1>(vectors),
+                    Self::channel3::<2>(vectors),
+                ]
+            }
+
+            /// Picks channel `C` out of three vectors of values interleaved as
+            /// `[x, y, z, x, y, z, ...]`.
+            #[inline(always)]
+            fn channel3<const C: usize>(vectors: [Self; 3]) -> Self {
+                // Lane `i` of channel `C` is value `3 * i + C`. The vector holding that value only
+                // grows with `i`, so each vector covers a run of lanes.
+                let [a, b, d] = vectors.map(|v| v.shuffle::<EveryThird<C>>());
+                let in_first = Self::lanes_where(|i| 3 * i + C < Self::WIDTH);
+                let in_second = Self::lanes_where(|i| 3 * i + C < 2 * Self::WIDTH);
+                in_first.select(a, in_second.select(b, d))
+            }
+
+            /// Interleaves three vectors, one per channel, into three vectors of values laid out
+            /// as `[x, y, z, x, y, z, ...]`.
+            #[inline(always)]
+            fn interleave3(channels: [Self; 3]) -> [Self; 3] {
+                [
+                    Self::interleaved3::<0>(channels),
+                    Self::interleaved3::<1>(channels),
+                    Self::interleaved3::<2>(channels),
+                ]
+            }
+
+            /// Builds vector `K` of the values of three channels interleaved as
+            /// `[x, y, z, x, y, z, ...]`.
+            #[inline(always)]
+            fn interleaved3<const K: usize>(channels: [Self; 3]) -> Self {
+                // Lane `j` is value `K * WIDTH + j`, which is lane `value / 3` of channel
+                // `value % 3`.
+                let value = |j: usize| K * Self::WIDTH + j;
+                let [a, b, c] = channels.map(|v| v.shuffle::<ThirdOfValue<K>>());
+                let in_first = Self::lanes_where(|j| value(j) % 3 == 0);
+                let in_second = Self::lanes_where(|j| value(j) % 3 == 1);
+                in_first.select(a, in_second.select(b, c))
+            }
+        }
+    };
+}
+
+macro_rules! load_deinterleaved {
+    (Neon, $scalar_ty:ident, $ptr:ident, $n:expr) => {
+        match $n {
+            2 => {
+                let (a, b) = Ops::<Neon, $scalar_ty>::load_deinterleaved2($ptr);
+                [Self(a), Self(b), Self(b), Self(b)]
+            }
+            3 => {
+                let (a, b, c) = Ops::<Neon, $scalar_ty>::load_deinterleaved3($ptr);
+                [Self(a), Self(b), Self(c), Self(c)]
+            }
+            _ => {
+                let (a, b, c, d) = Ops::<Neon, $scalar_ty>::load_deinterleaved4($ptr);
+                [Self(a), Self(b), Self(c), Self(d)]
+            }
+        }
+    };
+    ($engine:ident, $scalar_ty:ident, $ptr:ident, $n:expr) => {{
+        let load = |k: usize| Self::load_from_ptr_unaligned($ptr.add(k * Self::WIDTH));
+        match $n {
+            2 => {
+                let (a, b) = load(0).unzip(load(1));
+                [a, b, b, b]
+            }
+            3 => {
+                let [a, b, c] = Self::deinterleave3([load(0), load(1), load(2)]);
+                [a, b, c, c]
+            }
+            _ => {
+                let (even_01, odd_01) = load(0).unzip(load(1));
+                let (even_23, odd_23) = load(2).unzip(load(3));
+                let (a, c) = even_01.unzip(even_23);
+                let (b, d) = odd_01.unzip(odd_23);
+                [a, b, c, d]
+            }
+        }
+    }};
+}
+
+/// Folds the lanes the same way `reduce_lanes` does, carrying the lane indices along and moving
+/// to the odd lane only when `$cmp` holds, so ties keep the first lane.
+macro_rules! arg_reduce {
+    ($self:ident, $cmp:ident) => {{
+        let mut values = $self;
+        let mut indices = <Self as SimdBaseIo>::GatherIndices::zeroes();
+        for i in 0..Self::WIDTH {
+            indices[i] = (i as i8).into();
+        }
+        let mut width = Self::WIDTH;
+        while width > 1 {
+            let (even, odd) = values.unzip(values);
+            let (even_indices, odd_indices) = indices.unzip(indices);
+            let pick_odd = odd.$cmp(even);
+            values = Self::select(pick_odd, odd, even);
+            indices = SimdBaseOps::select(pick_odd, odd_indices, even_indices);
+            width /= 2;
+        }
+        let index: i64 = indices[0].into();
+        index as usize
+    }};
+}
+
+macro_rules! transpose {
+    ($engine:ident, $scalar_ty:ident, $rows:ident) => {{
+        assert!(
+            $rows.len() == Self::WIDTH,
+            "transpose needs {} rows, but the slice holds {}",
+            Self::WIDTH,
+            $rows.len()
+        );
+        let transposed =
+            unsafe { Ops::<$engine, $scalar_ty>::transpose(core::array::from_fn(|i| $rows[i].0)) };
+        for (row, value) in $rows.iter_mut().zip(transposed) {
+            *row = Self(value);
+        }
+    }};
+}
+
+macro_rules! store_interleaved {
+    (Neon, $scalar_ty:ident, $ptr:ident, $vectors:ident, $n:expr) => {
+        match $n {
+            2 => Ops::<Neon, $scalar_ty>::store_interleaved2($ptr, $vectors[0].0, $vectors[1].0),
+            3 => Ops::<Neon, $scalar_ty>::store_interleaved3(
+                $ptr,
+                $vectors[0].0,
+                $vectors[1].0,
+                $vectors[2].0,
+            ),
+            _ => Ops::<Neon, $scalar_ty>::store_interleaved4(
+                $ptr,
+                $vectors[0].0,
+                $vectors[1].0,
+                $vectors[2].0,
+                $vectors[3].0,
+            ),
+        }
+    };
+    ($engine:ident, $scalar_ty:ident, $ptr:ident, $vectors:ident, $n:expr) => {{
+        let interleaved = match $n {
+            2 => {
+                let [a, b, ..] = $vectors;
+                [a.zip_lo(b), a.zip_hi(b), b, b]
+            }
+            3 => {
+                let [a, b, c] = Self::interleave3([$vectors[0], $vectors[1], $vectors[2]]);
+                [a, b, c, c]
+            }
+            _ => {
+                let [a, b, c, d] = $vectors;
+                let (even_01, even_23) = (a.zip_lo(c), a.zip_hi(c));
+                let (odd_01, odd_23) = (b.zip_lo(d), b.zip_hi(d));
+                [
+                    even_01.zip_lo(odd_01),
+                    even_01.zip_hi(odd_01),
+                    even_23.zip_lo(odd_23),
+                    even_23.zip_hi(odd_23),
+                ]
+            }
+        };
+        for (k, vector) in interleaved.into_iter().take($n).enumerate() {
+            vector.copy_to_ptr_unaligned($ptr.add(k * Self::WIDTH));
+        }
+    }};
+}
+
 macro_rules! define_simd_type {
     (Scalar, $ty:ty, $width:literal, $underlying:ty) => {
         paste::item! {
@@ -441,11 +712,136 @@ macro_rules! define_simd_type {
     };
 }
 
+macro_rules! define_simd_mask {
+    ($engine:ident, $ty:ident, $int_ty:ident, $width:literal, $underlying:ty) => {
+        #[derive(Copy, Clone)]
+        pub struct $ty($underlying);
+
+        impl SimdMask for $ty {
+            const WIDTH: usize = $width;
+            type Engine = $engine;
+
+            #[inline(always)]
+            fn to_bitmask(self) -> u64 {
+                unsafe { Ops::<$engine, $int_ty>::mask_to_bitmask(self.0) }
+            }
+        }
+
+        impl BitAnd for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn bitand(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $int_ty>::mask_and(self.0, rhs.0)) }
+            }
+        }
+
+        impl BitAndAssign for $ty {
+            #[inline(always)]
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl BitOr for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn bitor(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $int_ty>::mask_or(self.0, rhs.0)) }
+            }
+        }
+
+        impl BitOrAssign for $ty {
+            #[inline(always)]
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl BitXor for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn bitxor(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $int_ty>::mask_xor(self.0, rhs.0)) }
+            }
+        }
+
+        impl BitXorAssign for $ty {
+            #[inline(always)]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl Not for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn not(self) -> Self {
+                unsafe { Self(Ops::<$engine, $int_ty>::mask_not(self.0)) }
+            }
+        }
+
+        impl core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let bits = self.to_bitmask();
+                let mut list = f.debug_list();
+                for i in 0..$width {
+                    list.entry(&((bits >> i) & 1 == 1));
+                }
+                list.finish()
+            }
+        }
+    };
+}
+
 macro_rules! impl_simd_base {
-    ($engine:ident, $ty:ident, $scalar_ty:ident, |$self:ident| {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $mask_ty:ident, $mask_int_ty:ident, |$self:ident| {
         $($hadd:tt)*
     }) => {
+        impl $ty {
+            #[inline(always)]
+            unsafe fn to_mask(value: <Self as SimdConsts>::UnderlyingType) -> $mask_ty {
+                $mask_ty(Ops::<$engine, $mask_int_ty>::mask_from_vector(Self::to_int_lanes(value)))
+            }
+
+            /// A mask with the first `len` lanes set, used to stay within the bounds of a slice.
+            #[inline(always)]
+            fn first_lanes(len: usize) -> $mask_ty {
+                unsafe { $mask_ty(Ops::<$engine, $mask_int_ty>::mask_first_lanes(len)) }
+            }
+
+            /// Reinterprets the lanes as the signed integers of the same width, for ops that only
+            /// move lanes around.
+            #[inline(always)]
+            unsafe fn to_int_lanes(
+                value: <Self as SimdConsts>::UnderlyingType,
+            ) -> <<Self as SimdBaseIo>::GatherIndices as SimdConsts>::UnderlyingType {
+                let bits = Ops::<$engine, $scalar_ty>::bitcast_binary(value);
+                paste::paste! { Ops::<$engine, binary>::[<bitcast_ $mask_int_ty>](bits) }
+            }
+
+            #[inline(always)]
+            unsafe fn from_int_lanes(
+                value: <<Self as SimdBaseIo>::GatherIndices as SimdConsts>::UnderlyingType,
+            ) -> <Self as SimdConsts>::UnderlyingType {
+                let bits = Ops::<$engine, $mask_int_ty>::bitcast_binary(value);
+                paste::paste! { Ops::<$engine, binary>::[<bitcast_ $scalar_ty>](bits) }
+            }
+
+            #[inline(always)]
+            unsafe fn mask_to_underlying(mask: $mask_ty) -> <Self as SimdConsts>::UnderlyingType {
+                Self::from_int_lanes(Ops::<$engine, $mask_int_ty>::mask_to_vector(mask.0))
+            }
+        }
+
+        impl_interleave3!($engine, $ty, $mask_ty);
+
         impl SimdBaseIo for $ty {
+            type Mask = $mask_ty;
+
+            paste::paste! {
+                type GatherIndices = <$engine as Simd>::[<V $mask_int_ty>];
+            }
+
             #[inline(always)]
             fn zeroes() -> Self {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::zeroes()) }
@@ -481,6 +877,73 @@ macro_rules! impl_simd_base {
                 unsafe { Ops::<$engine, $scalar_ty>::store_aligned(ptr, self.0) }
             }
 
+            #[inline(always)]
+            fn load_partial(slice: &[Self::Scalar]) -> Self {
+                unsafe {
+                    if slice.len() < Self::WIDTH {
+                        let mask = Self::first_lanes(slice.len());
+                        Self(Ops::<$engine, $scalar_ty>::load_masked(slice.as_ptr(), mask.0))
+                    } else {
+                        Self::load_from_ptr_unaligned(slice.as_ptr())
+                    }
+                }
+            }
+
+            #[inline(always)]
+            fn load_deinterleaved<const N: usize>(slice: &[Self::Scalar]) -> [Self; N] {
+                assert!((2..=4).contains(&N), "load_deinterleaved supports 2, 3 or 4 channels");
+                assert!(
+                    slice.len() >= N * Self::WIDTH,
+                    "load_deinterleaved needs {} values, but the slice holds {}",
+                    N * Self::WIDTH,
+                    slice.len()
+                );
+                let ptr = slice.as_ptr();
+                let channels = unsafe { load_deinterleaved!($engine, $scalar_ty, ptr, N) };
+                core::array::from_fn(|c| channels[c])
+            }
+
+            #[inline(always)]
+            fn store_interleaved<const N: usize>(vectors: [Self; N], slice: &mut [Self::Scalar]) {
+                assert!((2..=4).contains(&N), "store_interleaved supports 2, 3 or 4 channels");
+                assert!(
+                    slice.len() >= N * Self::WIDTH,
+                    "store_interleaved needs room for {} values, but the slice holds {}",
+                    N * Self::WIDTH,
+                    slice.len()
+                );
+                let ptr = slice.as_mut_ptr();
+                let vectors: [Self; 4] = core::array::from_fn(|c| vectors[c.min(N - 1)]);
+                unsafe { store_interleaved!($engine, $scalar_ty, ptr, vectors, N) }
+            }
+
+            #[inline(always)]
+            unsafe fn gather_unchecked(slice: &[Self::Scalar], indices: Self::GatherIndices) -> Self {
+                gather_unchecked!($engine, $scalar_ty, slice, indices)
+            }
+
+            #[inline(always)]
+            unsafe fn load_from_ptr_masked(ptr: *const Self::Scalar, mask: Self::Mask) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::load_masked(ptr, mask.0)) }
+            }
+
+            #[inline(always)]
+            unsafe fn copy_to_ptr_masked(self, ptr: *mut Self::Scalar, mask: Self::Mask) {
+                unsafe { Ops::<$engine, $scalar_ty>::store_masked(ptr, self.0, mask.0) }
+            }
+
+            #[inline(always)]
+            fn load_from_slice_masked(slice: &[Self::Scalar], mask: Self::Mask) -> Self {
+                let mask = mask & Self::first_lanes(slice.len());
+                unsafe { Self::load_from_ptr_masked(slice.as_ptr(), mask) }
+            }
+
+            #[inline(always)]
+            fn store_to_slice_masked(self, slice: &mut [Self::Scalar], mask: Self::Mask) {
+                let mask = mask & Self::first_lanes(slice.len());
+                unsafe { self.copy_to_ptr_masked(slice.as_mut_ptr(), mask) }
+            }
+
             #[inline(always)]
             unsafe fn underlying_value(self) -> Self::UnderlyingType {
                 self.0
@@ -578,38 +1041,111 @@ macro_rules! impl_simd_base {
             }
 
             #[inline(always)]
-            fn blendv(self, a: Self, b: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::blendv(a.0, b.0, self.0)) }
+            fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+                unsafe {
+                    let mask = Self::mask_to_underlying(mask);
+                    Self(Ops::<$engine, $scalar_ty>::blendv(if_false.0, if_true.0, mask))
+                }
+            }
+
+            #[inline(always)]
+            fn from_mask(mask: Self::Mask) -> Self {
+                unsafe { Self(Self::mask_to_underlying(mask)) }
+            }
+
+            #[inline(always)]
+            unsafe fn scatter_unchecked(self, slice: &mut [Self::Scalar], indices: Self::GatherIndices) {
+                scatter_unchecked!($engine, $scalar_ty, self, slice, indices)
+            }
+
+            #[inline(always)]
+            unsafe fn scatter_masked_unchecked(
+                self,
+                slice: &mut [Self::Scalar],
+                indices: Self::GatherIndices,
+                mask: Self::Mask,
+            ) {
+                scatter_masked_unchecked!($engine, $scalar_ty, self, slice, indices, mask)
+            }
+
+            #[inline(always)]
+            fn cmp_eq(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::eq(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn cmp_neq(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::neq(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn cmp_lt(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::lt(self.0, rhs.0)) }
             }
 
             #[inline(always)]
-            fn cmp_eq(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::eq(self.0, rhs.0)) }
+            fn cmp_lte(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::lte(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn cmp_gt(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::gt(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn cmp_gte(self, rhs: Self) -> Self::Mask {
+                unsafe { Self::to_mask(Ops::<$engine, $scalar_ty>::gte(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn get_mask(self) -> u64 {
+                unsafe {
+                    let bits = Ops::<$engine, $scalar_ty>::bitcast_binary(self.0);
+                    let vector = paste::paste! { Ops::<$engine, binary>::[<bitcast_ $mask_int_ty>](bits) };
+                    Ops::<$engine, $mask_int_ty>::get_mask(vector)
+                }
             }
 
             #[inline(always)]
-            fn cmp_neq(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::neq(self.0, rhs.0)) }
+            fn zip_lo(self, other: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::zip_lo(self.0, other.0)) }
             }
 
             #[inline(always)]
-            fn cmp_lt(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::lt(self.0, rhs.0)) }
+            fn zip_hi(self, other: Self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::zip_hi(self.0, other.0)) }
             }
 
             #[inline(always)]
-            fn cmp_lte(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::lte(self.0, rhs.0)) }
+            fn unzip(self, other: Self) -> (Self, Self) {
+                let (even, odd) = unsafe { Ops::<$engine, $scalar_ty>::unzip(self.0, other.0) };
+                (Self(even), Self(odd))
             }
 
             #[inline(always)]
-            fn cmp_gt(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::gt(self.0, rhs.0)) }
+            fn permute(self, indices: Self::GatherIndices) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::permute(self.0, indices.0)) }
             }
 
             #[inline(always)]
-            fn cmp_gte(self, rhs: Self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::gte(self.0, rhs.0)) }
+            fn shuffle<P: ShufflePattern>(self) -> Self {
+                const WIDTH: usize = <$ty as SimdConsts>::WIDTH;
+                let indices = const {
+                    let lanes = <P as LaneIndices<WIDTH>>::INDICES;
+                    let mut indices = [0; WIDTH];
+                    let mut i = 0;
+                    while i < WIDTH {
+                        indices[i] = lanes[i].rem_euclid(WIDTH) as $mask_int_ty;
+                        i += 1;
+                    }
+                    indices
+                };
+                unsafe {
+                    let lanes = Self::to_int_lanes(self.0);
+                    let shuffled = Ops::<$engine, $mask_int_ty>::shuffle(lanes, indices);
+                    Self(Self::from_int_lanes(shuffled))
+                }
             }
 
             #[inline(always)]
@@ -626,6 +1162,26 @@ macro_rules! impl_simd_base {
             fn horizontal_add($self) -> Self::HorizontalAddScalar {
                 $($hadd)*
             }
+
+            #[inline(always)]
+            fn argmin(self) -> usize {
+                arg_reduce!(self, cmp_lt)
+            }
+
+            #[inline(always)]
+            fn argmax(self) -> usize {
+                arg_reduce!(self, cmp_gt)
+            }
+
+            #[inline(always)]
+            fn prefix_sum(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::prefix_sum(self.0)) }
+            }
+
+            #[inline(always)]
+            fn exclusive_prefix_sum(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::exclusive_prefix_sum(self.0)) }
+            }
         }
     };
 }
@@ -655,6 +1211,34 @@ macro_rules! impl_simd_int {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::shr_const::<BY>(self.0)) }
             }
 
+            #[inline(always)]
+            fn shl_var(self, counts: Self) -> Self {
+                let counts = counts & (<$scalar_ty>::BITS - 1) as $scalar_ty;
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shl_var(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn shr_var(self, counts: Self) -> Self {
+                let counts = counts & (<$scalar_ty>::BITS - 1) as $scalar_ty;
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shr_var(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn shr_logical(self, counts: Self) -> Self {
+                let counts = counts & (<$scalar_ty>::BITS - 1) as $scalar_ty;
+                unsafe { Self(Ops::<$engine, $scalar_ty>::shr_logical(self.0, counts.0)) }
+            }
+
+            #[inline(always)]
+            fn count_ones(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::count_ones(self.0)) }
+            }
+
+            #[inline(always)]
+            fn leading_zeros(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::leading_zeros(self.0)) }
+            }
+
             #[inline(always)]
             fn horizontal_unsigned_add($self) -> Self::HorizontalAddScalar {
                 $($hadd)*
@@ -669,7 +1253,7 @@ macro_rules! impl_simd_int {
 }
 
 macro_rules! impl_simd_float {
-    ($engine:ident, $ty:ident, $scalar_ty:ident) => {
+    ($engine:ident, $ty:ident, $scalar_ty:ident, $int_ty:ident, $to_bits:ident, $from_bits:ident) => {
         impl SimdFloat for $ty {
             #[inline(always)]
             fn div(self, rhs: Self) -> Self {
@@ -687,8 +1271,18 @@ macro_rules! impl_simd_float {
             }
 
             #[inline(always)]
-            fn round(self) -> Self {
-                unsafe { Self(Ops::<$engine, $scalar_ty>::round(self.0)) }
+            fn round_ties_even(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::round_ties_even(self.0)) }
+            }
+
+            #[inline(always)]
+            fn round_ties_away(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::round_ties_away(self.0)) }
+            }
+
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                unsafe { Self(Ops::<$engine, $scalar_ty>::trunc(self.0)) }
             }
 
             #[inline(always)]
@@ -736,6 +1330,47 @@ macro_rules! impl_simd_float {
                 unsafe { Self(Ops::<$engine, $scalar_ty>::rsqrt(self.0)) }
             }
 
+            #[inline(always)]
+            fn is_nan(self) -> Self::Mask {
+                // NaNs are the only values with magnitude bits above those of infinity.
+                let magnitude = self.$to_bits() & $int_ty::MAX;
+                magnitude.cmp_gt(SimdBaseIo::set1($scalar_ty::INFINITY.to_bits() as $int_ty))
+            }
+
+            #[inline(always)]
+            fn is_infinite(self) -> Self::Mask {
+                let magnitude = self.$to_bits() & $int_ty::MAX;
+                magnitude.cmp_eq(SimdBaseIo::set1($scalar_ty::INFINITY.to_bits() as $int_ty))
+            }
+
+            #[inline(always)]
+            fn is_finite(self) -> Self::Mask {
+                let magnitude = self.$to_bits() & $int_ty::MAX;
+                magnitude.cmp_lt(SimdBaseIo::set1($scalar_ty::INFINITY.to_bits() as $int_ty))
+            }
+
+            #[inline(always)]
+            fn is_sign_negative(self) -> Self::Mask {
+                self.$to_bits().cmp_lt(SimdBaseIo::zeroes())
+            }
+
+            #[inline(always)]
+            fn copysign(self, sign: Self) -> Self {
+                let magnitude = self.$to_bits() & $int_ty::MAX;
+                (magnitude | (sign.$to_bits() & $int_ty::MIN)).$from_bits()
+            }
+
+            #[inline(always)]
+            fn signum(self) -> Self {
+                let one = Self::set1(1.0).copysign(self);
+                Self::select(self.is_nan(), Self::set1(<$scalar_ty>::NAN), one)
+            }
+
+            #[inline(always)]
+            fn fract(self) -> Self {
+                self - SimdFloat::trunc(self)
+            }
+
             #[inline(always)]
             fn from_f64(value: f64) -> Self {
                 Self::set1(value as $scalar_ty)
@@ -745,8 +1380,8 @@ macro_rules! impl_simd_float {
 }
 
 macro_rules! impl_i8_simd_type {
-    ($engine:ident, $i8_ty:ident, $i16_ty:ident) => {
-        impl_simd_base!($engine, $i8_ty, i8, |self| {
+    ($engine:ident, $i8_ty:ident, $i16_ty:ident, $u8_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $i8_ty, i8, $mask_ty, i8, |self| {
             self.partial_horizontal_add()
                 .partial_horizontal_add()
                 .partial_horizontal_add()
@@ -760,6 +1395,24 @@ macro_rules! impl_i8_simd_type {
         });
 
         impl SimdInt8 for $i8_ty {
+            #[inline(always)]
+            fn bitcast_u8(self) -> <Self::Engine as Simd>::Vu8 {
+                unsafe {
+                    let bits = Ops::<$engine, i8>::bitcast_binary(self.0);
+                    $u8_ty(Ops::<$engine, binary>::bitcast_u8(bits))
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::saturating_add(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::saturating_sub(self.0, rhs.0)) }
+            }
+
             #[inline(always)]
             fn extend_to_i16(self) -> (<Self::Engine as Simd>::Vi16, <Self::Engine as Simd>::Vi16) {
                 let (a, b) = unsafe { Ops::<$engine, i8>::extend_i16(self.0) };
@@ -775,16 +1428,42 @@ macro_rules! impl_i8_simd_type {
             }
 
             #[inline(always)]
-            fn get_mask(self) -> u32 {
-                unsafe { Ops::<$engine, i8>::get_mask(self.0) }
+            fn cmp_eq_any(self, set: &[i8]) -> Self::Mask {
+                assert!(set.len() <= 16, "cmp_eq_any supports at most 16 values");
+                unsafe { Self::to_mask(Ops::<$engine, i8>::cmp_eq_any(self.0, set)) }
+            }
+
+            #[inline(always)]
+            fn cmp_in_ranges(self, ranges: &[(i8, i8)]) -> Self::Mask {
+                assert!(ranges.len() <= 8, "cmp_in_ranges supports at most 8 ranges");
+                unsafe { Self::to_mask(Ops::<$engine, i8>::cmp_in_ranges(self.0, ranges)) }
+            }
+
+            #[inline(always)]
+            fn lookup16(table: &[i8; 16], indices: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::lookup16(table, indices.0)) }
+            }
+
+            #[inline(always)]
+            fn shuffle_bytes(self, indices: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i8>::shuffle_bytes(self.0, indices.0)) }
+            }
+
+            #[inline(always)]
+            fn align_right<const N: i32>(self, low: Self) -> Self {
+                assert!(
+                    N >= 0 && N as usize <= Self::WIDTH,
+                    "align_right offset out of range"
+                );
+                unsafe { Self(Ops::<$engine, i8>::align_right::<N>(self.0, low.0)) }
             }
         }
     };
 }
 
 macro_rules! impl_i16_simd_type {
-    ($engine:ident, $i16_ty:ident, $i32_ty:ident) => {
-        impl_simd_base!($engine, $i16_ty, i16, |self| {
+    ($engine:ident, $i16_ty:ident, $i8_ty:ident, $i32_ty:ident, $u8_ty:ident, $u16_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $i16_ty, i16, $mask_ty, i16, |self| {
             self.partial_horizontal_add()
                 .partial_horizontal_add()
                 .partial_horizontal_add()
@@ -796,6 +1475,39 @@ macro_rules! impl_i16_simd_type {
         });
 
         impl SimdInt16 for $i16_ty {
+            #[inline(always)]
+            fn bitcast_u16(self) -> <Self::Engine as Simd>::Vu16 {
+                unsafe {
+                    let bits = Ops::<$engine, i16>::bitcast_binary(self.0);
+                    $u16_ty(Ops::<$engine, binary>::bitcast_u16(bits))
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i16>::saturating_add(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i16>::saturating_sub(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i16>::mul_hi(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn mulhrs(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i16>::mulhrs(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn madd(self, rhs: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i16>::madd(self.0, rhs.0)) }
+            }
+
             #[inline(always)]
             fn extend_to_i32(self) -> (<Self::Engine as Simd>::Vi32, <Self::Engine as Simd>::Vi32) {
                 let (a, b) = unsafe { Ops::<$engine, i16>::extend_i32(self.0) };
@@ -809,13 +1521,32 @@ macro_rules! impl_i16_simd_type {
                 let (a, b) = unsafe { Ops::<$engine, i16>::unsigned_extend_i32(self.0) };
                 ($i32_ty(a), $i32_ty(b))
             }
+
+            #[inline(always)]
+            fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe { $i8_ty(Ops::<$engine, i16>::narrow_saturating_i8(self.0, hi.0)) }
+            }
+
+            #[inline(always)]
+            fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu8 {
+                unsafe {
+                    $u8_ty(Ops::<$engine, i16>::unsigned_narrow_saturating_u8(
+                        self.0, hi.0,
+                    ))
+                }
+            }
+
+            #[inline(always)]
+            fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe { $i8_ty(Ops::<$engine, i16>::narrow_truncating_i8(self.0, hi.0)) }
+            }
         }
     };
 }
 
 macro_rules! impl_i32_simd_type {
-    ($engine:ident, $i32_ty:ident, $f32_ty:ident, $i64_ty:ident) => {
-        impl_simd_base!($engine, $i32_ty, i32, |self| {
+    ($engine:ident, $i32_ty:ident, $i16_ty:ident, $f32_ty:ident, $f64_ty:ident, $i64_ty:ident, $u16_ty:ident, $u32_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $i32_ty, i32, $mask_ty, i32, |self| {
             self.partial_horizontal_add().partial_horizontal_add()
         });
         impl_simd_int!($engine, $i32_ty, i32, |self| {
@@ -834,6 +1565,20 @@ macro_rules! impl_i32_simd_type {
                 unsafe { $f32_ty(Ops::<$engine, i32>::cast_f32(self.0)) }
             }
 
+            #[inline(always)]
+            fn to_f64_pair(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64) {
+                let (a, b) = unsafe { Ops::<$engine, i32>::extend_f64(self.0) };
+                ($f64_ty(a), $f64_ty(b))
+            }
+
+            #[inline(always)]
+            fn bitcast_u32(self) -> <Self::Engine as Simd>::Vu32 {
+                unsafe {
+                    let bits = Ops::<$engine, i32>::bitcast_binary(self.0);
+                    $u32_ty(Ops::<$engine, binary>::bitcast_u32(bits))
+                }
+            }
+
             #[inline(always)]
             fn extend_to_i64(self) -> (<Self::Engine as Simd>::Vi64, <Self::Engine as Simd>::Vi64) {
                 let (a, b) = unsafe { Ops::<$engine, i32>::extend_i64(self.0) };
@@ -847,13 +1592,42 @@ macro_rules! impl_i32_simd_type {
                 let (a, b) = unsafe { Ops::<$engine, i32>::unsigned_extend_i64(self.0) };
                 ($i64_ty(a), $i64_ty(b))
             }
+
+            #[inline(always)]
+            fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe { $i16_ty(Ops::<$engine, i32>::narrow_saturating_i16(self.0, hi.0)) }
+            }
+
+            #[inline(always)]
+            fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu16 {
+                unsafe {
+                    $u16_ty(Ops::<$engine, i32>::unsigned_narrow_saturating_u16(
+                        self.0, hi.0,
+                    ))
+                }
+            }
+
+            #[inline(always)]
+            fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe { $i16_ty(Ops::<$engine, i32>::narrow_truncating_i16(self.0, hi.0)) }
+            }
+
+            #[inline(always)]
+            fn mul_hi(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, i32>::mul_hi(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn transpose(rows: &mut [Self]) {
+                transpose!($engine, i32, rows)
+            }
         }
     };
 }
 
 macro_rules! impl_i64_simd_type {
-    ($engine:ident, $i64_ty:ident, $f64_ty:ident) => {
-        impl_simd_base!($engine, $i64_ty, i64, |self| {
+    ($engine:ident, $i64_ty:ident, $i32_ty:ident, $f64_ty:ident, $u32_ty:ident, $u64_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $i64_ty, i64, $mask_ty, i64, |self| {
             self.partial_horizontal_add()
         });
         impl_simd_int!($engine, $i64_ty, i64, |self| {
@@ -871,20 +1645,143 @@ macro_rules! impl_i64_simd_type {
                 unsafe { $f64_ty(Ops::<$engine, i64>::cast_f64(self.0)) }
             }
 
+            #[inline(always)]
+            fn bitcast_u64(self) -> <Self::Engine as Simd>::Vu64 {
+                unsafe {
+                    let bits = Ops::<$engine, i64>::bitcast_binary(self.0);
+                    $u64_ty(Ops::<$engine, binary>::bitcast_u64(bits))
+                }
+            }
+
             #[inline(always)]
             fn partial_horizontal_add(self) -> i64 {
                 unsafe { Ops::<$engine, i64>::horizontal_add(self.0) }
             }
+
+            #[inline(always)]
+            fn narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i64>::narrow_saturating_i32(self.0, hi.0)) }
+            }
+
+            #[inline(always)]
+            fn unsigned_narrow_saturating(self, hi: Self) -> <Self::Engine as Simd>::Vu32 {
+                unsafe {
+                    $u32_ty(Ops::<$engine, i64>::unsigned_narrow_saturating_u32(
+                        self.0, hi.0,
+                    ))
+                }
+            }
+
+            #[inline(always)]
+            fn narrow_truncating(self, hi: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, i64>::narrow_truncating_i32(self.0, hi.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u8_simd_type {
+    ($engine:ident, $u8_ty:ident, $i8_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $u8_ty, u8, $mask_ty, i8, |self| {
+            self.bitcast_i8().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u8_ty, u8, |self| { self.horizontal_add() });
+
+        impl SimdUint8 for $u8_ty {
+            #[inline(always)]
+            fn bitcast_i8(self) -> <Self::Engine as Simd>::Vi8 {
+                unsafe {
+                    let bits = Ops::<$engine, u8>::bitcast_binary(self.0);
+                    $i8_ty(Ops::<$engine, binary>::bitcast_i8(bits))
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u8>::saturating_add(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u8>::saturating_sub(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u16_simd_type {
+    ($engine:ident, $u16_ty:ident, $i16_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $u16_ty, u16, $mask_ty, i16, |self| {
+            self.bitcast_i16().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u16_ty, u16, |self| { self.horizontal_add() });
+
+        impl SimdUint16 for $u16_ty {
+            #[inline(always)]
+            fn bitcast_i16(self) -> <Self::Engine as Simd>::Vi16 {
+                unsafe {
+                    let bits = Ops::<$engine, u16>::bitcast_binary(self.0);
+                    $i16_ty(Ops::<$engine, binary>::bitcast_i16(bits))
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u16>::saturating_add(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self(Ops::<$engine, u16>::saturating_sub(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u32_simd_type {
+    ($engine:ident, $u32_ty:ident, $i32_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $u32_ty, u32, $mask_ty, i32, |self| {
+            self.bitcast_i32().horizontal_unsigned_add() as u64
+        });
+        impl_simd_int!($engine, $u32_ty, u32, |self| { self.horizontal_add() });
+
+        impl SimdUint32 for $u32_ty {
+            #[inline(always)]
+            fn bitcast_i32(self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe {
+                    let bits = Ops::<$engine, u32>::bitcast_binary(self.0);
+                    $i32_ty(Ops::<$engine, binary>::bitcast_i32(bits))
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_u64_simd_type {
+    ($engine:ident, $u64_ty:ident, $i64_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $u64_ty, u64, $mask_ty, i64, |self| {
+            self.bitcast_i64().horizontal_add() as u64
+        });
+        impl_simd_int!($engine, $u64_ty, u64, |self| { self.horizontal_add() });
+
+        impl SimdUint64 for $u64_ty {
+            #[inline(always)]
+            fn bitcast_i64(self) -> <Self::Engine as Simd>::Vi64 {
+                unsafe {
+                    let bits = Ops::<$engine, u64>::bitcast_binary(self.0);
+                    $i64_ty(Ops::<$engine, binary>::bitcast_i64(bits))
+                }
+            }
         }
     };
 }
 
 macro_rules! impl_f32_simd_type {
-    ($engine:ident, $f32_ty:ident, $i32_ty:ident) => {
-        impl_simd_base!($engine, $f32_ty, f32, |self| {
+    ($engine:ident, $f32_ty:ident, $i32_ty:ident, $f64_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $f32_ty, f32, $mask_ty, i32, |self| {
             unsafe { Ops::<$engine, f32>::horizontal_add(self.0) }
         });
-        impl_simd_float!($engine, $f32_ty, f32);
+        impl_simd_float!($engine, $f32_ty, f32, i32, bitcast_i32, bitcast_f32);
 
         impl SimdFloat32 for $f32_ty {
             #[inline(always)]
@@ -897,20 +1794,36 @@ macro_rules! impl_f32_simd_type {
                 unsafe { $i32_ty(Ops::<$engine, f32>::cast_i32(self.0)) }
             }
 
+            #[inline(always)]
+            fn to_i32_trunc(self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, f32>::trunc_i32(self.0)) }
+            }
+
             #[inline(always)]
             fn fast_inverse(self) -> Self {
                 unsafe { Self(Ops::<$engine, f32>::recip(self.0)) }
             }
+
+            #[inline(always)]
+            fn to_f64_pair(self) -> (<Self::Engine as Simd>::Vf64, <Self::Engine as Simd>::Vf64) {
+                let (a, b) = unsafe { Ops::<$engine, f32>::extend_f64(self.0) };
+                ($f64_ty(a), $f64_ty(b))
+            }
+
+            #[inline(always)]
+            fn transpose(rows: &mut [Self]) {
+                transpose!($engine, f32, rows)
+            }
         }
     };
 }
 
 macro_rules! impl_f64_simd_type {
-    ($engine:ident, $f64_ty:ident, $i64_ty:ident) => {
-        impl_simd_base!($engine, $f64_ty, f64, |self| {
+    ($engine:ident, $f64_ty:ident, $f32_ty:ident, $i32_ty:ident, $i64_ty:ident, $mask_ty:ident) => {
+        impl_simd_base!($engine, $f64_ty, f64, $mask_ty, i64, |self| {
             unsafe { Ops::<$engine, f64>::horizontal_add(self.0) }
         });
-        impl_simd_float!($engine, $f64_ty, f64);
+        impl_simd_float!($engine, $f64_ty, f64, i64, bitcast_i64, bitcast_f64);
 
         impl SimdFloat64 for $f64_ty {
             #[inline(always)]
@@ -922,6 +1835,21 @@ macro_rules! impl_f64_simd_type {
             fn cast_i64(self) -> <Self::Engine as Simd>::Vi64 {
                 unsafe { $i64_ty(Ops::<$engine, f64>::cast_i64(self.0)) }
             }
+
+            #[inline(always)]
+            fn to_i64_trunc(self) -> <Self::Engine as Simd>::Vi64 {
+                unsafe { $i64_ty(Ops::<$engine, f64>::trunc_i64(self.0)) }
+            }
+
+            #[inline(always)]
+            fn to_f32_narrow(self, hi: Self) -> <Self::Engine as Simd>::Vf32 {
+                unsafe { $f32_ty(Ops::<$engine, f64>::narrow_f32(self.0, hi.0)) }
+            }
+
+            #[inline(always)]
+            fn to_i32_narrow(self, hi: Self) -> <Self::Engine as Simd>::Vi32 {
+                unsafe { $i32_ty(Ops::<$engine, f64>::narrow_i32(self.0, hi.0)) }
+            }
         }
     };
 }
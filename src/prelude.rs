@@ -8,7 +8,7 @@ pub use super::invoking::*;
 pub use crate::base::{
     SimdArrayIterator, SimdArrayMutIterator, SimdBase, SimdBaseIo, SimdBaseOps, SimdConsts,
     SimdFloat, SimdFloat32, SimdFloat64, SimdInt, SimdInt16, SimdInt32, SimdInt64, SimdInt8,
-    SimdIter,
+    SimdIter, SimdMask, SimdUint16, SimdUint32, SimdUint64, SimdUint8,
 };
 
 pub use paste::item as simdeez_paste_item;
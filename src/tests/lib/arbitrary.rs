@@ -43,6 +43,10 @@ const IMPORTANT_I8: [i8; 7] = [0, 1, -1, 2, -2, i8::MAX, i8::MIN];
 const IMPORTANT_I16: [i16; 7] = [0, 1, -1, 2, -2, i16::MAX, i16::MIN];
 const IMPORTANT_I32: [i32; 7] = [0, 1, -1, 2, -2, i32::MAX, i32::MIN];
 const IMPORTANT_I64: [i64; 7] = [0, 1, -1, 2, -2, i64::MAX, i64::MIN];
+const IMPORTANT_U8: [u8; 6] = [0, 1, 2, u8::MAX, u8::MAX - 1, 1 << 7];
+const IMPORTANT_U16: [u16; 6] = [0, 1, 2, u16::MAX, u16::MAX - 1, 1 << 15];
+const IMPORTANT_U32: [u32; 6] = [0, 1, 2, u32::MAX, u32::MAX - 1, 1 << 31];
+const IMPORTANT_U64: [u64; 6] = [0, 1, 2, u64::MAX, u64::MAX - 1, 1 << 63];
 
 fn iter_arbitrary_f32(interval: usize) -> impl Iterator<Item = f32> {
     assert!(interval > IMPORTANT_F32.len());
@@ -116,6 +120,22 @@ fn iter_arbitrary_i64(interval: usize) -> impl Iterator<Item = i64> {
     iter_arbitrary_ints(interval, &IMPORTANT_I64, i64::MIN..=i64::MAX)
 }
 
+fn iter_arbitrary_u8(interval: usize) -> impl Iterator<Item = u8> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U8, u8::MIN..=u8::MAX)
+}
+
+fn iter_arbitrary_u16(interval: usize) -> impl Iterator<Item = u16> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U16, u16::MIN..=u16::MAX)
+}
+
+fn iter_arbitrary_u32(interval: usize) -> impl Iterator<Item = u32> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U32, u32::MIN..=u32::MAX)
+}
+
+fn iter_arbitrary_u64(interval: usize) -> impl Iterator<Item = u64> {
+    iter_arbitrary_ints(interval, &IMPORTANT_U64, u64::MIN..=u64::MAX)
+}
+
 fn iter_arbitrary_blendv_i8() -> impl Iterator<Item = i8> {
     [-1, 0].iter().cycle().copied()
 }
@@ -132,6 +152,22 @@ fn iter_arbitrary_blendv_i64() -> impl Iterator<Item = i64> {
     [-1, 0].iter().cycle().copied()
 }
 
+fn iter_arbitrary_blendv_u8() -> impl Iterator<Item = u8> {
+    [u8::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u16() -> impl Iterator<Item = u16> {
+    [u16::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u32() -> impl Iterator<Item = u32> {
+    [u32::MAX, 0].iter().cycle().copied()
+}
+
+fn iter_arbitrary_blendv_u64() -> impl Iterator<Item = u64> {
+    [u64::MAX, 0].iter().cycle().copied()
+}
+
 fn iter_arbitrary_blendv_f32() -> impl Iterator<Item = f32> {
     iter::once(f32::from_bits(u32::MAX))
         .chain(iter::once(f32::from_bits(0)))
@@ -225,6 +261,37 @@ impl RandSimd {
             scalar_size: 8,
         }
     }
+    pub fn u8() -> IterRandSimdForScalar<u8, impl Iterator<Item = u8>, impl Iterator<Item = u8>> {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u8),
+            blendv: Box::new(iter_arbitrary_blendv_u8),
+            scalar_size: 1,
+        }
+    }
+    pub fn u16() -> IterRandSimdForScalar<u16, impl Iterator<Item = u16>, impl Iterator<Item = u16>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u16),
+            blendv: Box::new(iter_arbitrary_blendv_u16),
+            scalar_size: 2,
+        }
+    }
+    pub fn u32() -> IterRandSimdForScalar<u32, impl Iterator<Item = u32>, impl Iterator<Item = u32>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u32),
+            blendv: Box::new(iter_arbitrary_blendv_u32),
+            scalar_size: 4,
+        }
+    }
+    pub fn u64() -> IterRandSimdForScalar<u64, impl Iterator<Item = u64>, impl Iterator<Item = u64>>
+    {
+        IterRandSimdForScalar {
+            any: Box::new(iter_arbitrary_u64),
+            blendv: Box::new(iter_arbitrary_blendv_u64),
+            scalar_size: 8,
+        }
+    }
 }
 
 impl<N: ScalarNumber, I: Iterator<Item = N>, I2: Iterator<Item = N>>
@@ -274,6 +341,67 @@ impl<N: ScalarNumber, I: Iterator<Item = N>, I2: Iterator<Item = N>>
         iter.map(|v| (v,)).take(1000 * S::WIDTH)
     }
 
+    /// Same as one_arg, except starting with values that are easy to round incorrectly
+    pub fn one_arg_rounding_edge_cases<S: SimdBase<Scalar = N>>(
+        self,
+    ) -> impl Iterator<Item = (S,)> {
+        let iter = iter_as_simd(
+            N::ROUNDING_EDGE_CASES
+                .iter()
+                .copied()
+                .chain((self.any)(1000)),
+        );
+        iter.map(|v| (v,)).take(1000 * S::WIDTH)
+    }
+
+    /// Same as one_arg, except with random runs of bits cleared at both ends of each value
+    pub fn one_arg_bit_runs<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S,)> {
+        let bits = self.scalar_size as u32 * 8;
+        let mut rng = ChaCha8Rng::seed_from_u64(bits as u64);
+        let iter = iter_as_simd(
+            (self.any)(1000)
+                .map(move |v| v.clear_outer_bits(rng.gen_range(0..bits), rng.gen_range(0..bits))),
+        );
+        iter.map(|v| (v,)).take(1000 * S::WIDTH)
+    }
+
+    /// Same as one_arg, except starting with values that saturate or round incorrectly when cast
+    pub fn one_arg_cast_edge_cases<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S,)> {
+        let iter = iter_as_simd(
+            N::CAST_EDGE_CASES
+                .iter()
+                .chain(N::ROUNDING_EDGE_CASES)
+                .copied()
+                .chain((self.any)(1000)),
+        );
+        iter.map(|v| (v,)).take(1000 * S::WIDTH)
+    }
+
+    /// Same as one_arg, except starting with values whose sign is easy to get wrong
+    pub fn one_arg_sign_edge_cases<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S,)> {
+        let iter = iter_as_simd(N::SIGN_EDGE_CASES.iter().copied().chain((self.any)(1000)));
+        iter.map(|v| (v,)).take(1000 * S::WIDTH)
+    }
+
+    /// Same as two_arg, except that both arguments go through every pair of values whose sign is
+    /// easy to get wrong first
+    pub fn two_arg_sign_edge_cases<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S, S)> {
+        let cases = N::SIGN_EDGE_CASES;
+        let pairs = cases
+            .iter()
+            .flat_map(move |&a| cases.iter().map(move |&b| (a, b)));
+        let (firsts, seconds): (Vec<N>, Vec<N>) = pairs.unzip();
+        let iter1 = iter_as_simd(firsts.into_iter().chain((self.any)(14)));
+        let iter2 = iter_as_simd(seconds.into_iter().chain((self.any)(15)));
+        iter1.zip(iter2).take(14 * 15 * 20 * S::WIDTH)
+    }
+
+    /// Same as one_arg, except filtering out NaN floats
+    pub fn one_arg_nan_filtered<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S,)> {
+        let iter = iter_as_simd((self.any)(1000).filter(|v| !v.is_float_nan()));
+        iter.map(|v| (v,)).take(1000 * S::WIDTH)
+    }
+
     /// Same as two_arg, except filtering out NaN floats
     pub fn two_arg_nan_filtered<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S, S)> {
         let iter1 = iter_as_simd((self.any)(15).filter(|v| !v.is_float_nan()));
@@ -281,7 +409,7 @@ impl<N: ScalarNumber, I: Iterator<Item = N>, I2: Iterator<Item = N>>
         iter1.zip(iter2).take(14 * 15 * 20 * S::WIDTH)
     }
 
-    /// A blendv mask that's not all 0's or all 1's is undefined behavior between different architectures.
+    /// Same as three_arg, except every lane of the first argument is either all 0's or all 1's.
     pub fn iter_blendv_ags<S: SimdBase<Scalar = N>>(self) -> impl Iterator<Item = (S, S, S)> {
         let mut mask_iter = iter_as_simd((self.blendv)());
         let mut iter2 = iter_as_simd((self.any)(15));
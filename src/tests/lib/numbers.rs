@@ -16,11 +16,26 @@ impl EqPrecision {
     }
 }
 
-pub trait ScalarNumber: PartialEq + Copy + core::fmt::Display {
+pub trait ScalarNumber: PartialEq + Copy + core::fmt::Display + 'static {
+    /// Values that are easy to round incorrectly, e.g. ties and odd numbers right above the
+    /// point where every float is an integer.
+    const ROUNDING_EDGE_CASES: &'static [Self] = &[];
+
+    /// Values right around the limits of the integer of the same width, where casting saturates.
+    const CAST_EDGE_CASES: &'static [Self] = &[];
+
+    /// Values whose sign is easy to get wrong, like signed zeros, infinities and NaNs.
+    const SIGN_EDGE_CASES: &'static [Self] = &[];
+
     fn almost_eq(self, other: Self, _precision: EqPrecision) -> bool {
         self == other
     }
 
+    /// Compares the bits of both numbers, so that signed zeros and NaNs have to match too.
+    fn bit_eq(self, other: Self) -> bool {
+        self == other
+    }
+
     fn is_minimum_int(&self) -> bool {
         false
     }
@@ -40,6 +55,15 @@ pub trait ScalarNumber: PartialEq + Copy + core::fmt::Display {
         false
     }
 
+    /// Shifts the bits of an integer left and then logically right, clearing runs of bits at both
+    /// ends of the value.
+    fn clear_outer_bits(self, _left: u32, _right: u32) -> Self {
+        self
+    }
+
+    /// Whether the highest bit is set, which is what makes a lane truthy in `get_mask`.
+    fn is_sign_bit_set(self) -> bool;
+
     fn unchecked_add(self, other: Self) -> Self;
 }
 
@@ -48,10 +72,18 @@ pub trait IntScalarNumber: ScalarNumber {
 }
 
 impl ScalarNumber for i8 {
+    fn is_sign_bit_set(self) -> bool {
+        self < 0
+    }
+
     fn is_minimum_int(&self) -> bool {
         *self == i8::MIN
     }
 
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        ((self as u8) << left >> right) as i8
+    }
+
     fn unchecked_add(self, other: Self) -> Self {
         self.wrapping_add(other)
     }
@@ -64,10 +96,18 @@ impl IntScalarNumber for i8 {
 }
 
 impl ScalarNumber for i16 {
+    fn is_sign_bit_set(self) -> bool {
+        self < 0
+    }
+
     fn is_minimum_int(&self) -> bool {
         *self == i16::MIN
     }
 
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        ((self as u16) << left >> right) as i16
+    }
+
     fn unchecked_add(self, other: Self) -> Self {
         self.wrapping_add(other)
     }
@@ -80,10 +120,18 @@ impl IntScalarNumber for i16 {
 }
 
 impl ScalarNumber for i32 {
+    fn is_sign_bit_set(self) -> bool {
+        self < 0
+    }
+
     fn is_minimum_int(&self) -> bool {
         *self == i32::MIN
     }
 
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        ((self as u32) << left >> right) as i32
+    }
+
     fn unchecked_add(self, other: Self) -> Self {
         self.wrapping_add(other)
     }
@@ -96,10 +144,18 @@ impl IntScalarNumber for i32 {
 }
 
 impl ScalarNumber for i64 {
+    fn is_sign_bit_set(self) -> bool {
+        self < 0
+    }
+
     fn is_minimum_int(&self) -> bool {
         *self == i64::MIN
     }
 
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        ((self as u64) << left >> right) as i64
+    }
+
     fn unchecked_add(self, other: Self) -> Self {
         self.wrapping_add(other)
     }
@@ -111,7 +167,129 @@ impl IntScalarNumber for i64 {
     }
 }
 
+impl ScalarNumber for u8 {
+    fn is_sign_bit_set(self) -> bool {
+        self.leading_zeros() == 0
+    }
+
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        self << left >> right
+    }
+
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl IntScalarNumber for u8 {
+    fn unsigned_cast_to_i64(self) -> i64 {
+        self as u64 as i64
+    }
+}
+
+impl ScalarNumber for u16 {
+    fn is_sign_bit_set(self) -> bool {
+        self.leading_zeros() == 0
+    }
+
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        self << left >> right
+    }
+
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl IntScalarNumber for u16 {
+    fn unsigned_cast_to_i64(self) -> i64 {
+        self as u64 as i64
+    }
+}
+
+impl ScalarNumber for u32 {
+    fn is_sign_bit_set(self) -> bool {
+        self.leading_zeros() == 0
+    }
+
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        self << left >> right
+    }
+
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl IntScalarNumber for u32 {
+    fn unsigned_cast_to_i64(self) -> i64 {
+        self as u64 as i64
+    }
+}
+
+impl ScalarNumber for u64 {
+    fn is_sign_bit_set(self) -> bool {
+        self.leading_zeros() == 0
+    }
+
+    fn clear_outer_bits(self, left: u32, right: u32) -> Self {
+        self << left >> right
+    }
+
+    fn unchecked_add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+}
+
+impl IntScalarNumber for u64 {
+    fn unsigned_cast_to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
 impl ScalarNumber for f32 {
+    const SIGN_EDGE_CASES: &'static [Self] = &[
+        0.0,
+        -0.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        -f32::NAN,
+        -2.5,
+        f32::MIN_POSITIVE,
+    ];
+
+    const CAST_EDGE_CASES: &'static [Self] = &[
+        2147483520.0,
+        2147483648.0,
+        -2147483648.0,
+        -2147483904.0,
+        0.99999994,
+        -0.99999994,
+        -1.5,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+
+    const ROUNDING_EDGE_CASES: &'static [Self] = &[
+        2.5,
+        -2.5,
+        0.49999997,
+        -0.49999997,
+        -0.25,
+        4194304.5,
+        8388607.5,
+        8388609.0,
+        -8388609.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+
+    fn is_sign_bit_set(self) -> bool {
+        self.is_sign_negative()
+    }
+
     fn almost_eq(self, other: Self, precision: EqPrecision) -> bool {
         if self.is_nan() && other.is_nan() {
             return true;
@@ -148,6 +326,10 @@ impl ScalarNumber for f32 {
         self.is_nan()
     }
 
+    fn bit_eq(self, other: Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+
     fn is_undefined_behavior_when_rounding(self) -> bool {
         // Anything that's close to ending in .5 may cause undefined behavior
         ((self.abs() % 1.0) - 0.5).abs() < f32::EPSILON
@@ -165,6 +347,52 @@ impl ScalarNumber for f32 {
     }
 }
 impl ScalarNumber for f64 {
+    const SIGN_EDGE_CASES: &'static [Self] = &[
+        0.0,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -f64::NAN,
+        -2.5,
+        f64::MIN_POSITIVE,
+    ];
+
+    const CAST_EDGE_CASES: &'static [Self] = &[
+        9223372036854774784.0,
+        9223372036854775808.0,
+        -9223372036854775808.0,
+        -9223372036854777856.0,
+        2147483647.5,
+        2147483648.0,
+        -2147483648.5,
+        -2147483649.0,
+        0.9999999999999999,
+        -0.9999999999999999,
+        -1.5,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+
+    const ROUNDING_EDGE_CASES: &'static [Self] = &[
+        2.5,
+        -2.5,
+        0.49999999999999994,
+        -0.49999999999999994,
+        -0.25,
+        2251799813685248.5,
+        4503599627370495.5,
+        4503599627370497.0,
+        -4503599627370497.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+
+    fn is_sign_bit_set(self) -> bool {
+        self.is_sign_negative()
+    }
+
     fn almost_eq(self, other: Self, precision: EqPrecision) -> bool {
         if self.is_nan() && other.is_nan() {
             return true;
@@ -201,6 +429,10 @@ impl ScalarNumber for f64 {
         self.is_nan()
     }
 
+    fn bit_eq(self, other: Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+
     fn is_undefined_behavior_when_rounding(self) -> bool {
         // Anything that's close to ending in .5 may cause undefined behavior
         ((self.abs() % 1.0) - 0.5).abs() < f64::EPSILON
@@ -1,6 +1,9 @@
-use core::{fmt::Debug, ops::Add};
+use core::{
+    fmt::Debug,
+    ops::{Add, Mul},
+};
 
-use crate::{SimdBase, SimdBaseIo};
+use crate::{LaneIndices, Simd, SimdBase, SimdBaseIo, SimdConsts, SimdInt16, SimdInt8, SimdMask};
 
 use super::{
     fn_tuple::{Func, Tuple},
@@ -66,6 +69,86 @@ pub fn elementwise_eq_tester<
     });
 }
 
+/// Same as `elementwise_eq_tester` with exact precision, for testers that don't take a precision.
+pub fn exact_eq_tester<
+    N: ScalarNumber,
+    RN: ScalarNumber,
+    Args: Tuple + Debug + Clone + SimdTupleIterable<N>,
+    ScalarArg: SimdBase<Scalar = N>,
+    SimdRet: SimdBase<Scalar = RN>,
+    ScalarRet: SimdBase<Scalar = RN>,
+>(
+    inputs: impl Iterator<Item = Args>,
+    simd_fn: impl Func<Args, Output = SimdRet>,
+    scalar_fn: impl Func<Args::AsTuple<ScalarArg>, Output = ScalarRet>,
+) {
+    elementwise_eq_tester(inputs, EqPrecision::exact(), simd_fn, scalar_fn);
+}
+
+/// Checks each lane against `scalar_fn` called on the lanes of the arguments, which is usually the
+/// std function of the same name. The bits have to match, including the signs of zeros and NaNs.
+pub fn std_eq_tester<
+    N: ScalarNumber,
+    Args: Tuple + Debug + Clone + SimdTupleIterable<N>,
+    SimdRet: SimdBase<Scalar = N>,
+>(
+    inputs: impl Iterator<Item = Args>,
+    simd_fn: impl Func<Args, Output = SimdRet>,
+    scalar_fn: impl Func<Args::AsScalar, Output = N>,
+) {
+    check_elementwise_function(inputs, simd_fn, |result, args| {
+        let expected = scalar_fn.call(args);
+        if expected.bit_eq(result) {
+            Ok(())
+        } else {
+            Err(format!("Expected {expected}, got {result}"))
+        }
+    });
+}
+
+/// Checks each lane of the returned mask against the scalar engine, along with the
+/// mask's bitmask and lane counts.
+pub fn mask_eq_tester<
+    N: ScalarNumber,
+    Args: Tuple + Debug + Clone + SimdTupleIterable<N>,
+    ScalarArg: SimdBase<Scalar = N>,
+    SimdRet: SimdMask,
+    ScalarRet: SimdMask,
+>(
+    inputs: impl Iterator<Item = Args>,
+    simd_fn: impl Func<Args, Output = SimdRet>,
+    scalar_fn: impl Func<Args::AsTuple<ScalarArg>, Output = ScalarRet>,
+) {
+    check_function(inputs, simd_fn, |result, args| {
+        let bits = result.to_bitmask();
+        let mut count = 0;
+        for (i, scalars) in args.iter_scalars().enumerate() {
+            let expected = scalar_fn.call(Args::wrap_scalars(scalars)).to_bitmask() == 1;
+            let actual = (bits >> i) & 1 == 1;
+            if expected != actual {
+                return Err(format!(
+                    "Failed for element {i}: Expected {expected}, got {actual}"
+                ));
+            }
+            count += expected as usize;
+        }
+
+        if bits.checked_shr(SimdRet::WIDTH as u32).unwrap_or(0) != 0 {
+            return Err(format!("Bitmask {bits:#x} has bits set past the last lane"));
+        }
+        if result.count() != count
+            || result.any() != (count > 0)
+            || result.all() != (count == SimdRet::WIDTH)
+            || result.none() != (count == 0)
+        {
+            return Err(format!(
+                "Lane counts don't match {count} set lanes for {result:?}"
+            ));
+        }
+        Ok(())
+    });
+}
+
 pub fn bitshift_eq_tester<
     N: ScalarNumber + Eq,
     SimdArg: SimdBase<Scalar = N>,
@@ -91,252 +174,1392 @@ pub fn bitshift_eq_tester<
     });
 }
 
-pub fn horizontal_add_tester<
-    RN: ScalarNumber + Add<Output = RN> + Default,
-    N: ScalarNumber + Add<Output = N> + Default + Into<RN>,
-    SimdArg: SimdBase<Scalar = N>,
->(
-    inputs: impl Iterator<Item = (SimdArg,)>,
-    simd_fn: impl Func<(SimdArg,), Output = RN>,
+pub fn align_right_tester<SimdArg: SimdBase<Scalar = i8>>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+    offset: usize,
+    simd_fn: impl Func<(SimdArg, SimdArg), Output = SimdArg>,
 ) {
     check_function(inputs, simd_fn, |result, args| {
-        let mut sum: RN = Default::default();
-        for scalar in args.0.iter() {
-            sum = sum.unchecked_add(scalar.into());
-        }
-
-        let equal = sum.almost_eq(result, EqPrecision::almost(5));
-        if !equal {
-            return Err(format!("Failed: Expected sum to be {sum}, got {result}",));
+        let joined: Vec<i8> = args.1.iter().chain(args.0.iter()).collect();
+        for i in 0..SimdArg::WIDTH {
+            let expected = joined.get(i + offset).copied().unwrap_or(0);
+            if expected != result[i] {
+                return Err(format!(
+                    "Failed for element {}: Expected {}, got {}",
+                    i, expected, result[i]
+                ));
+            }
         }
         Ok(())
     });
 }
 
-pub fn unsigned_horizontal_add_tester<
-    N: IntScalarNumber + Add<Output = N> + Default,
-    SimdArg: SimdBase<Scalar = N>,
->(
+/// Builds a 16 entry table out of the lanes of a vector, repeating them for narrow vectors.
+fn lookup_table<SimdArg: SimdBase<Scalar = i8>>(v: SimdArg) -> [i8; 16] {
+    core::array::from_fn(|j| v[j % SimdArg::WIDTH].wrapping_add(j as i8))
+}
+
+/// Checks `lookup16` and `shuffle_bytes` against picking each lane by hand. The second input holds the
+/// indices, masked with `index_mask` so that indices within range are common.
+pub fn byte_shuffle_tester<SimdArg: SimdInt8>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+    index_mask: i8,
+) {
+    let indices = |b: SimdArg| b & SimdArg::set1(index_mask);
+    let inputs: Vec<_> = inputs.collect();
+
+    check_function(
+        inputs.iter().copied(),
+        |a: SimdArg, b: SimdArg| SimdArg::lookup16(&lookup_table(a), indices(b)),
+        |result, args| {
+            let table = lookup_table(args.0);
+            let indices = indices(args.1);
+            for i in 0..SimdArg::WIDTH {
+                let expected = table.get(indices[i] as u8 as usize).copied().unwrap_or(0);
+                if expected != result[i] {
+                    return Err(format!(
+                        "lookup16 failed for element {}: Expected {}, got {}",
+                        i, expected, result[i]
+                    ));
+                }
+            }
+            Ok(())
+        },
+    );
+
+    check_function(
+        inputs.into_iter(),
+        |a: SimdArg, b: SimdArg| a.shuffle_bytes(indices(b)),
+        |result, args| {
+            let indices = indices(args.1);
+            for i in 0..SimdArg::WIDTH {
+                let index = indices[i] as u8 as usize;
+                let expected = if index < SimdArg::WIDTH {
+                    args.0[index]
+                } else {
+                    0
+                };
+                if expected != result[i] {
+                    return Err(format!(
+                        "shuffle_bytes failed for element {}: Expected {}, got {}",
+                        i, expected, result[i]
+                    ));
+                }
+            }
+            Ok(())
+        },
+    );
+}
+
+/// Checks `get_mask` and the truthy helpers against the sign bit of each lane, for each input
+/// and for a splat of its first lane so that the all truthy and all falsy cases are covered.
+pub fn truthy_tester<N: ScalarNumber, SimdArg: SimdBase<Scalar = N>>(
     inputs: impl Iterator<Item = (SimdArg,)>,
-    simd_fn: impl Func<(SimdArg,), Output = i64>,
 ) {
-    check_function(inputs, simd_fn, |result, args| {
-        let mut sum: i64 = 0;
-        for scalar in args.0.iter() {
-            sum = sum.wrapping_add(scalar.unsigned_cast_to_i64());
+    let check = |v: SimdArg| -> Result<(), String> {
+        let lanes: Vec<bool> = v.iter().map(|n| n.is_sign_bit_set()).collect();
+        let expected = lanes
+            .iter()
+            .enumerate()
+            .fold(0u64, |mask, (i, &set)| mask | ((set as u64) << i));
+
+        let mask = v.get_mask();
+        if mask != expected {
+            return Err(format!("Expected mask {expected:#x}, got {mask:#x}"));
         }
 
-        let equal = sum.almost_eq(result, EqPrecision::almost(5));
-        if !equal {
-            return Err(format!("Failed: Expected sum to be {sum}, got {result}",));
+        let checks = [
+            (
+                "is_any_truthy",
+                Some(v.is_any_truthy() as usize),
+                Some(lanes.iter().any(|&l| l) as usize),
+            ),
+            (
+                "is_all_truthy",
+                Some(v.is_all_truthy() as usize),
+                Some(lanes.iter().all(|&l| l) as usize),
+            ),
+            (
+                "index_of_first_truthy",
+                v.index_of_first_truthy(),
+                lanes.iter().position(|&l| l),
+            ),
+            (
+                "index_of_first_falsy",
+                v.index_of_first_falsy(),
+                lanes.iter().position(|&l| !l),
+            ),
+            (
+                "index_of_last_truthy",
+                v.index_of_last_truthy(),
+                lanes.iter().rposition(|&l| l),
+            ),
+            (
+                "index_of_last_falsy",
+                v.index_of_last_falsy(),
+                lanes.iter().rposition(|&l| !l),
+            ),
+        ];
+        for (name, actual, expected) in checks {
+            if actual != expected {
+                return Err(format!("{name}: Expected {expected:?}, got {actual:?}"));
+            }
         }
         Ok(())
-    });
-}
+    };
 
-#[macro_export]
-macro_rules! elementwise_eq_tester {
-    (< $simd_kind:ident :: $simd_ty:ident as $base_kind:ident >  :: $fn_name:ident, $inputs:expr, $precision:expr) => {{
-        let f = <<$simd_kind as Simd>::$simd_ty as $base_kind>::$fn_name;
-        let sf = <<Scalar as Simd>::$simd_ty as $base_kind>::$fn_name;
-        elementwise_eq_tester($inputs, $precision, f, sf);
-    }};
+    for (v,) in inputs {
+        let splat = SimdArg::set1(v[0]);
+        for v in [v, splat] {
+            if let Err(str) = check(v) {
+                panic!("\nFailed for {:?}: {}", v, str);
+            }
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! bitshift_eq_tester {
-    ($simd_kind:ident :: $simd_ty:ident :: $fn_name:ident, $inputs:expr) => {{
-        let f = <<$simd_kind as Simd>::$simd_ty as SimdInt>::$fn_name;
-        let sf = <<Scalar as Simd>::$simd_ty as SimdInt>::$fn_name;
-        bitshift_eq_tester($inputs, f, sf);
-    }};
-}
+/// Gathers from a slice built out of the inputs, checking both `gather` and `gather_unchecked`
+/// against indexing the slice directly.
+pub fn gather_tester<
+    N: ScalarNumber,
+    I: ScalarNumber + TryFrom<usize>,
+    Indices: SimdBase<Scalar = I>,
+    SimdArg: SimdBase<Scalar = N, GatherIndices = Indices>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+) {
+    let data: Vec<N> = inputs
+        .take(64)
+        .flat_map(|(v,)| v.iter().collect::<Vec<_>>())
+        .collect();
+    let max_index = data.len().min(i8::MAX as usize + 1);
 
-#[macro_export]
-macro_rules! const_bitshift_eq_tester {
-    ($scalar_ty:ident, $simd_kind:ident :: $simd_ty:ident :: $fn_name:ident, $inputs:expr) => {{
-        let f = |val, s| {
-            macro_rules! expand {
-                ($imm8:literal) => {
-                    <<$simd_kind as Simd>::$simd_ty as SimdInt>::$fn_name::<$imm8>(val)
-                };
-            }
-            test_constify_imm8_for_bitshift!($scalar_ty, s, expand)
-        };
-        let sf = |val, s| {
-            macro_rules! expand {
-                ($imm8:literal) => {
-                    <<Scalar as Simd>::$simd_ty as SimdInt>::$fn_name::<$imm8>(val)
-                };
+    for k in 0..64 {
+        let mut indices = Indices::zeroes();
+        for i in 0..Indices::WIDTH {
+            let index = ((k * Indices::WIDTH + i) * 7919) % max_index;
+            indices[i] = I::try_from(index).ok().unwrap();
+        }
+
+        let results = [SimdArg::gather(&data, indices), unsafe {
+            SimdArg::gather_unchecked(&data, indices)
+        }];
+        for result in results {
+            for i in 0..SimdArg::WIDTH {
+                let index = ((k * Indices::WIDTH + i) * 7919) % max_index;
+                if !data[index].almost_eq(result[i], EqPrecision::exact()) {
+                    panic!(
+                        "\nFailed for {:?}: element {}: Expected {}, got {}",
+                        indices, i, data[index], result[i]
+                    );
+                }
             }
-            test_constify_imm8_for_bitshift!($scalar_ty, s, expand)
-        };
-        bitshift_eq_tester($inputs, f, sf);
-    }};
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! horizontal_add_tester {
-    (signed $simd_kind:ident :: $simd_ty:ident, $inputs:expr) => {{
-        let f = <<$simd_kind as Simd>::$simd_ty as SimdBaseOps>::horizontal_add;
-        horizontal_add_tester($inputs, f);
-    }};
+/// Scatters the first input into a small slice, checking `scatter`, `scatter_masked` and their
+/// unchecked variants against storing each lane in order. Lanes of the mask are set where
+/// the first input is less than the second.
+pub fn scatter_tester<
+    N: ScalarNumber + Default,
+    I: ScalarNumber + TryFrom<usize>,
+    Indices: SimdBase<Scalar = I>,
+    SimdArg: SimdBase<Scalar = N, GatherIndices = Indices>,
+>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+) {
+    // Shorter than the vector, so that some lanes share an index.
+    let len = SimdArg::WIDTH / 2 + 1;
 
-    (unsigned $simd_kind:ident :: $simd_ty:ident, $inputs:expr) => {{
-        let f = <<$simd_kind as Simd>::$simd_ty as SimdInt>::horizontal_unsigned_add;
-        unsigned_horizontal_add_tester($inputs, f);
-    }};
-}
+    for (k, (value, other)) in inputs.enumerate() {
+        let mut indices = Indices::zeroes();
+        for i in 0..Indices::WIDTH {
+            let index = ((k * Indices::WIDTH + i) * 7919) % len;
+            indices[i] = I::try_from(index).ok().unwrap();
+        }
+        let mask = value.cmp_lt(other);
+        let bits = mask.to_bitmask();
 
-#[macro_export]
-macro_rules! with_feature_flag {
-    (Avx2, $($r:tt)+) => {
-        #[cfg(target_feature = "avx2")]
-        $($r)+
-    };
-    (Sse2, $($r:tt)+) => {
-        #[cfg(target_feature = "sse2")]
-        $($r)+
-    };
-    (Sse41, $($r:tt)+) => {
-        #[cfg(target_feature = "sse4.1")]
-        $($r)+
-    };
-    (Neon, $($r:tt)+) => {
-        #[cfg(target_feature = "neon")]
-        $($r)+
-    };
-    (Wasm, $($r:tt)+) => {
-        #[cfg(target_feature = "simd128")]
-        $($r)+
-    };
-    (Scalar, $($r:tt)+) => {
-        $($r)+
-    };
+        let mut expected = vec![N::default(); len];
+        let mut expected_masked = vec![N::default(); len];
+        for i in 0..SimdArg::WIDTH {
+            let index = ((k * Indices::WIDTH + i) * 7919) % len;
+            expected[index] = value[i];
+            if (bits >> i) & 1 == 1 {
+                expected_masked[index] = value[i];
+            }
+        }
+
+        let mut results = vec![vec![N::default(); len]; 4];
+        value.scatter(&mut results[0], indices);
+        unsafe { value.scatter_unchecked(&mut results[1], indices) };
+        value.scatter_masked(&mut results[2], indices, mask);
+        unsafe { value.scatter_masked_unchecked(&mut results[3], indices, mask) };
+
+        for (r, result) in results.iter().enumerate() {
+            let expected = if r < 2 { &expected } else { &expected_masked };
+            for i in 0..len {
+                if !expected[i].almost_eq(result[i], EqPrecision::exact()) {
+                    panic!(
+                        "\nFailed for {:?} with {:?} and {:?}: element {}: Expected {}, got {}",
+                        value, indices, mask, i, expected[i], result[i]
+                    );
+                }
+            }
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! elementwise_eq_tester_impl {
-    (@full $simd:ident, $simd_ty:ident, $simd_base:ident, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        with_feature_flag!($simd,
-            paste::item! {
-                #[test]
-                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
-                    elementwise_eq_tester!(
-                        <$simd:: [<V$simd_ty>] as $simd_base>::$simd_fn,
-                        RandSimd::$simd_ty().$arg_cnt(),
-                        $precision
+/// Checks `load_partial`, the masked loads and the masked stores for every slice length up to one
+/// past the width. Lanes of the mask are set where the first input is less than the second, and
+/// loads read from a slice holding the second input.
+pub fn masked_io_tester<N: ScalarNumber + Default, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+) {
+    for (value, other) in inputs {
+        let mask = value.cmp_lt(other);
+        let bits = mask.to_bitmask();
+        let data: Vec<N> = other.iter().collect();
+
+        let check = |name: &str, len: usize, expected: &[N], result: &[N]| {
+            for i in 0..expected.len() {
+                if !expected[i].almost_eq(result[i], EqPrecision::exact()) {
+                    panic!(
+                        "\n{} failed for {:?} with {:?} and length {}: element {}: Expected {}, got {}",
+                        name, value, mask, len, i, expected[i], result[i]
                     );
                 }
             }
-        );
-    };
+        };
 
-    (@simdkind $simd_ty:ident, $simd_base:ident, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@full Scalar, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@full Avx2, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@full Sse2, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@full Sse41, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@full Neon, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@full Wasm, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
-    };
+        for len in 0..=SimdArg::WIDTH + 1 {
+            let slice = &data[..len.min(SimdArg::WIDTH)];
+            let active = |i: usize| i < slice.len() && (bits >> i) & 1 == 1;
 
-    (SimdBaseOps, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind i8, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i16, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind f32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind f64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
-    };
+            let partial: Vec<N> = (0..SimdArg::WIDTH)
+                .map(|i| slice.get(i).copied().unwrap_or_default())
+                .collect();
+            check(
+                "load_partial",
+                len,
+                &partial,
+                &SimdArg::load_partial(slice).iter().collect::<Vec<_>>(),
+            );
 
-    (SimdInt, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind i8, SimdInt, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i16, SimdInt, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i32, SimdInt, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind i64, SimdInt, $simd_fn, $arg_cnt, $precision);
-    };
+            let masked: Vec<N> = (0..SimdArg::WIDTH)
+                .map(|i| if active(i) { slice[i] } else { N::default() })
+                .collect();
+            let loaded = SimdArg::load_from_slice_masked(slice, mask);
+            check(
+                "load_from_slice_masked",
+                len,
+                &masked,
+                &loaded.iter().collect::<Vec<_>>(),
+            );
 
-    (SimdFloat, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind f32, SimdFloat, $simd_fn, $arg_cnt, $precision);
-        elementwise_eq_tester_impl!(@simdkind f64, SimdFloat, $simd_fn, $arg_cnt, $precision);
-    };
+            let mut expected = vec![N::default(); slice.len()];
+            for (i, lane) in expected.iter_mut().enumerate() {
+                if active(i) {
+                    *lane = value[i];
+                }
+            }
+            let mut stored = vec![N::default(); slice.len()];
+            value.store_to_slice_masked(&mut stored, mask);
+            check("store_to_slice_masked", len, &expected, &stored);
+        }
+    }
+}
 
-    (SimdFloat32, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind f32, SimdFloat32, $simd_fn, $arg_cnt, $precision);
-    };
+/// A shuffle picking lanes past the width, which wrap around.
+struct Scrambled;
 
-    (SimdInt32, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind i32, SimdInt32, $simd_fn, $arg_cnt, $precision);
+impl<const WIDTH: usize> LaneIndices<WIDTH> for Scrambled {
+    const INDICES: [usize; WIDTH] = {
+        let mut indices = [0; WIDTH];
+        let mut i = 0;
+        while i < WIDTH {
+            indices[i] = i * 7919 % 256;
+            i += 1;
+        }
+        indices
     };
+}
 
-    (SimdFloat64, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind f64, SimdFloat64, $simd_fn, $arg_cnt, $precision);
-    };
+/// A shuffle picking every third lane, starting from lane 5.
+struct StrideThree;
 
-    (SimdInt64, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
-        elementwise_eq_tester_impl!(@simdkind i64, SimdInt64, $simd_fn, $arg_cnt, $precision);
+impl<const WIDTH: usize> LaneIndices<WIDTH> for StrideThree {
+    const INDICES: [usize; WIDTH] = {
+        let mut indices = [0; WIDTH];
+        let mut i = 0;
+        while i < WIDTH {
+            indices[i] = i * 3 + 5;
+            i += 1;
+        }
+        indices
     };
 }
 
-#[macro_export]
-macro_rules! bitshift_eq_tester_impl {
-    (@full dyn, $simd:ident, $simd_ty:ident, $simd_fn:ident) => {
-        with_feature_flag!($simd,
-            paste::item! {
-                #[test]
-                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
-                    bitshift_eq_tester!(
-                        $simd:: [<V$simd_ty>]::$simd_fn,
-                        RandSimd::$simd_ty().one_arg_and_bitshift_arg()
-                    );
+/// Checks `permute` with indices that are negative or past the width, and the shuffles built on
+/// top of it, against picking each lane by hand.
+pub fn permute_tester<
+    N: ScalarNumber,
+    I: ScalarNumber + From<i8>,
+    Indices: SimdBase<Scalar = I>,
+    SimdArg: SimdBase<Scalar = N, GatherIndices = Indices>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+) {
+    let width = SimdArg::WIDTH;
+    let check = |name: &str, value: SimdArg, result: SimdArg, source: &dyn Fn(usize) -> usize| {
+        for i in 0..width {
+            let expected = value[source(i)];
+            if !expected.almost_eq(result[i], EqPrecision::exact()) {
+                panic!(
+                    "\n{} failed for {:?}: element {}: Expected {}, got {}",
+                    name, value, i, expected, result[i]
+                );
+            }
+        }
+    };
+
+    for (k, (value,)) in inputs.enumerate() {
+        let raw: Vec<i8> = (0..width)
+            .map(|i| ((k * width + i) * 7919 % 256) as u8 as i8)
+            .collect();
+        let mut indices = Indices::zeroes();
+        for i in 0..width {
+            indices[i] = raw[i].into();
+        }
+        let lane = |i: usize| raw[i] as usize & (width - 1);
+        check("permute", value, value.permute(indices), &lane);
+        check(
+            "shuffle::<Scrambled>",
+            value,
+            value.shuffle::<Scrambled>(),
+            &|i| i * 7919 % 256 % width,
+        );
+
+        check("reverse", value, value.reverse(), &|i| width - 1 - i);
+        check(
+            "rotate_lanes_left::<1>",
+            value,
+            value.rotate_lanes_left::<1>(),
+            &|i| (i + 1) % width,
+        );
+        check(
+            "rotate_lanes_left::<3>",
+            value,
+            value.rotate_lanes_left::<3>(),
+            &|i| (i + 3) % width,
+        );
+        check(
+            "broadcast_lane::<0>",
+            value,
+            value.broadcast_lane::<0>(),
+            &|_| 0,
+        );
+        if width > 1 {
+            check(
+                "broadcast_lane::<1>",
+                value,
+                value.broadcast_lane::<1>(),
+                &|_| 1,
+            );
+        }
+        check("swap_pairs", value, value.swap_pairs(), &|i| {
+            (i ^ 1) % width
+        });
+        check(
+            "shuffle::<StrideThree>",
+            value,
+            value.shuffle::<StrideThree>(),
+            &|i| (i * 3 + 5) % width,
+        );
+    }
+}
+
+/// Checks `zip_lo`, `zip_hi` and `unzip` against interleaving the lanes by hand, and
+/// `load_deinterleaved` and `store_interleaved` for every supported channel count.
+pub fn interleave_tester<N: ScalarNumber + Default, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+) {
+    let width = SimdArg::WIDTH;
+    let check = |name: &str, expected: &[N], result: &[N]| {
+        for i in 0..expected.len() {
+            if !expected[i].almost_eq(result[i], EqPrecision::exact()) {
+                panic!(
+                    "\n{} failed for element {}: Expected {:?}, got {:?}",
+                    name,
+                    i,
+                    expected.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+                    result.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+                );
+            }
+        }
+    };
+    let lanes = |v: SimdArg| v.iter().collect::<Vec<_>>();
+
+    let inputs: Vec<_> = inputs.collect();
+    for &(a, b) in &inputs {
+        let zipped: Vec<N> = (0..width).flat_map(|i| [a[i], b[i]]).collect();
+        check("zip_lo", &zipped[..width], &lanes(a.zip_lo(b)));
+        check("zip_hi", &zipped[width..], &lanes(a.zip_hi(b)));
+
+        let joined: Vec<N> = a.iter().chain(b.iter()).collect();
+        let (even, odd) = a.unzip(b);
+        let evens: Vec<N> = joined.iter().copied().step_by(2).collect();
+        let odds: Vec<N> = joined.iter().copied().skip(1).step_by(2).collect();
+        check("unzip even", &evens, &lanes(even));
+        check("unzip odd", &odds, &lanes(odd));
+    }
+
+    let data: Vec<N> = inputs
+        .iter()
+        .flat_map(|(a, b)| lanes(*a).into_iter().chain(lanes(*b)))
+        .collect();
+    for values in data.chunks_exact(4 * width) {
+        let deinterleaved = |n: usize, channels: Vec<SimdArg>| {
+            for (c, channel) in channels.iter().enumerate() {
+                let expected: Vec<N> = (0..width).map(|i| values[i * n + c]).collect();
+                check(
+                    &format!("load_deinterleaved::<{n}> channel {c}"),
+                    &expected,
+                    &lanes(*channel),
+                );
+            }
+            let mut stored = vec![N::default(); n * width + 1];
+            match n {
+                2 => SimdArg::store_interleaved::<2>([channels[0], channels[1]], &mut stored),
+                3 => SimdArg::store_interleaved::<3>(
+                    [channels[0], channels[1], channels[2]],
+                    &mut stored,
+                ),
+                _ => SimdArg::store_interleaved::<4>(
+                    [channels[0], channels[1], channels[2], channels[3]],
+                    &mut stored,
+                ),
+            }
+            let mut expected = values[..n * width].to_vec();
+            expected.push(N::default());
+            check(&format!("store_interleaved::<{n}>"), &expected, &stored);
+        };
+        deinterleaved(2, SimdArg::load_deinterleaved::<2>(values).to_vec());
+        deinterleaved(3, SimdArg::load_deinterleaved::<3>(values).to_vec());
+        deinterleaved(4, SimdArg::load_deinterleaved::<4>(values).to_vec());
+    }
+}
+
+/// Transposes `WIDTH` rows at a time and checks every lane against the source rows. Takes the
+/// transpose function as an argument since it lives on both `SimdInt32` and `SimdFloat32`.
+pub fn transpose_tester<N: ScalarNumber, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    transpose: fn(&mut [SimdArg]),
+) {
+    let width = SimdArg::WIDTH;
+    let rows: Vec<SimdArg> = inputs.map(|(row,)| row).collect();
+    for block in rows.chunks_exact(width) {
+        let mut transposed = block.to_vec();
+        transpose(&mut transposed);
+        for i in 0..width {
+            for j in 0..width {
+                if !block[i][j].almost_eq(transposed[j][i], EqPrecision::exact()) {
+                    panic!(
+                        "\ntranspose failed for {:?}: row {}, lane {}: Expected {}, got {}",
+                        block, j, i, block[i][j], transposed[j][i]
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn horizontal_add_tester<
+    RN: ScalarNumber + Add<Output = RN> + Default,
+    N: ScalarNumber + Add<Output = N> + Default + Into<RN>,
+    SimdArg: SimdBase<Scalar = N>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: impl Func<(SimdArg,), Output = RN>,
+) {
+    check_function(inputs, simd_fn, |result, args| {
+        let mut sum: RN = Default::default();
+        for scalar in args.0.iter() {
+            sum = sum.unchecked_add(scalar.into());
+        }
+
+        let equal = sum.almost_eq(result, EqPrecision::almost(5));
+        if !equal {
+            return Err(format!("Failed: Expected sum to be {sum}, got {result}",));
+        }
+        Ok(())
+    });
+}
+
+/// Folds neighbouring pairs of lanes with the element-wise version of the reduction, which has its
+/// own tests, until one value is left. This is the order the horizontal reductions use.
+fn fold_lanes<SimdArg: SimdBase>(
+    value: SimdArg,
+    elementwise_fn: fn(SimdArg, SimdArg) -> SimdArg,
+) -> SimdArg::Scalar {
+    let mut lanes: Vec<_> = value.iter().collect();
+    while lanes.len() > 1 {
+        lanes = lanes
+            .chunks(2)
+            .map(|pair| elementwise_fn(SimdArg::set1(pair[0]), SimdArg::set1(pair[1]))[0])
+            .collect();
+    }
+    lanes[0]
+}
+
+pub fn horizontal_reduce_tester<N: ScalarNumber, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: impl Func<(SimdArg,), Output = N>,
+    elementwise_fn: fn(SimdArg, SimdArg) -> SimdArg,
+    precision: EqPrecision,
+) {
+    check_function(inputs, simd_fn, |result, args| {
+        let expected = fold_lanes(args.0, elementwise_fn);
+        if !expected.almost_eq(result, precision) {
+            return Err(format!("Expected {expected}, got {result}"));
+        }
+        Ok(())
+    });
+}
+
+pub fn arg_reduce_tester<N: ScalarNumber, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: impl Func<(SimdArg,), Output = usize>,
+    elementwise_fn: fn(SimdArg, SimdArg) -> SimdArg,
+) {
+    check_function(inputs, simd_fn, |result, args| {
+        let expected = fold_lanes(args.0, elementwise_fn);
+        let index = args.0.iter().position(|scalar| scalar == expected).unwrap();
+        if index != result {
+            return Err(format!(
+                "Expected lane {index} holding {expected}, got lane {result}"
+            ));
+        }
+        Ok(())
+    });
+}
+
+/// Checks `prefix_sum` and `exclusive_prefix_sum` against summing the lanes one by one, and
+/// `prefix_sum_in_place` on a slice that ends with a partial vector. `sum_eq` compares a result
+/// with the sequential sum of `values`.
+pub fn prefix_sum_tester<N: ScalarNumber + Default, SimdArg: SimdBase<Scalar = N>>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    sum_eq: fn(N, N, &[N]) -> bool,
+) {
+    let width = SimdArg::WIDTH;
+    let check = |name: &str, values: &[N], results: &[N], skip: usize| {
+        let mut sum = N::default();
+        for i in 0..results.len() {
+            if i >= skip {
+                let summed = &values[..i + 1 - skip];
+                if !sum_eq(results[i], sum.unchecked_add(values[i - skip]), summed) {
+                    panic!(
+                        "\n{} failed for element {}: Expected sum of {:?}, got {}",
+                        name,
+                        i,
+                        summed.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+                        results[i]
+                    );
+                }
+                sum = sum.unchecked_add(values[i - skip]);
+            } else if !sum_eq(results[i], N::default(), &[]) {
+                panic!(
+                    "\n{} failed for element {}: Expected 0, got {}",
+                    name, i, results[i]
+                );
+            }
+        }
+    };
+
+    let inputs: Vec<SimdArg> = inputs.map(|(value,)| value).collect();
+    for value in &inputs {
+        let values: Vec<N> = value.iter().collect();
+        check(
+            "prefix_sum",
+            &values,
+            &value.prefix_sum().iter().collect::<Vec<_>>(),
+            0,
+        );
+        check(
+            "exclusive_prefix_sum",
+            &values,
+            &value.exclusive_prefix_sum().iter().collect::<Vec<_>>(),
+            1,
+        );
+    }
+
+    let values: Vec<N> = inputs.iter().flat_map(|value| value.iter()).collect();
+    for chunk in values.chunks(3 * width + 1) {
+        let mut results = chunk.to_vec();
+        SimdArg::prefix_sum_in_place(&mut results);
+        check("prefix_sum_in_place", chunk, &results, 0);
+    }
+}
+
+pub fn exact_sum_eq<N: ScalarNumber>(result: N, expected: N, _values: &[N]) -> bool {
+    result == expected
+}
+
+/// Allows for the rounding errors of summing `values` in a different order. Sums that overflow
+/// are skipped, since whether they do depends on the order.
+pub fn float_sum_eq<N: ScalarNumber + Into<f64>>(result: N, expected: N, values: &[N]) -> bool {
+    let (result, expected): (f64, f64) = (result.into(), expected.into());
+    if !result.is_finite() || !expected.is_finite() {
+        return true;
+    }
+    let bound: f64 = values.iter().map(|&value| value.into().abs()).sum();
+    (result - expected).abs() <= bound * 1e-5
+}
+
+/// Checks a widening multiply against multiplying the lanes after converting them with `into`.
+pub fn mul_widen_tester<
+    N: ScalarNumber + Into<W>,
+    W: ScalarNumber + Mul<Output = W>,
+    SimdArg: SimdBase<Scalar = N>,
+    Wide: SimdBase<Scalar = W>,
+>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+    simd_fn: fn(SimdArg, SimdArg) -> (Wide, Wide),
+) {
+    check_function(inputs, simd_fn, |(lo, hi), (a, b)| {
+        let results: Vec<W> = lo.iter().chain(hi.iter()).collect();
+        for i in 0..SimdArg::WIDTH {
+            let expected = a[i].into() * b[i].into();
+            if expected != results[i] {
+                return Err(format!(
+                    "Failed for element {i}: Expected {expected}, got {}",
+                    results[i]
+                ));
+            }
+        }
+        Ok(())
+    });
+}
+
+/// Checks an element-wise conversion against converting each lane with `scalar_fn`.
+pub fn convert_tester<
+    N: ScalarNumber,
+    W: ScalarNumber,
+    SimdArg: SimdBase<Scalar = N>,
+    SimdResult: SimdBase<Scalar = W>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: fn(SimdArg) -> SimdResult,
+    scalar_fn: fn(N) -> W,
+) {
+    check_elementwise_function(inputs, simd_fn, |result, (a,)| {
+        let expected = scalar_fn(a);
+        if expected == result {
+            Ok(())
+        } else {
+            Err(format!("Expected {expected}, got {result}"))
+        }
+    });
+}
+
+/// Checks a widening function, which splits its argument into two halves, against converting each
+/// lane with `scalar_fn`.
+pub fn widen_tester<
+    N: ScalarNumber,
+    W: ScalarNumber,
+    SimdArg: SimdBase<Scalar = N>,
+    Wide: SimdBase<Scalar = W>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: fn(SimdArg) -> (Wide, Wide),
+    scalar_fn: fn(N) -> W,
+) {
+    check_function(inputs, simd_fn, |(lo, hi), (a,)| {
+        let results: Vec<W> = lo.iter().chain(hi.iter()).collect();
+        for i in 0..SimdArg::WIDTH {
+            let expected = scalar_fn(a[i]);
+            if expected != results[i] {
+                return Err(format!(
+                    "Failed for element {i}: Expected {expected}, got {}",
+                    results[i]
+                ));
+            }
+        }
+        Ok(())
+    });
+}
+
+/// Checks a narrowing function, which puts the lanes of the first argument before the lanes of the
+/// second one, against converting each lane with `scalar_fn`.
+pub fn narrow_tester<
+    W: ScalarNumber,
+    N: ScalarNumber,
+    SimdArg: SimdBase<Scalar = W>,
+    Narrow: SimdBase<Scalar = N>,
+>(
+    inputs: impl Iterator<Item = (SimdArg, SimdArg)>,
+    simd_fn: fn(SimdArg, SimdArg) -> Narrow,
+    scalar_fn: fn(W) -> N,
+) {
+    check_function(inputs, simd_fn, |result, (a, b)| {
+        for (i, lane) in a.iter().chain(b.iter()).take(Narrow::WIDTH).enumerate() {
+            let expected = scalar_fn(lane);
+            if expected != result[i] {
+                return Err(format!(
+                    "Failed for element {i}: Expected {expected}, got {}",
+                    result[i]
+                ));
+            }
+        }
+        Ok(())
+    });
+}
+
+/// Checks that narrowing the halves produced by `extend_fn` gives back the original vector.
+pub fn narrow_round_trip_tester<N: ScalarNumber, SimdArg: SimdBase<Scalar = N>, Wide: SimdBase>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    extend_fn: fn(SimdArg) -> (Wide, Wide),
+    narrow_fn: fn(Wide, Wide) -> SimdArg,
+) {
+    check_function(inputs, extend_fn, |(lo, hi), (a,)| {
+        let result = narrow_fn(lo, hi);
+        for i in 0..SimdArg::WIDTH {
+            if a[i] != result[i] {
+                return Err(format!(
+                    "Failed for element {i}: Expected {}, got {}",
+                    a[i], result[i]
+                ));
+            }
+        }
+        Ok(())
+    });
+}
+
+pub fn madd_tester<SimdArg: SimdInt16>(inputs: impl Iterator<Item = (SimdArg, SimdArg)>) {
+    type Vi32<S> = <<S as SimdConsts>::Engine as Simd>::Vi32;
+    check_function(inputs, SimdArg::madd, |result: Vi32<SimdArg>, (a, b)| {
+        for i in 0..Vi32::<SimdArg>::WIDTH {
+            let expected = (2 * i..(2 * i + 2).min(SimdArg::WIDTH))
+                .map(|j| a[j] as i32 * b[j] as i32)
+                .fold(0, i32::wrapping_add);
+            if expected != result[i] {
+                return Err(format!(
+                    "Failed for element {i}: Expected {expected}, got {}",
+                    result[i]
+                ));
+            }
+        }
+        Ok(())
+    });
+}
+
+pub fn unsigned_horizontal_add_tester<
+    N: IntScalarNumber + Add<Output = N> + Default,
+    SimdArg: SimdBase<Scalar = N>,
+>(
+    inputs: impl Iterator<Item = (SimdArg,)>,
+    simd_fn: impl Func<(SimdArg,), Output = i64>,
+) {
+    check_function(inputs, simd_fn, |result, args| {
+        let mut sum: i64 = 0;
+        for scalar in args.0.iter() {
+            sum = sum.wrapping_add(scalar.unsigned_cast_to_i64());
+        }
+
+        let equal = sum.almost_eq(result, EqPrecision::almost(5));
+        if !equal {
+            return Err(format!("Failed: Expected sum to be {sum}, got {result}",));
+        }
+        Ok(())
+    });
+}
+
+#[macro_export]
+macro_rules! elementwise_eq_tester {
+    (< $simd_kind:ident :: $simd_ty:ident as $base_kind:ident >  :: $fn_name:ident, $inputs:expr, $precision:expr) => {{
+        let f = <<$simd_kind as Simd>::$simd_ty as $base_kind>::$fn_name;
+        let sf = <<Scalar as Simd>::$simd_ty as $base_kind>::$fn_name;
+        elementwise_eq_tester($inputs, $precision, f, sf);
+    }};
+}
+
+#[macro_export]
+macro_rules! bitshift_eq_tester {
+    ($simd_kind:ident :: $simd_ty:ident :: $fn_name:ident, $inputs:expr) => {{
+        let f = <<$simd_kind as Simd>::$simd_ty as SimdInt>::$fn_name;
+        let sf = <<Scalar as Simd>::$simd_ty as SimdInt>::$fn_name;
+        bitshift_eq_tester($inputs, f, sf);
+    }};
+}
+
+#[macro_export]
+macro_rules! const_bitshift_eq_tester {
+    ($scalar_ty:ident, $simd_kind:ident :: $simd_ty:ident :: $fn_name:ident, $inputs:expr) => {{
+        let f = |val, s| {
+            macro_rules! expand {
+                ($imm8:literal) => {
+                    <<$simd_kind as Simd>::$simd_ty as SimdInt>::$fn_name::<$imm8>(val)
+                };
+            }
+            test_constify_imm8_for_bitshift!($scalar_ty, s, expand)
+        };
+        let sf = |val, s| {
+            macro_rules! expand {
+                ($imm8:literal) => {
+                    <<Scalar as Simd>::$simd_ty as SimdInt>::$fn_name::<$imm8>(val)
+                };
+            }
+            test_constify_imm8_for_bitshift!($scalar_ty, s, expand)
+        };
+        bitshift_eq_tester($inputs, f, sf);
+    }};
+}
+
+#[macro_export]
+macro_rules! horizontal_add_tester {
+    (signed $simd_kind:ident :: $simd_ty:ident, $inputs:expr) => {{
+        let f = <<$simd_kind as Simd>::$simd_ty as SimdBaseOps>::horizontal_add;
+        horizontal_add_tester($inputs, f);
+    }};
+
+    (unsigned $simd_kind:ident :: $simd_ty:ident, $inputs:expr) => {{
+        let f = <<$simd_kind as Simd>::$simd_ty as SimdInt>::horizontal_unsigned_add;
+        unsigned_horizontal_add_tester($inputs, f);
+    }};
+}
+
+/// Invokes `$tester!(@full <engine>, ...)` once for every engine, passing the remaining arguments
+/// through unchanged.
+#[macro_export]
+macro_rules! for_each_engine {
+    ($tester:ident $($args:tt)*) => {
+        $tester!(@full Scalar $($args)*);
+        $tester!(@full Avx512 $($args)*);
+        $tester!(@full Avx2Fma $($args)*);
+        $tester!(@full Avx2 $($args)*);
+        $tester!(@full Avx $($args)*);
+        $tester!(@full Sse2 $($args)*);
+        $tester!(@full Sse41 $($args)*);
+        $tester!(@full Sse42 $($args)*);
+        $tester!(@full Ssse3 $($args)*);
+        $tester!(@full Neon $($args)*);
+        $tester!(@full Wasm $($args)*);
+    };
+}
+
+#[macro_export]
+macro_rules! with_feature_flag {
+    (Avx512, $($r:tt)+) => {
+        #[cfg(all(
+            target_feature = "avx512f",
+            target_feature = "avx512bw",
+            target_feature = "avx512dq"
+        ))]
+        $($r)+
+    };
+    (Avx2Fma, $($r:tt)+) => {
+        #[cfg(all(target_feature = "avx2", target_feature = "fma"))]
+        $($r)+
+    };
+    (Avx2, $($r:tt)+) => {
+        #[cfg(target_feature = "avx2")]
+        $($r)+
+    };
+    (Avx, $($r:tt)+) => {
+        #[cfg(target_feature = "avx")]
+        $($r)+
+    };
+    (Sse2, $($r:tt)+) => {
+        #[cfg(target_feature = "sse2")]
+        $($r)+
+    };
+    (Sse41, $($r:tt)+) => {
+        #[cfg(target_feature = "sse4.1")]
+        $($r)+
+    };
+    (Sse42, $($r:tt)+) => {
+        #[cfg(target_feature = "sse4.2")]
+        $($r)+
+    };
+    (Ssse3, $($r:tt)+) => {
+        #[cfg(target_feature = "ssse3")]
+        $($r)+
+    };
+    (Neon, $($r:tt)+) => {
+        #[cfg(target_feature = "neon")]
+        $($r)+
+    };
+    (Wasm, $($r:tt)+) => {
+        #[cfg(target_feature = "simd128")]
+        $($r)+
+    };
+    (Scalar, $($r:tt)+) => {
+        $($r)+
+    };
+}
+
+#[macro_export]
+macro_rules! elementwise_eq_tester_impl {
+    (@full $simd:ident, $simd_ty:ident, $simd_base:ident, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
+                    elementwise_eq_tester!(
+                        <$simd:: [<V$simd_ty>] as $simd_base>::$simd_fn,
+                        RandSimd::$simd_ty().$arg_cnt(),
+                        $precision
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident, $simd_base:ident, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        for_each_engine!(elementwise_eq_tester_impl, $simd_ty, $simd_base, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdBaseOps, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i8, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i16, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u8, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u16, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind f32, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind f64, SimdBaseOps, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdInt, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i8, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i16, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i32, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind i64, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u8, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u16, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u32, SimdInt, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind u64, SimdInt, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdFloat, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind f32, SimdFloat, $simd_fn, $arg_cnt, $precision);
+        elementwise_eq_tester_impl!(@simdkind f64, SimdFloat, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdFloat32, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind f32, SimdFloat32, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdInt8, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i8, SimdInt8, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdInt16, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i16, SimdInt16, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdInt32, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i32, SimdInt32, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdFloat64, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind f64, SimdFloat64, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdInt64, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind i64, SimdInt64, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdUint8, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind u8, SimdUint8, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdUint16, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind u16, SimdUint16, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdUint32, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind u32, SimdUint32, $simd_fn, $arg_cnt, $precision);
+    };
+
+    (SimdUint64, $simd_fn:ident, $arg_cnt:ident, $precision:expr) => {
+        elementwise_eq_tester_impl!(@simdkind u64, SimdUint64, $simd_fn, $arg_cnt, $precision);
+    };
+}
+
+#[macro_export]
+macro_rules! closure_eq_tester_impl {
+    (@full $simd:ident, $simd_ty:ident, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, std, $arg_cnt:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$test_name _ $simd:lower _ $simd_ty>]() {
+                    let f = {
+                        type V = <$simd as Simd>::[<V $simd_ty>];
+                        |$($arg: V),*| $body
+                    };
+                    let sf = |$($arg: $simd_ty),*| $body;
+                    $tester(RandSimd::$simd_ty().$arg_cnt(), f, sf);
+                }
+            }
+        );
+    };
+
+    (@full $simd:ident, $simd_ty:ident, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, $arg_cnt:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$test_name _ $simd:lower _ $simd_ty>]() {
+                    let f = {
+                        type V = <$simd as Simd>::[<V $simd_ty>];
+                        |$($arg: V),*| $body
+                    };
+                    let sf = {
+                        type V = <Scalar as Simd>::[<V $simd_ty>];
+                        |$($arg: V),*| $body
+                    };
+                    $tester(RandSimd::$simd_ty().$arg_cnt(), f, sf);
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, std, $arg_cnt:ident) => {
+        for_each_engine!(closure_eq_tester_impl, $simd_ty, $tester, $test_name, |$($arg),*| $body, std, $arg_cnt);
+    };
+
+    (@simdkind $simd_ty:ident, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, $arg_cnt:ident) => {
+        for_each_engine!(closure_eq_tester_impl, $simd_ty, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+    };
+
+    (SimdBaseOps, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, $arg_cnt:ident) => {
+        closure_eq_tester_impl!(@simdkind i8, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind i16, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind i32, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind i64, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind u8, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind u16, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind u32, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind u64, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind f32, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind f64, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+    };
+
+    (SimdFloat, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, std, $arg_cnt:ident) => {
+        closure_eq_tester_impl!(@simdkind f32, $tester, $test_name, |$($arg),*| $body, std, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind f64, $tester, $test_name, |$($arg),*| $body, std, $arg_cnt);
+    };
+
+    (SimdFloat, $tester:ident, $test_name:ident, |$($arg:ident),*| $body:expr, $arg_cnt:ident) => {
+        closure_eq_tester_impl!(@simdkind f32, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+        closure_eq_tester_impl!(@simdkind f64, $tester, $test_name, |$($arg),*| $body, $arg_cnt);
+    };
+}
+
+#[macro_export]
+macro_rules! bitshift_eq_tester_impl {
+    (@full dyn, $simd:ident, $simd_ty:ident, $simd_fn:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
+                    bitshift_eq_tester!(
+                        $simd:: [<V$simd_ty>]::$simd_fn,
+                        RandSimd::$simd_ty().one_arg_and_bitshift_arg()
+                    );
+                }
+            }
+        );
+    };
+
+    (@full const, $simd:ident, $simd_ty:ident, $simd_fn:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
+                    const_bitshift_eq_tester!(
+                        $simd_ty,
+                        $simd:: [<V$simd_ty>]::$simd_fn,
+                        RandSimd::$simd_ty().one_arg_and_bitshift_arg()
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $is_const:ident, $simd_ty:ident, $simd_fn:ident) => {
+        bitshift_eq_tester_impl!(@full $is_const, Scalar, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Avx512, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Avx2Fma, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Avx2, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Avx, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Sse2, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Sse41, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Sse42, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Ssse3, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Neon, $simd_ty, $simd_fn);
+        bitshift_eq_tester_impl!(@full $is_const, Wasm, $simd_ty, $simd_fn);
+    };
+
+    ($is_const:ident $simd_fn:ident) => {
+        bitshift_eq_tester_impl!(@simdkind $is_const, i8, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, i16, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, i32, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, i64, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u8, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u16, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u32, $simd_fn);
+        bitshift_eq_tester_impl!(@simdkind $is_const, u64, $simd_fn);
+    };
+}
+
+#[macro_export]
+macro_rules! horizontal_add_tester_impl {
+    (@full $kind:ident, $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$kind _horizontal_add_ $simd:lower _ $simd_ty>]() {
+                    horizontal_add_tester!(
+                        $kind
+                        $simd:: [<V$simd_ty>],
+                        RandSimd::$simd_ty().one_arg()
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $kind:ident, $simd_ty:ident) => {
+        horizontal_add_tester_impl!(@full $kind, Scalar, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Avx512, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Avx2Fma, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Avx2, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Avx, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Sse2, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Sse41, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Sse42, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Ssse3, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Neon, $simd_ty);
+        horizontal_add_tester_impl!(@full $kind, Wasm, $simd_ty);
+    };
+
+    (signed) => {
+        horizontal_add_tester_impl!(@simdkind signed, i8);
+        horizontal_add_tester_impl!(@simdkind signed, i16);
+        horizontal_add_tester_impl!(@simdkind signed, i32);
+        horizontal_add_tester_impl!(@simdkind signed, i64);
+        horizontal_add_tester_impl!(@simdkind signed, u8);
+        horizontal_add_tester_impl!(@simdkind signed, u16);
+        horizontal_add_tester_impl!(@simdkind signed, u32);
+        horizontal_add_tester_impl!(@simdkind signed, u64);
+        horizontal_add_tester_impl!(@simdkind signed, f32);
+        horizontal_add_tester_impl!(@simdkind signed, f64);
+    };
+
+    (unsigned) => {
+        horizontal_add_tester_impl!(@simdkind unsigned, i8);
+        horizontal_add_tester_impl!(@simdkind unsigned, i16);
+        horizontal_add_tester_impl!(@simdkind unsigned, i32);
+        horizontal_add_tester_impl!(@simdkind unsigned, i64);
+    };
+}
+
+#[macro_export]
+macro_rules! truthy_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<truthy_ $simd:lower _ $simd_ty>]() {
+                    truthy_tester::<_, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().one_arg()
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(truthy_tester_impl, $simd_ty);
+    };
+
+    () => {
+        truthy_tester_impl!(@simdkind i8);
+        truthy_tester_impl!(@simdkind i16);
+        truthy_tester_impl!(@simdkind i32);
+        truthy_tester_impl!(@simdkind i64);
+        truthy_tester_impl!(@simdkind u8);
+        truthy_tester_impl!(@simdkind u16);
+        truthy_tester_impl!(@simdkind u32);
+        truthy_tester_impl!(@simdkind u64);
+        truthy_tester_impl!(@simdkind f32);
+        truthy_tester_impl!(@simdkind f64);
+    };
+}
+
+#[macro_export]
+macro_rules! gather_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<gather_ $simd:lower _ $simd_ty>]() {
+                    gather_tester::<_, _, _, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().one_arg()
+                    );
+                }
+
+                #[test]
+                #[should_panic(expected = "out of bounds")]
+                fn [<gather_out_of_bounds_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let data = [Default::default(); 4];
+                    let mut indices = <V as SimdBaseIo>::GatherIndices::zeroes();
+                    indices[V::WIDTH - 1] = 4;
+                    V::gather(&data, indices);
+                }
+
+                #[test]
+                #[should_panic(expected = "out of bounds")]
+                fn [<gather_negative_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let data = [Default::default(); 4];
+                    let mut indices = <V as SimdBaseIo>::GatherIndices::zeroes();
+                    indices[0] = -1;
+                    V::gather(&data, indices);
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(gather_tester_impl, $simd_ty);
+    };
+
+    () => {
+        gather_tester_impl!(@simdkind i8);
+        gather_tester_impl!(@simdkind i16);
+        gather_tester_impl!(@simdkind i32);
+        gather_tester_impl!(@simdkind i64);
+        gather_tester_impl!(@simdkind u8);
+        gather_tester_impl!(@simdkind u16);
+        gather_tester_impl!(@simdkind u32);
+        gather_tester_impl!(@simdkind u64);
+        gather_tester_impl!(@simdkind f32);
+        gather_tester_impl!(@simdkind f64);
+    };
+}
+
+#[macro_export]
+macro_rules! scatter_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<scatter_ $simd:lower _ $simd_ty>]() {
+                    scatter_tester::<_, _, _, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().two_arg()
+                    );
+                }
+
+                #[test]
+                #[should_panic(expected = "out of bounds")]
+                fn [<scatter_out_of_bounds_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let mut data = [Default::default(); 4];
+                    let mut indices = <V as SimdBaseIo>::GatherIndices::zeroes();
+                    indices[V::WIDTH - 1] = 4;
+                    V::zeroes().scatter(&mut data, indices);
+                }
+
+                #[test]
+                #[should_panic(expected = "out of bounds")]
+                fn [<scatter_masked_out_of_bounds_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let mut data = [Default::default(); 4];
+                    let mut indices = <V as SimdBaseIo>::GatherIndices::zeroes();
+                    indices[0] = -1;
+                    let mask = V::zeroes().cmp_eq(V::zeroes());
+                    V::zeroes().scatter_masked(&mut data, indices, mask);
+                }
+
+                #[test]
+                fn [<scatter_masked_ignores_inactive_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let mut data = [Default::default(); 4];
+                    let mut indices = <V as SimdBaseIo>::GatherIndices::zeroes();
+                    indices[0] = -1;
+                    indices[V::WIDTH - 1] = 4;
+                    let mask = V::zeroes().cmp_neq(V::zeroes());
+                    V::zeroes().scatter_masked(&mut data, indices, mask);
                 }
             }
         );
     };
 
-    (@full const, $simd:ident, $simd_ty:ident, $simd_fn:ident) => {
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(scatter_tester_impl, $simd_ty);
+    };
+
+    () => {
+        scatter_tester_impl!(@simdkind i8);
+        scatter_tester_impl!(@simdkind i16);
+        scatter_tester_impl!(@simdkind i32);
+        scatter_tester_impl!(@simdkind i64);
+        scatter_tester_impl!(@simdkind u8);
+        scatter_tester_impl!(@simdkind u16);
+        scatter_tester_impl!(@simdkind u32);
+        scatter_tester_impl!(@simdkind u64);
+        scatter_tester_impl!(@simdkind f32);
+        scatter_tester_impl!(@simdkind f64);
+    };
+}
+
+#[macro_export]
+macro_rules! masked_io_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
         with_feature_flag!($simd,
             paste::item! {
                 #[test]
-                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
-                    const_bitshift_eq_tester!(
-                        $simd_ty,
-                        $simd:: [<V$simd_ty>]::$simd_fn,
-                        RandSimd::$simd_ty().one_arg_and_bitshift_arg()
+                fn [<masked_io_ $simd:lower _ $simd_ty>]() {
+                    masked_io_tester::<_, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().two_arg()
                     );
                 }
             }
         );
     };
 
-    (@simdkind $is_const:ident, $simd_ty:ident, $simd_fn:ident) => {
-        bitshift_eq_tester_impl!(@full $is_const, Scalar, $simd_ty, $simd_fn);
-        bitshift_eq_tester_impl!(@full $is_const, Avx2, $simd_ty, $simd_fn);
-        bitshift_eq_tester_impl!(@full $is_const, Sse2, $simd_ty, $simd_fn);
-        bitshift_eq_tester_impl!(@full $is_const, Sse41, $simd_ty, $simd_fn);
-        bitshift_eq_tester_impl!(@full $is_const, Neon, $simd_ty, $simd_fn);
-        bitshift_eq_tester_impl!(@full $is_const, Wasm, $simd_ty, $simd_fn);
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(masked_io_tester_impl, $simd_ty);
     };
 
-    ($is_const:ident $simd_fn:ident) => {
-        bitshift_eq_tester_impl!(@simdkind $is_const, i8, $simd_fn);
-        bitshift_eq_tester_impl!(@simdkind $is_const, i16, $simd_fn);
-        bitshift_eq_tester_impl!(@simdkind $is_const, i32, $simd_fn);
-        bitshift_eq_tester_impl!(@simdkind $is_const, i64, $simd_fn);
+    () => {
+        masked_io_tester_impl!(@simdkind i8);
+        masked_io_tester_impl!(@simdkind i16);
+        masked_io_tester_impl!(@simdkind i32);
+        masked_io_tester_impl!(@simdkind i64);
+        masked_io_tester_impl!(@simdkind u8);
+        masked_io_tester_impl!(@simdkind u16);
+        masked_io_tester_impl!(@simdkind u32);
+        masked_io_tester_impl!(@simdkind u64);
+        masked_io_tester_impl!(@simdkind f32);
+        masked_io_tester_impl!(@simdkind f64);
     };
 }
 
 #[macro_export]
-macro_rules! horizontal_add_tester_impl {
-    (@full $kind:ident, $simd:ident, $simd_ty:ident) => {
+macro_rules! permute_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
         with_feature_flag!($simd,
             paste::item! {
                 #[test]
-                fn [<$kind _horizontal_add_ $simd:lower _ $simd_ty>]() {
-                    horizontal_add_tester!(
-                        $kind
-                        $simd:: [<V$simd_ty>],
+                fn [<permute_ $simd:lower _ $simd_ty>]() {
+                    permute_tester::<_, _, _, <$simd as Simd>::[<V $simd_ty>]>(
                         RandSimd::$simd_ty().one_arg()
                     );
                 }
@@ -344,28 +1567,458 @@ macro_rules! horizontal_add_tester_impl {
         );
     };
 
-    (@simdkind $kind:ident, $simd_ty:ident) => {
-        horizontal_add_tester_impl!(@full $kind, Scalar, $simd_ty);
-        horizontal_add_tester_impl!(@full $kind, Avx2, $simd_ty);
-        horizontal_add_tester_impl!(@full $kind, Sse2, $simd_ty);
-        horizontal_add_tester_impl!(@full $kind, Sse41, $simd_ty);
-        horizontal_add_tester_impl!(@full $kind, Neon, $simd_ty);
-        horizontal_add_tester_impl!(@full $kind, Wasm, $simd_ty);
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(permute_tester_impl, $simd_ty);
     };
 
-    (signed) => {
-        horizontal_add_tester_impl!(@simdkind signed, i8);
-        horizontal_add_tester_impl!(@simdkind signed, i16);
-        horizontal_add_tester_impl!(@simdkind signed, i32);
-        horizontal_add_tester_impl!(@simdkind signed, i64);
-        horizontal_add_tester_impl!(@simdkind signed, f32);
-        horizontal_add_tester_impl!(@simdkind signed, f64);
+    () => {
+        permute_tester_impl!(@simdkind i8);
+        permute_tester_impl!(@simdkind i16);
+        permute_tester_impl!(@simdkind i32);
+        permute_tester_impl!(@simdkind i64);
+        permute_tester_impl!(@simdkind u8);
+        permute_tester_impl!(@simdkind u16);
+        permute_tester_impl!(@simdkind u32);
+        permute_tester_impl!(@simdkind u64);
+        permute_tester_impl!(@simdkind f32);
+        permute_tester_impl!(@simdkind f64);
     };
+}
 
-    (unsigned) => {
-        horizontal_add_tester_impl!(@simdkind unsigned, i8);
-        horizontal_add_tester_impl!(@simdkind unsigned, i16);
-        horizontal_add_tester_impl!(@simdkind unsigned, i32);
-        horizontal_add_tester_impl!(@simdkind unsigned, i64);
+#[macro_export]
+macro_rules! broadcast_lane_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                #[should_panic(expected = "out of bounds")]
+                fn [<broadcast_lane_out_of_bounds_ $simd:lower _ $simd_ty>]() {
+                    <$simd as Simd>::[<V $simd_ty>]::zeroes().broadcast_lane::<64>();
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident) => {
+        broadcast_lane_tester_impl!(@full Scalar, $simd_ty);
+        broadcast_lane_tester_impl!(@full Avx512, $simd_ty);
+        broadcast_lane_tester_impl!(@full Avx2, $simd_ty);
+        broadcast_lane_tester_impl!(@full Sse2, $simd_ty);
+        broadcast_lane_tester_impl!(@full Neon, $simd_ty);
+        broadcast_lane_tester_impl!(@full Wasm, $simd_ty);
+    };
+
+    () => {
+        broadcast_lane_tester_impl!(@simdkind i8);
+        broadcast_lane_tester_impl!(@simdkind f64);
+    };
+}
+
+#[macro_export]
+macro_rules! interleave_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<interleave_ $simd:lower _ $simd_ty>]() {
+                    interleave_tester::<_, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().two_arg()
+                    );
+                }
+
+                #[test]
+                #[should_panic(expected = "load_deinterleaved needs")]
+                fn [<load_deinterleaved_short_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let data = vec![Default::default(); 3 * V::WIDTH - 1];
+                    V::load_deinterleaved::<3>(&data);
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(interleave_tester_impl, $simd_ty);
+    };
+
+    () => {
+        interleave_tester_impl!(@simdkind i8);
+        interleave_tester_impl!(@simdkind i16);
+        interleave_tester_impl!(@simdkind i32);
+        interleave_tester_impl!(@simdkind i64);
+        interleave_tester_impl!(@simdkind u8);
+        interleave_tester_impl!(@simdkind u16);
+        interleave_tester_impl!(@simdkind u32);
+        interleave_tester_impl!(@simdkind u64);
+        interleave_tester_impl!(@simdkind f32);
+        interleave_tester_impl!(@simdkind f64);
+    };
+}
+
+#[macro_export]
+macro_rules! transpose_tester_impl {
+    (@full $simd:ident, $simd_ty:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<transpose_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    transpose_tester::<_, V>(RandSimd::$simd_ty().one_arg(), V::transpose);
+                }
+
+                #[test]
+                #[should_panic(expected = "transpose needs")]
+                fn [<transpose_short_ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    let mut rows = vec![V::zeroes(); V::WIDTH + 1];
+                    V::transpose(&mut rows);
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident) => {
+        for_each_engine!(transpose_tester_impl, $simd_ty);
+    };
+
+    () => {
+        transpose_tester_impl!(@simdkind i32);
+        transpose_tester_impl!(@simdkind f32);
+    };
+}
+
+#[macro_export]
+macro_rules! horizontal_reduce_tester_impl {
+    (@full $simd:ident, $simd_ty:ident, $tester:ident, $simd_fn:ident, $elementwise_fn:ident, $arg_cnt:ident $(, $precision:expr)?) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$simd_fn _ $simd:lower _ $simd_ty>]() {
+                    type V = <$simd as Simd>::[<V $simd_ty>];
+                    $tester(
+                        RandSimd::$simd_ty().$arg_cnt(),
+                        |a: V| a.$simd_fn(),
+                        <V as SimdBaseOps>::$elementwise_fn
+                        $(, $precision)?
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident, $($args:tt)*) => {
+        for_each_engine!(horizontal_reduce_tester_impl, $simd_ty, $($args)*);
+    };
+
+    ($($args:tt)*) => {
+        horizontal_reduce_tester_impl!(@simdkind i8, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind i16, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind i32, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind i64, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind u8, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind u16, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind u32, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind u64, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind f32, $($args)*);
+        horizontal_reduce_tester_impl!(@simdkind f64, $($args)*);
+    };
+}
+
+#[macro_export]
+macro_rules! prefix_sum_tester_impl {
+    (@full $simd:ident, $simd_ty:ident, $sum_eq:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<prefix_sum_ $simd:lower _ $simd_ty>]() {
+                    prefix_sum_tester::<_, <$simd as Simd>::[<V $simd_ty>]>(
+                        RandSimd::$simd_ty().one_arg(),
+                        $sum_eq
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $simd_ty:ident, $sum_eq:ident) => {
+        for_each_engine!(prefix_sum_tester_impl, $simd_ty, $sum_eq);
+    };
+
+    () => {
+        prefix_sum_tester_impl!(@simdkind i8, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind i16, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind i32, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind i64, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind u8, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind u16, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind u32, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind u64, exact_sum_eq);
+        prefix_sum_tester_impl!(@simdkind f32, float_sum_eq);
+        prefix_sum_tester_impl!(@simdkind f64, float_sum_eq);
+    };
+}
+
+#[macro_export]
+macro_rules! widening_mul_tester_impl {
+    (@full $simd:ident) => {
+        with_feature_flag!(
+            $simd,
+            paste::item! {
+                #[test]
+                fn [<madd_ $simd:lower _i16>]() {
+                    madd_tester::<<$simd as Simd>::Vi16>(RandSimd::i16().two_arg());
+                }
+
+                #[test]
+                fn [<mul_widen_ $simd:lower _i16>]() {
+                    mul_widen_tester(
+                        RandSimd::i16().two_arg(),
+                        <<$simd as Simd>::Vi16 as SimdInt16>::mul_widen
+                    );
+                }
+
+                #[test]
+                fn [<mul_widen_ $simd:lower _i32>]() {
+                    mul_widen_tester(
+                        RandSimd::i32().two_arg(),
+                        <<$simd as Simd>::Vi32 as SimdInt32>::mul_widen
+                    );
+                }
+            }
+        );
+    };
+
+    () => {
+        for_each_engine!(widening_mul_tester_impl);
+    };
+}
+
+#[macro_export]
+macro_rules! narrow_tester_impl {
+    (@width $simd:ident, $wide:ident, $narrow:ident, $unsigned:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<narrow_ $simd:lower _ $wide>]() {
+                    type Wide = <$simd as Simd>::[<V $wide>];
+                    type Narrow = <$simd as Simd>::[<V $narrow>];
+
+                    narrow_tester(
+                        RandSimd::$wide().two_arg(),
+                        Wide::narrow_saturating,
+                        |x: $wide| x.clamp($narrow::MIN.into(), $narrow::MAX.into()) as $narrow,
+                    );
+                    narrow_tester(
+                        RandSimd::$wide().two_arg(),
+                        Wide::unsigned_narrow_saturating,
+                        |x: $wide| x.clamp(0, $unsigned::MAX.into()) as $unsigned,
+                    );
+                    narrow_tester(
+                        RandSimd::$wide().two_arg(),
+                        Wide::narrow_truncating,
+                        |x: $wide| x as $narrow,
+                    );
+
+                    narrow_round_trip_tester(
+                        RandSimd::$narrow().one_arg(),
+                        Narrow::[<extend_to_ $wide>],
+                        Wide::narrow_saturating,
+                    );
+                    narrow_round_trip_tester(
+                        RandSimd::$narrow().one_arg(),
+                        Narrow::[<unsigned_extend_to_ $wide>],
+                        |lo: Wide, hi: Wide| lo.unsigned_narrow_saturating(hi).[<bitcast_ $narrow>](),
+                    );
+                }
+            }
+        );
+    };
+
+    (@full $simd:ident) => {
+        narrow_tester_impl!(@width $simd, i16, i8, u8);
+        narrow_tester_impl!(@width $simd, i32, i16, u16);
+        narrow_tester_impl!(@width $simd, i64, i32, u32);
+    };
+
+    () => {
+        for_each_engine!(narrow_tester_impl);
+    };
+}
+
+#[macro_export]
+macro_rules! float_conversion_tester_impl {
+    (@full $simd:ident) => {
+        with_feature_flag!(
+            $simd,
+            paste::item! {
+                #[test]
+                fn [<float_conversions_ $simd:lower>]() {
+                    type Vf32 = <$simd as Simd>::Vf32;
+                    type Vf64 = <$simd as Simd>::Vf64;
+                    type Vi32 = <$simd as Simd>::Vi32;
+
+                    widen_tester(
+                        RandSimd::f32().one_arg_nan_filtered(),
+                        Vf32::to_f64_pair,
+                        |x: f32| x as f64,
+                    );
+                    widen_tester(RandSimd::i32().one_arg(), Vi32::to_f64_pair, |x: i32| x as f64);
+                    narrow_tester(
+                        RandSimd::f64().two_arg_nan_filtered(),
+                        Vf64::to_f32_narrow,
+                        |x: f64| x as f32,
+                    );
+                    narrow_tester(
+                        RandSimd::f64().two_arg(),
+                        Vf64::to_i32_narrow,
+                        |x: f64| x.round_ties_even() as i32,
+                    );
+                    narrow_tester(
+                        RandSimd::f64().one_arg_cast_edge_cases().map(|(a,): (Vf64,)| (a, -a)),
+                        Vf64::to_i32_narrow,
+                        |x: f64| x.round_ties_even() as i32,
+                    );
+
+                    narrow_round_trip_tester(
+                        RandSimd::f32().one_arg_nan_filtered(),
+                        Vf32::to_f64_pair,
+                        Vf64::to_f32_narrow,
+                    );
+                    narrow_round_trip_tester(
+                        RandSimd::i32().one_arg(),
+                        Vi32::to_f64_pair,
+                        Vf64::to_i32_narrow,
+                    );
+                }
+            }
+        );
+    };
+
+    () => {
+        for_each_engine!(float_conversion_tester_impl);
+    };
+}
+
+#[macro_export]
+macro_rules! float_to_int_tester_impl {
+    (@width $simd:ident, $float:ident, $int:ident) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<float_to_int_ $simd:lower _ $float>]() {
+                    type V = <$simd as Simd>::[<V $float>];
+
+                    convert_tester(
+                        RandSimd::$float().one_arg_cast_edge_cases(),
+                        V::[<to_ $int _trunc>],
+                        |x: $float| x as $int,
+                    );
+                    convert_tester(
+                        RandSimd::$float().one_arg_cast_edge_cases(),
+                        V::[<to_ $int _round>],
+                        |x: $float| x.round() as $int,
+                    );
+                    convert_tester(
+                        RandSimd::$float().one_arg_cast_edge_cases(),
+                        V::[<to_ $int _floor>],
+                        |x: $float| x.floor() as $int,
+                    );
+                    convert_tester(
+                        RandSimd::$float().one_arg_cast_edge_cases(),
+                        V::[<to_ $int _round_ties_even>],
+                        |x: $float| x.round_ties_even() as $int,
+                    );
+                    convert_tester(
+                        RandSimd::$float().one_arg_cast_edge_cases(),
+                        V::[<to_ $int _saturating>],
+                        |x: $float| x.round_ties_even() as $int,
+                    );
+                }
+            }
+        );
+    };
+
+    (@full $simd:ident) => {
+        float_to_int_tester_impl!(@width $simd, f32, i32);
+        float_to_int_tester_impl!(@width $simd, f64, i64);
+    };
+
+    () => {
+        for_each_engine!(float_to_int_tester_impl);
+    };
+}
+
+#[macro_export]
+macro_rules! int8_eq_tester_impl {
+    (@full $simd:ident, $test_name:ident, $simd_fn:ident $args:tt) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<$test_name _ $simd:lower>]() {
+                    let f = |a: <$simd as Simd>::Vi8| a.$simd_fn $args;
+                    let sf = |a: <Scalar as Simd>::Vi8| a.$simd_fn $args;
+                    mask_eq_tester(RandSimd::i8().one_arg(), f, sf);
+                }
+            }
+        );
+    };
+
+    ($test_name:ident, $simd_fn:ident $args:tt) => {
+        for_each_engine!(int8_eq_tester_impl, $test_name, $simd_fn $args);
+    };
+}
+
+#[macro_export]
+macro_rules! byte_shuffle_tester_impl {
+    (@full $simd:ident, $index_mask:literal) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                fn [<byte_shuffle_ $index_mask _ $simd:lower>]() {
+                    byte_shuffle_tester::<<$simd as Simd>::Vi8>(
+                        RandSimd::i8().two_arg(),
+                        $index_mask as u8 as i8,
+                    );
+                }
+            }
+        );
+    };
+
+    (@simdkind $index_mask:literal) => {
+        for_each_engine!(byte_shuffle_tester_impl, $index_mask);
+    };
+
+    ($($index_mask:literal),*) => {
+        $(byte_shuffle_tester_impl!(@simdkind $index_mask);)*
+    };
+}
+
+#[macro_export]
+macro_rules! align_right_tester_impl {
+    (@full $simd:ident, $offset:literal) => {
+        with_feature_flag!($simd,
+            paste::item! {
+                #[test]
+                #[allow(unused_comparisons)]
+                fn [<align_right_ $offset _ $simd:lower>]() {
+                    type V = <$simd as Simd>::Vi8;
+                    if $offset <= V::WIDTH {
+                        align_right_tester(
+                            RandSimd::i8().two_arg(),
+                            $offset,
+                            |a: V, low: V| a.align_right::<$offset>(low),
+                        );
+                    }
+                }
+            }
+        );
+    };
+
+    (@simdkind $offset:literal) => {
+        for_each_engine!(align_right_tester_impl, $offset);
+    };
+
+    ($($offset:literal),*) => {
+        $(align_right_tester_impl!(@simdkind $offset);)*
     };
 }
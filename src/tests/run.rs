@@ -10,7 +10,9 @@ use crate::engines::scalar::*;
 #[cfg(target_arch = "wasm32")]
 use crate::engines::wasm32::Wasm;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-use crate::engines::{avx2::*, sse2::*, sse41::*};
+use crate::engines::{
+    avx::*, avx2::*, avx2fma::*, avx512::*, sse2::*, sse41::*, sse42::*, ssse3::*,
+};
 
 use crate::*;
 
@@ -27,22 +29,102 @@ elementwise_eq_tester_impl!(SimdBaseOps, and_not, two_arg, EqPrecision::exact())
 // Abs breaks on minimum integer values as they dont have a corrisponding maximum, causing undefined behavior.
 elementwise_eq_tester_impl!(SimdBaseOps, abs, one_arg_abs_filtered, EqPrecision::exact());
 
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_eq, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_lt, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_lte, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_gt, two_arg, EqPrecision::exact());
-elementwise_eq_tester_impl!(SimdBaseOps, cmp_gte, two_arg, EqPrecision::exact());
-
-elementwise_eq_tester_impl!(SimdBaseOps, blendv, iter_blendv_ags, EqPrecision::exact());
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    cmp_eq,
+    |a, b| a.cmp_eq(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    cmp_lt,
+    |a, b| a.cmp_lt(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    cmp_lte,
+    |a, b| a.cmp_lte(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    cmp_gt,
+    |a, b| a.cmp_gt(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    cmp_gte,
+    |a, b| a.cmp_gte(b),
+    two_arg
+);
 
 // We filter out NaN numbers for neq because under some hardware implementations (including Avx2)
 // it appears that `NaN != [number]` is false. Technically that's invalid according to the floatin point
 // spec, but it seems like a hardware thing that we cant avoid.
-elementwise_eq_tester_impl!(
+closure_eq_tester_impl!(
     SimdBaseOps,
+    mask_eq_tester,
     cmp_neq,
-    two_arg_nan_filtered,
-    EqPrecision::exact()
+    |a, b| a.cmp_neq(b),
+    two_arg_nan_filtered
+);
+
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    mask_and,
+    |a, b| a.cmp_lte(b) & a.cmp_gte(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    mask_or,
+    |a, b| a.cmp_lt(b) | a.cmp_eq(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    mask_xor,
+    |a, b| a.cmp_lte(b) ^ a.cmp_gte(b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    mask_eq_tester,
+    mask_not,
+    |a, b| !a.cmp_lt(b),
+    two_arg
+);
+
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    exact_eq_tester,
+    select,
+    |mask, a, b| V::select(mask.cmp_eq(V::zeroes()), a, b),
+    iter_blendv_ags
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    exact_eq_tester,
+    mask_blendv,
+    |a, b| a.cmp_lt(b).blendv(a, b),
+    two_arg
+);
+closure_eq_tester_impl!(
+    SimdBaseOps,
+    exact_eq_tester,
+    from_mask,
+    |a, b| V::from_mask(a.cmp_lt(b)),
+    two_arg
 );
 
 elementwise_eq_tester_impl!(SimdBaseOps, max, two_arg_nan_filtered, EqPrecision::exact());
@@ -60,6 +142,26 @@ elementwise_eq_tester_impl!(
     EqPrecision::almost(6)
 );
 
+// These are expected to give identical results on every engine, including for ties.
+elementwise_eq_tester_impl!(
+    SimdFloat,
+    round_ties_even,
+    one_arg_rounding_edge_cases,
+    EqPrecision::exact()
+);
+elementwise_eq_tester_impl!(
+    SimdFloat,
+    round_ties_away,
+    one_arg_rounding_edge_cases,
+    EqPrecision::exact()
+);
+elementwise_eq_tester_impl!(
+    SimdFloat,
+    trunc,
+    one_arg_rounding_edge_cases,
+    EqPrecision::exact()
+);
+
 elementwise_eq_tester_impl!(SimdFloat, mul_add, three_arg, EqPrecision::almost(5));
 elementwise_eq_tester_impl!(SimdFloat, mul_sub, three_arg, EqPrecision::almost(5));
 elementwise_eq_tester_impl!(SimdFloat, neg_mul_add, three_arg, EqPrecision::almost(5));
@@ -68,10 +170,83 @@ elementwise_eq_tester_impl!(SimdFloat, neg_mul_sub, three_arg, EqPrecision::almo
 elementwise_eq_tester_impl!(SimdFloat, sqrt, one_arg, EqPrecision::almost(7));
 elementwise_eq_tester_impl!(SimdFloat, rsqrt, one_arg, EqPrecision::almost(2)); // Has very low precision
 
+closure_eq_tester_impl!(
+    SimdFloat,
+    mask_eq_tester,
+    is_nan,
+    |a| a.is_nan(),
+    one_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    mask_eq_tester,
+    is_infinite,
+    |a| a.is_infinite(),
+    one_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    mask_eq_tester,
+    is_finite,
+    |a| a.is_finite(),
+    one_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    mask_eq_tester,
+    is_sign_negative,
+    |a| a.is_sign_negative(),
+    one_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    std_eq_tester,
+    copysign,
+    |a, b| a.copysign(b),
+    std,
+    two_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    std_eq_tester,
+    signum,
+    |a| a.signum(),
+    std,
+    one_arg_sign_edge_cases
+);
+closure_eq_tester_impl!(
+    SimdFloat,
+    std_eq_tester,
+    fract,
+    |a| a.fract(),
+    std,
+    one_arg_sign_edge_cases
+);
+
 bitshift_eq_tester_impl!(dyn shl);
 bitshift_eq_tester_impl!(dyn shr);
 bitshift_eq_tester_impl!(const shl_const);
 bitshift_eq_tester_impl!(const shr_const);
+elementwise_eq_tester_impl!(SimdInt, shl_var, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt, shr_var, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt, shr_logical, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt, rotate_left, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt, rotate_right, two_arg, EqPrecision::exact());
+bitshift_eq_tester_impl!(const rotate_left_const);
+bitshift_eq_tester_impl!(const rotate_right_const);
+elementwise_eq_tester_impl!(SimdInt, count_ones, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(
+    SimdInt,
+    leading_zeros,
+    one_arg_bit_runs,
+    EqPrecision::exact()
+);
+elementwise_eq_tester_impl!(
+    SimdInt,
+    trailing_zeros,
+    one_arg_bit_runs,
+    EqPrecision::exact()
+);
 
 elementwise_eq_tester_impl!(
     SimdFloat32,
@@ -87,6 +262,124 @@ elementwise_eq_tester_impl!(
     EqPrecision::exact()
 );
 elementwise_eq_tester_impl!(SimdInt64, cast_f64, one_arg, EqPrecision::exact());
+float_conversion_tester_impl!();
+float_to_int_tester_impl!();
+
+elementwise_eq_tester_impl!(SimdInt8, bitcast_u8, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt16, bitcast_u16, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt32, bitcast_u32, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt64, bitcast_u64, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint8, bitcast_i8, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint16, bitcast_i16, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint32, bitcast_i32, one_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint64, bitcast_i64, one_arg, EqPrecision::exact());
+
+elementwise_eq_tester_impl!(SimdInt8, saturating_add, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt8, saturating_sub, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt16, saturating_add, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt16, saturating_sub, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint8, saturating_add, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint8, saturating_sub, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint16, saturating_add, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdUint16, saturating_sub, two_arg, EqPrecision::exact());
+
+elementwise_eq_tester_impl!(SimdInt16, mul_hi, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt32, mul_hi, two_arg, EqPrecision::exact());
+elementwise_eq_tester_impl!(SimdInt16, mulhrs, two_arg, EqPrecision::exact());
+widening_mul_tester_impl!();
+narrow_tester_impl!();
 
 horizontal_add_tester_impl!(signed);
 horizontal_add_tester_impl!(unsigned);
+
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_min,
+    min,
+    one_arg_nan_filtered,
+    EqPrecision::exact()
+);
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_max,
+    max,
+    one_arg_nan_filtered,
+    EqPrecision::exact()
+);
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_mul,
+    mul,
+    one_arg,
+    EqPrecision::almost(5)
+);
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_and,
+    bit_and,
+    one_arg,
+    EqPrecision::exact()
+);
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_or,
+    bit_or,
+    one_arg,
+    EqPrecision::exact()
+);
+horizontal_reduce_tester_impl!(
+    horizontal_reduce_tester,
+    horizontal_xor,
+    bit_xor,
+    one_arg,
+    EqPrecision::exact()
+);
+horizontal_reduce_tester_impl!(arg_reduce_tester, argmin, min, one_arg_nan_filtered);
+horizontal_reduce_tester_impl!(arg_reduce_tester, argmax, max, one_arg_nan_filtered);
+
+prefix_sum_tester_impl!();
+
+truthy_tester_impl!();
+
+gather_tester_impl!();
+
+scatter_tester_impl!();
+
+masked_io_tester_impl!();
+
+permute_tester_impl!();
+
+broadcast_lane_tester_impl!();
+
+interleave_tester_impl!();
+
+transpose_tester_impl!();
+
+int8_eq_tester_impl!(cmp_eq_any_empty, cmp_eq_any(&[]));
+int8_eq_tester_impl!(cmp_eq_any_few, cmp_eq_any(&[0, 10, -128]));
+int8_eq_tester_impl!(
+    cmp_eq_any_full,
+    cmp_eq_any(&[-128, -100, -64, -32, -16, -8, -1, 0, 1, 2, 8, 16, 32, 64, 100, 127])
+);
+int8_eq_tester_impl!(cmp_in_ranges_empty, cmp_in_ranges(&[]));
+int8_eq_tester_impl!(
+    cmp_in_ranges_digits,
+    cmp_in_ranges(&[(b'0' as i8, b'9' as i8)])
+);
+int8_eq_tester_impl!(
+    cmp_in_ranges_full,
+    cmp_in_ranges(&[
+        (-128, -120),
+        (-50, -40),
+        (-1, 1),
+        (5, 5),
+        (20, 10),
+        (48, 57),
+        (65, 90),
+        (120, 127)
+    ])
+);
+
+align_right_tester_impl!(0, 1, 7, 15, 16, 17, 31, 32, 33, 63, 64);
+
+byte_shuffle_tester_impl!(15, 31, 63, 255);